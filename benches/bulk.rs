@@ -0,0 +1,53 @@
+//! Compares the bulk slice parsers against parsing one element at a time through `count`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use cursor_binary_parser::binary_cursor::BinaryCursor;
+use std::hint::black_box;
+
+/// The number of f32 vertex components parsed per iteration
+const COMPONENTS: usize = 1 << 20;
+
+fn bench_f32(c: &mut Criterion) {
+    let data: Vec<u8> = (0..COMPONENTS as u32)
+        .flat_map(|i| (i as f32).to_le_bytes())
+        .collect();
+
+    let mut group = c.benchmark_group("f32_le");
+    group.bench_function("count", |b| {
+        b.iter(|| {
+            let mut cursor = BinaryCursor::new(black_box(&data));
+            cursor.count(|c| c.parse_f32_le(), COMPONENTS).unwrap()
+        })
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| {
+            let mut cursor = BinaryCursor::new(black_box(&data));
+            cursor.parse_f32_slice_le(COMPONENTS).unwrap()
+        })
+    });
+    group.finish();
+}
+
+fn bench_u16(c: &mut Criterion) {
+    let data: Vec<u8> = (0..COMPONENTS as u32)
+        .flat_map(|i| (i as u16).to_le_bytes())
+        .collect();
+
+    let mut group = c.benchmark_group("u16_le");
+    group.bench_function("count", |b| {
+        b.iter(|| {
+            let mut cursor = BinaryCursor::new(black_box(&data));
+            cursor.count(|c| c.parse_u16_le(), COMPONENTS).unwrap()
+        })
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| {
+            let mut cursor = BinaryCursor::new(black_box(&data));
+            cursor.parse_u16_slice_le(COMPONENTS).unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_f32, bench_u16);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+//! Compares the primitive parsers against the `std::io::Read` approach they replace, on a
+//! tight loop of small fields.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use cursor_binary_parser::binary_cursor::BinaryCursor;
+use std::hint::black_box;
+use std::io::{Cursor, Read};
+
+/// The number of u32s parsed per iteration
+const VALUES: usize = 1 << 22;
+
+fn bench_u32(c: &mut Criterion) {
+    let data: Vec<u8> = (0..VALUES as u32).flat_map(u32::to_le_bytes).collect();
+
+    let mut group = c.benchmark_group("u32_le_loop");
+    group.bench_function("read_exact", |b| {
+        b.iter(|| {
+            let mut cursor = Cursor::new(black_box(&data));
+            let mut sum = 0u32;
+            for _ in 0..VALUES {
+                let mut buf = [0u8; 4];
+                cursor.read_exact(&mut buf).unwrap();
+                sum = sum.wrapping_add(u32::from_le_bytes(buf));
+            }
+            sum
+        })
+    });
+    group.bench_function("parse_u32_le", |b| {
+        b.iter(|| {
+            let mut cursor = BinaryCursor::new(black_box(&data));
+            let mut sum = 0u32;
+            for _ in 0..VALUES {
+                sum = sum.wrapping_add(cursor.parse_u32_le().unwrap());
+            }
+            sum
+        })
+    });
+    group.finish();
+}
+
+fn bench_mixed(c: &mut Criterion) {
+    let data: Vec<u8> = (0..VALUES as u32 / 2)
+        .flat_map(|i| {
+            let mut record = [0u8; 8];
+            record[0] = i as u8;
+            record[1..3].copy_from_slice(&(i as u16).to_le_bytes());
+            record[3] = 0xFF;
+            record[4..].copy_from_slice(&i.to_le_bytes());
+            record
+        })
+        .collect();
+
+    c.bench_function("u8_u16_u8_u32_records", |b| {
+        b.iter(|| {
+            let mut cursor = BinaryCursor::new(black_box(&data));
+            let mut sum = 0u64;
+            while !cursor.at_end() {
+                sum += u64::from(cursor.parse_u8().unwrap());
+                sum += u64::from(cursor.parse_u16_le().unwrap());
+                sum += u64::from(cursor.parse_u8().unwrap());
+                sum += u64::from(cursor.parse_u32_le().unwrap());
+            }
+            sum
+        })
+    });
+}
+
+criterion_group!(benches, bench_u32, bench_mixed);
+criterion_main!(benches);
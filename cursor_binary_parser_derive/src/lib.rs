@@ -0,0 +1,253 @@
+//! Derive macro for the `FromCursor` trait of
+//! [`cursor_binary_parser`](https://docs.rs/cursor_binary_parser).
+//!
+//! This crate is re-exported by `cursor_binary_parser` behind its `derive` cargo feature and
+//! is not intended to be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, GenericArgument, Ident, LitInt, LitStr,
+    PathArguments, Result, Type, parse_macro_input, parse_quote,
+};
+
+/// Derives `FromCursor` for a struct by parsing each field in declaration order
+///
+/// Every field must implement `FromCursor`, apart from `Vec` fields, which need a `count`
+/// attribute. The generated implementation can be used with `BinaryCursor::parse` or
+/// nested inside other `FromCursor` types. Named, tuple and unit structs are supported.
+///
+/// The generated `from_cursor` is atomic: if any field fails to parse, the cursor is left
+/// where it was before the struct. Each type parameter of a generic struct is required to
+/// implement `FromCursor`.
+///
+/// # Attributes
+///
+/// | Attribute | Applies to | Effect |
+/// |-----------|------------|--------|
+/// | `#[cursor(endian = "big")]` | struct or field | Parses with the given byte order (`"big"` or `"little"`) instead of the cursor's. A field attribute overrides a struct attribute. |
+/// | `#[cursor(count = "field")]` | `Vec` field | Parses as many elements as the value of an earlier integer field. A value that does not fit in a `usize`, such as a negative one, is an `InvalidData` error. |
+/// | `#[cursor(skip = N)]` | field | Skips `N` bytes of padding before the field. |
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use cursor_binary_parser::prelude::*;
+///
+/// #[derive(FromCursor)]
+/// #[cursor(endian = "big")]
+/// struct Palette {
+///     version: u16,
+///     #[cursor(skip = 2)]
+///     num_colours: u8,
+///     #[cursor(count = "num_colours")]
+///     colours: Vec<[u8; 3]>,
+/// }
+/// ```
+#[proc_macro_derive(FromCursor, attributes(cursor))]
+pub fn derive_from_cursor(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A byte order named in an `endian` attribute
+#[derive(Clone, Copy)]
+enum Endian {
+    Little,
+    Big,
+}
+
+/// The options parsed from the `cursor` attributes on a struct or field
+#[derive(Default)]
+struct Options {
+    endian: Option<Endian>,
+    count: Option<Ident>,
+    skip: Option<u64>,
+}
+
+/// Parses the `cursor` attributes in `attrs`, rejecting field-only options unless `is_field`
+fn parse_options(attrs: &[Attribute], is_field: bool) -> Result<Options> {
+    let mut options = Options::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("cursor")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("endian") {
+                let value: LitStr = meta.value()?.parse()?;
+                options.endian = Some(match value.value().as_str() {
+                    "little" => Endian::Little,
+                    "big" => Endian::Big,
+                    _ => {
+                        return Err(Error::new(
+                            value.span(),
+                            "expected `endian = \"little\"` or `endian = \"big\"`",
+                        ));
+                    }
+                });
+            } else if is_field && meta.path.is_ident("count") {
+                let value: LitStr = meta.value()?.parse()?;
+                options.count = Some(value.parse()?);
+            } else if is_field && meta.path.is_ident("skip") {
+                let value: LitInt = meta.value()?.parse()?;
+                options.skip = Some(value.base10_parse()?);
+            } else if is_field {
+                return Err(
+                    meta.error("unknown `cursor` attribute, expected `endian`, `count` or `skip`")
+                );
+            } else {
+                return Err(
+                    meta.error("unknown struct-level `cursor` attribute, expected `endian`")
+                );
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+/// Returns the element type of `ty` if it is written as `Vec<T>`
+fn vec_element(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(element) if args.args.len() == 1 => Some(element),
+        _ => None,
+    }
+}
+
+/// Generates the `FromCursor` implementation for `input`
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let krate = quote!(::cursor_binary_parser);
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`FromCursor` can only be derived for structs",
+            ));
+        }
+    };
+    let struct_options = parse_options(&input.attrs, false)?;
+
+    let mut statements = vec![];
+    let mut names: Vec<Ident> = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        let options = parse_options(&field.attrs, true)?;
+        let ty = &field.ty;
+
+        if let Some(skip) = options.skip {
+            statements.push(quote!(__cursor.skip(#skip)?;));
+        }
+
+        let parse = match (&options.count, vec_element(ty)) {
+            (Some(count), Some(element)) => {
+                if !names.contains(count) {
+                    return Err(Error::new(
+                        count.span(),
+                        format!(
+                            "`count` field `{}` must be declared before this field",
+                            count
+                        ),
+                    ));
+                }
+                let operation = LitStr::new(&input.ident.to_string(), input.ident.span());
+                statements.push(quote_spanned! {count.span()=>
+                    #[allow(clippy::unnecessary_fallible_conversions)]
+                    let __count = <usize as ::core::convert::TryFrom<_>>::try_from(#count).map_err(|_| {
+                        #krate::binary_cursor::BinaryCursorError::InvalidData {
+                            operation: #operation,
+                            position: __cursor.position(),
+                            message: ::std::format!(
+                                "count field `{}` holds {}, which is not a valid length",
+                                ::core::stringify!(#count),
+                                #count,
+                            ),
+                        }
+                    })?;
+                });
+                quote_spanned! {element.span()=>
+                    __cursor.count(
+                        <#element as #krate::from_cursor::FromCursor>::from_cursor,
+                        __count,
+                    )
+                }
+            }
+            (Some(_), None) => {
+                return Err(Error::new(
+                    ty.span(),
+                    "`count` can only be used on `Vec` fields",
+                ));
+            }
+            (None, Some(_)) => {
+                return Err(Error::new(
+                    ty.span(),
+                    "`Vec` fields need a `#[cursor(count = \"field\")]` attribute naming the \
+                     earlier field that holds their length",
+                ));
+            }
+            (None, None) => quote_spanned! {ty.span()=>
+                <#ty as #krate::from_cursor::FromCursor>::from_cursor(__cursor)
+            },
+        };
+
+        let parse = match options.endian.or(struct_options.endian) {
+            Some(endian) => {
+                let endian = match endian {
+                    Endian::Little => quote!(#krate::binary_cursor::Endianness::Little),
+                    Endian::Big => quote!(#krate::binary_cursor::Endianness::Big),
+                };
+                quote!(__cursor.with_endianness(#endian, |__cursor| #parse))
+            }
+            None => parse,
+        };
+
+        let name = field
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("field_{}", i));
+        statements.push(quote!(let #name = #parse?;));
+        names.push(name);
+    }
+
+    let construct = match fields {
+        Fields::Named(_) => quote!(Self { #(#names),* }),
+        Fields::Unnamed(_) => quote!(Self(#(#names),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    let params: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: #krate::from_cursor::FromCursor));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::from_cursor::FromCursor for #ident #ty_generics #where_clause {
+            fn from_cursor<__T: ::core::convert::AsRef<[u8]>>(
+                __cursor: &mut #krate::binary_cursor::BinaryCursor<__T>,
+            ) -> ::core::result::Result<Self, #krate::binary_cursor::BinaryCursorError> {
+                __cursor.atomic(|__cursor| {
+                    #(#statements)*
+                    ::core::result::Result::Ok(#construct)
+                })
+            }
+        }
+    })
+}
@@ -6,8 +6,11 @@
 //!
 //! The implementation includes:
 //! - Position management with push/pop operations
-//! - Safe parsing of primitive types (u8, u16, u32, f32)
+//! - Safe parsing of fixed-width integers and floats in either byte order, and of
+//!   variable-length integers, strings, timestamps and network addresses
 //! - RAII-based temporary position changes via BinaryCursorJump
+//! - All-or-nothing multi-field parsing via `atomic`
+//! - Configurable byte order and safety limits via `BinaryCursorBuilder`
 //! - Error handling with custom error types
 //!
 //! # Safety
@@ -15,16 +18,117 @@
 //! All parsing operations are bounds-checked and will return errors rather than
 //! panicking on invalid input or out-of-bounds access.
 
-use std::io::{Cursor, Read};
+use crate::streaming_cursor::StreamingBinaryCursor;
+use std::collections::HashMap;
+use std::io::{BufRead, Cursor, Read, Seek, SeekFrom, Write};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
 // region: Error implementation
 /// Error type for binary cursor operations
+///
+/// # Display format
+///
+/// The `Display` output of each variant is part of the crate's stable interface and only
+/// changes in a breaking release, so it is safe to match on in logs and CI output:
+///
+/// | Variant | Format |
+/// |---------|--------|
+/// | `ParseError` | `Parse error: {io error}` |
+/// | `UnexpectedEof` | `Unexpected EOF at offset {position} in {operation}: requested {requested} bytes, {available} available` |
+/// | `InvalidData` | `Invalid data at offset {position} in {operation}: {message}` |
+/// | `Context` | `{label}: {source}` |
+/// | `HexDump` | `{source}`, then a newline and the dump |
+/// | `Io` | `I/O error on {path}: {io error}` |
+///
+/// Tooling that needs individual values should prefer [`fields`](Self::fields) or
+/// [`to_json`](Self::to_json) over parsing these strings.
+///
+/// # Variants
+///
+/// The built-in parse methods report running out of data as `UnexpectedEof` and malformed
+/// values as `InvalidData`, both of which record the offset of the failure. `ParseError` is
+/// used for failures that have no meaningful offset: invalid arguments, such as an integer
+/// width of 9 bytes, errors from writers passed to methods like
+/// [`extract_region_to`](BinaryCursor::extract_region_to), and `io::Error`s converted with
+/// `?` in caller code. `Io` is used when a file cannot be opened or mapped, and records
+/// its path.
 #[derive(Debug, Error)]
 pub enum BinaryCursorError {
     /// Error that occurs during parsing operations
     #[error("Parse error: {0}")]
     ParseError(#[from] std::io::Error),
+    /// Fewer bytes were available than an operation required
+    #[error(
+        "Unexpected EOF at offset {position} in {operation}: requested {requested} bytes, {available} available"
+    )]
+    UnexpectedEof {
+        /// The parse method that failed
+        operation: &'static str,
+        /// The offset at which the operation started
+        position: u64,
+        /// The number of bytes the operation needed
+        requested: usize,
+        /// The number of bytes that were available
+        available: usize,
+    },
+    /// The bytes read could not be interpreted as the requested type
+    #[error("Invalid data at offset {position} in {operation}: {message}")]
+    InvalidData {
+        /// The parse method that failed
+        operation: &'static str,
+        /// The offset at which the invalid value started
+        position: u64,
+        /// A description of what was wrong with the data
+        message: String,
+    },
+    /// An error annotated with a label describing what was being parsed
+    #[error("{label}: {source}")]
+    Context {
+        /// A caller-supplied description, such as the name of the structure being parsed
+        label: &'static str,
+        /// The underlying error
+        source: Box<BinaryCursorError>,
+    },
+    /// An error annotated with a hex dump of the bytes around it
+    #[error("{source}\n{dump}")]
+    HexDump {
+        /// The dump, as produced by [`BinaryCursor::context`]
+        dump: String,
+        /// The underlying error
+        source: Box<BinaryCursorError>,
+    },
+    /// A file could not be opened or mapped
+    #[error("I/O error on {}: {source}", path.display())]
+    Io {
+        /// The path of the file
+        path: PathBuf,
+        /// The underlying error
+        source: std::io::Error,
+    },
+}
+
+/// A structured view of a [`BinaryCursorError`] for tooling that should not parse strings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorFields {
+    /// A stable identifier for the variant: `parse_error`, `unexpected_eof`, `invalid_data` or
+    /// `io_error`
+    pub kind: &'static str,
+    /// The offset at which the failure occurred, if known
+    pub offset: Option<u64>,
+    /// The number of bytes the operation needed, for EOF errors
+    pub requested: Option<usize>,
+    /// The number of bytes that were available, for EOF errors
+    pub available: Option<usize>,
+    /// The parse method that failed, if known
+    pub operation: Option<&'static str>,
+    /// A human-readable description of the failure
+    pub message: String,
+    /// Context labels attached to the error, outermost first
+    pub context: Vec<&'static str>,
 }
 
 impl BinaryCursorError {
@@ -32,527 +136,9405 @@ impl BinaryCursorError {
     pub fn from_io_error(error: std::io::Error) -> Self {
         Self::ParseError(error)
     }
+
+    /// Wraps this error with a label describing what was being parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let error = cursor.parse_cstr().map_err(|e| e.context("file name")).unwrap_err();
+    /// assert_eq!(error.fields().context, vec!["file name"]);
+    /// ```
+    pub fn context(self, label: &'static str) -> Self {
+        Self::Context {
+            label,
+            source: Box::new(self),
+        }
+    }
+
+    /// Attaches a hex dump of the bytes of `cursor` around the failure
+    ///
+    /// The dump covers 32 bytes either side of the error's offset, or of the cursor's
+    /// position for errors without one. [`position`](Self::position) and
+    /// [`fields`](Self::fields) look through the dump to the underlying error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"RIFX\x04\x00\x00\x00WAVE";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// let error = cursor
+    ///     .expect_tag(b"RIFF")
+    ///     .map_err(|e| e.with_context(&cursor))
+    ///     .unwrap_err();
+    /// assert!(error.to_string().ends_with(
+    ///     "00000000  52 49 46 58 04 00 00 00  57 41 56 45              |RIFX....WAVE    |\n          ^^"
+    /// ));
+    /// ```
+    pub fn with_context<T: AsRef<[u8]>>(self, cursor: &BinaryCursor<T>) -> Self {
+        let position = self.position().unwrap_or_else(|| cursor.position());
+        Self::HexDump {
+            dump: cursor.hexdump(position, 32),
+            source: Box::new(self),
+        }
+    }
+
+    /// Returns the offset at which the failure occurred, if it is known
+    ///
+    /// Context labels are looked through, so this is the offset of the innermost error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// cursor.parse_u16_le().unwrap();
+    /// let error = cursor.parse_u16_le().unwrap_err();
+    /// assert_eq!(error.position(), Some(2));
+    /// ```
+    pub fn position(&self) -> Option<u64> {
+        match self {
+            Self::ParseError(_) | Self::Io { .. } => None,
+            Self::UnexpectedEof { position, .. } | Self::InvalidData { position, .. } => {
+                Some(*position)
+            }
+            Self::Context { source, .. } | Self::HexDump { source, .. } => source.position(),
+        }
+    }
+
+    /// Returns the values carried by this error in structured form
+    ///
+    /// Context labels are collected from every [`Context`](Self::Context) layer, and the
+    /// remaining fields describe the innermost error.
+    pub fn fields(&self) -> ErrorFields {
+        match self {
+            Self::ParseError(error) => ErrorFields {
+                kind: "parse_error",
+                offset: None,
+                requested: None,
+                available: None,
+                operation: None,
+                message: error.to_string(),
+                context: vec![],
+            },
+            Self::UnexpectedEof {
+                operation,
+                position,
+                requested,
+                available,
+            } => ErrorFields {
+                kind: "unexpected_eof",
+                offset: Some(*position),
+                requested: Some(*requested),
+                available: Some(*available),
+                operation: Some(operation),
+                message: format!("requested {} bytes, {} available", requested, available),
+                context: vec![],
+            },
+            Self::InvalidData {
+                operation,
+                position,
+                message,
+            } => ErrorFields {
+                kind: "invalid_data",
+                offset: Some(*position),
+                requested: None,
+                available: None,
+                operation: Some(operation),
+                message: message.clone(),
+                context: vec![],
+            },
+            Self::Context { label, source } => {
+                let mut fields = source.fields();
+                fields.context.insert(0, label);
+                fields
+            }
+            Self::HexDump { source, .. } => source.fields(),
+            Self::Io { path, source } => ErrorFields {
+                kind: "io_error",
+                offset: None,
+                requested: None,
+                available: None,
+                operation: None,
+                message: format!("{}: {}", path.display(), source),
+                context: vec![],
+            },
+        }
+    }
+
+    /// Returns the structured fields of this error as a single-line JSON object
+    ///
+    /// The object always contains the keys `kind`, `offset`, `requested`, `available`,
+    /// `operation`, `message` and `context`, with `null` for values that are not known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"abc";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// let error = cursor.parse_cstr_max(16).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_json(),
+    ///     r#"{"kind":"unexpected_eof","offset":0,"requested":4,"available":3,"operation":"parse_cstr_max","message":"requested 4 bytes, 3 available","context":[]}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        fn number<N: std::fmt::Display>(value: Option<N>) -> String {
+            value.map_or_else(|| "null".to_string(), |v| v.to_string())
+        }
+
+        let fields = self.fields();
+        let context: Vec<String> = fields.context.iter().map(|l| json_string(l)).collect();
+        format!(
+            r#"{{"kind":{},"offset":{},"requested":{},"available":{},"operation":{},"message":{},"context":[{}]}}"#,
+            json_string(fields.kind),
+            number(fields.offset),
+            number(fields.requested),
+            number(fields.available),
+            fields
+                .operation
+                .map_or_else(|| "null".to_string(), json_string),
+            json_string(&fields.message),
+            context.join(","),
+        )
+    }
+}
+
+/// Quotes and escapes `value` as a JSON string literal
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
 }
 // endregion: Error implementation
 
+// region: Endianness
+/// Byte order used when assembling multi-byte values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    /// Least significant byte first
+    Little,
+    /// Most significant byte first
+    Big,
+}
+// endregion: Endianness
+
+// region: Padding
+/// Padding convention used by fixed-width string fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    /// The string ends at the first NUL byte; everything after it is padding
+    Null,
+    /// Trailing ASCII spaces are padding
+    Space,
+    /// The field has no padding and is returned as-is
+    None,
+}
+
+impl Padding {
+    /// Returns the portion of `field` that remains once padding is removed
+    fn trim(self, field: &[u8]) -> &[u8] {
+        match self {
+            Padding::Null => match field.iter().position(|&b| b == 0) {
+                Some(end) => &field[..end],
+                None => field,
+            },
+            Padding::Space => {
+                let end = field.iter().rposition(|&b| b != b' ').map_or(0, |i| i + 1);
+                &field[..end]
+            }
+            Padding::None => field,
+        }
+    }
+}
+// endregion: Padding
+
+// region: BitOrder
+/// Order in which bits are taken from each byte by [`BinaryCursorBits`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The most significant bit of each byte is read first, and multi-bit values are
+    /// assembled most significant bit first; this matches most packed header formats
+    MsbFirst,
+    /// The least significant bit of each byte is read first, and multi-bit values are
+    /// assembled least significant bit first, as in DEFLATE
+    LsbFirst,
+}
+// endregion: BitOrder
+
+// region: OffsetBase
+/// What an offset field read by [`BinaryCursor::parse_offset_u32_le`] is relative to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetBase {
+    /// The start of the data, so the offset is already absolute
+    Start,
+    /// The position of the offset field itself
+    CurrentFieldStart,
+    /// The given absolute position, such as the start of the enclosing structure
+    Absolute(u64),
+}
+// endregion: OffsetBase
+
+// region: CursorOptions
+/// Configuration that changes how a [`BinaryCursor`] parses its data
+///
+/// Cursors created with [`BinaryCursor::new`] use the default options, which match the
+/// behaviour of earlier versions of this crate. Use [`BinaryCursorBuilder`] to create a
+/// cursor with different options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CursorOptions {
+    /// The byte order used by methods without an explicit `_le` or `_be` suffix, such as
+    /// [`parse_u32`](BinaryCursor::parse_u32)
+    pub endianness: Endianness,
+    /// Whether variable-length integers must use their shortest encoding
+    pub strict: bool,
+    /// The largest number of bytes a single caller-sized read may allocate
+    pub max_alloc: Option<usize>,
+    /// The deepest the location stack may grow through `BinaryCursorJump`
+    pub depth_limit: Option<usize>,
+    /// Whether 2, 4 and 8 byte primitives must start at a multiple of their size
+    pub enforce_alignment: bool,
+}
+
+impl Default for CursorOptions {
+    fn default() -> Self {
+        Self {
+            endianness: Endianness::Little,
+            strict: false,
+            max_alloc: None,
+            depth_limit: None,
+            enforce_alignment: false,
+        }
+    }
+}
+// endregion: CursorOptions
+
 // region: Cursor implementation
 /// A cursor-like interface for parsing binary data
 ///
 /// This type provides methods for parsing various types of binary data and managing
 /// a location stack for temporary position changes. It works with any type T that
 /// implements `AsRef<[u8]>`, such as `Vec<u8>`, `&[u8]`, or other byte containers.
+///
+/// Fixed-size parse methods such as [`parse_u32_le`](BinaryCursor::parse_u32_le), and
+/// [`parse_bytes`](BinaryCursor::parse_bytes), read straight from the underlying slice.
+/// On success the position advances past exactly the bytes read; if too few bytes remain,
+/// they return [`BinaryCursorError::UnexpectedEof`] and leave the position unchanged.
+///
+/// A cursor over a cloneable container can itself be cloned, copying its position,
+/// location stack, options and bookmarks, and any trace events it has collected. A trace
+/// hook is not copied. See [`fork`](BinaryCursor::fork) for a cheap borrowed copy instead.
 #[derive(Debug)]
 pub struct BinaryCursor<T: AsRef<[u8]>> {
     /// The underlying cursor containing the binary data
     pub data: Cursor<T>,
     /// Stack of saved positions for temporary jumps
-    location_stack: Vec<u32>,
+    location_stack: Vec<u64>,
+    /// Options controlling how the data is parsed
+    options: CursorOptions,
+    /// Positions saved under a name, independently of the location stack
+    bookmarks: HashMap<String, u64>,
+    /// Where parse methods report what they read, if tracing is enabled
+    trace: Option<Trace>,
 }
 
-impl<T> BinaryCursor<T>
+impl<T> Clone for BinaryCursor<T>
 where
-    T: AsRef<[u8]>,
+    T: AsRef<[u8]> + Clone,
 {
-    /// Creates a new `BinaryCursor` from a slice of bytes
-    pub fn new(data: T) -> Self {
+    fn clone(&self) -> Self {
         Self {
-            data: Cursor::new(data),
-            location_stack: vec![],
+            data: self.data.clone(),
+            location_stack: self.location_stack.clone(),
+            options: self.options,
+            bookmarks: self.bookmarks.clone(),
+            trace: self.trace.as_ref().and_then(Trace::duplicate),
         }
     }
+}
 
-    /// Saves the current position to the location stack
-    pub fn push_location(&mut self) {
-        let pos = self.data.position() as u32;
-        self.location_stack.push(pos);
-    }
+/// A record of one parse method call, reported when tracing is enabled
+///
+/// See [`BinaryCursor::with_tracing`] and [`BinaryCursor::set_trace_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TraceEvent {
+    /// The name of the parse method
+    pub operation: &'static str,
+    /// The position when the method was called
+    pub offset: u64,
+    /// How far the method moved the position, which is 0 for most failures
+    pub len: u64,
+    /// How many traced methods were already running when this one was called
+    pub depth: usize,
+    /// Whether the method returned `Ok`
+    pub success: bool,
+}
 
-    /// Removes and returns the most recently saved position from the location stack
-    pub fn pop_location(&mut self) -> Option<u32> {
-        self.location_stack.pop()
+/// Where a cursor sends its trace events
+enum TraceSink {
+    Hook(Box<dyn FnMut(TraceEvent) + Send>),
+    Collect(Vec<TraceEvent>),
+}
+
+/// The tracing state of a cursor
+struct Trace {
+    sink: TraceSink,
+    /// The number of traced methods currently running
+    depth: usize,
+}
+
+impl Trace {
+    fn new(sink: TraceSink) -> Self {
+        Self { sink, depth: 0 }
     }
 
-    /// Restores the most recently saved position from the location stack
-    ///
-    /// Returns `true` if a position was restored, `false` if the stack was empty
-    pub fn restore_location(&mut self) -> bool {
-        if let Some(pos) = self.location_stack.pop() {
-            self.data.set_position(pos as u64);
-            true
-        } else {
-            false
+    fn emit(&mut self, event: TraceEvent) {
+        match &mut self.sink {
+            TraceSink::Hook(hook) => hook(event),
+            TraceSink::Collect(events) => events.push(event),
         }
     }
 
-    /// Parses a single u8 from the current position
-    pub fn parse_u8(&mut self) -> Result<u8, BinaryCursorError> {
-        let mut buf = [0u8; 1];
-        self.data.read_exact(&mut buf)?;
-        Ok(buf[0])
+    /// Copies collected events for a cloned cursor, since hooks cannot be cloned
+    fn duplicate(&self) -> Option<Self> {
+        match &self.sink {
+            TraceSink::Hook(_) => None,
+            TraceSink::Collect(events) => Some(Self {
+                sink: TraceSink::Collect(events.clone()),
+                depth: self.depth,
+            }),
+        }
     }
+}
 
-    /// Parses a u16 in little-endian format from the current position
-    pub fn parse_u16_le(&mut self) -> Result<u16, BinaryCursorError> {
-        let mut buf = [0u8; 2];
-        self.data.read_exact(&mut buf)?;
-        Ok(u16::from_le_bytes(buf))
+impl std::fmt::Debug for Trace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.sink {
+            TraceSink::Hook(_) => f.write_str("Trace(hook)"),
+            TraceSink::Collect(events) => f.debug_tuple("Trace").field(events).finish(),
+        }
     }
+}
 
-    /// Parses a u32 in little-endian format from the current position
-    pub fn parse_u32_le(&mut self) -> Result<u32, BinaryCursorError> {
-        let mut buf = [0u8; 4];
-        self.data.read_exact(&mut buf)?;
-        Ok(u32::from_le_bytes(buf))
-    }
+/// One of the parsers tried in order by [`BinaryCursor::alt`]
+pub type Alternative<'a, T, U> =
+    &'a mut dyn FnMut(&mut BinaryCursor<T>) -> Result<U, BinaryCursorError>;
 
-    /// Parses a u64 in little-endian format from the current position
-    pub fn parse_u64_le(&mut self) -> Result<u64, BinaryCursorError> {
-        let mut buf = [0u8; 8];
-        self.data.read_exact(&mut buf)?;
-        Ok(u64::from_le_bytes(buf))
+impl<T> BinaryCursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Creates a new `BinaryCursor` from a slice of bytes
+    pub fn new(data: T) -> Self {
+        Self::with_options(data, CursorOptions::default())
     }
 
-    /// Parses an f32 in little-endian format from the current position
-    pub fn parse_f32_le(&mut self) -> Result<f32, BinaryCursorError> {
-        let mut buf = [0u8; 4];
-        self.data.read_exact(&mut buf)?;
-        Ok(f32::from_le_bytes(buf))
+    /// Creates a new `BinaryCursor` with the given options, which are assumed to be valid
+    pub(crate) fn with_options(data: T, options: CursorOptions) -> Self {
+        Self {
+            data: Cursor::new(data),
+            location_stack: vec![],
+            options,
+            bookmarks: HashMap::new(),
+            trace: None,
+        }
     }
 
-    /// Parses an f64 (double precision) in little-endian format from the current position
-    pub fn parse_f64_le(&mut self) -> Result<f64, BinaryCursorError> {
-        let mut buf = [0u8; 8];
-        self.data.read_exact(&mut buf)?;
-        Ok(f64::from_le_bytes(buf))
+    /// Creates a new `BinaryCursor` that records a [`TraceEvent`] for every parse method call
+    ///
+    /// The events can be read back with [`trace_events`](Self::trace_events).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x00];
+    /// let mut cursor = BinaryCursor::with_tracing(data);
+    ///
+    /// cursor.parse_u8().unwrap();
+    /// cursor.parse_u16_le().unwrap();
+    /// assert!(cursor.parse_u32_le().is_err());
+    ///
+    /// let events = cursor.trace_events();
+    /// assert_eq!(events[1].operation, "parse_u16_le");
+    /// assert_eq!((events[1].offset, events[1].len), (1, 2));
+    /// assert!(!events[2].success);
+    /// ```
+    pub fn with_tracing(data: T) -> Self {
+        let mut cursor = Self::new(data);
+        cursor.trace = Some(Trace::new(TraceSink::Collect(vec![])));
+        cursor
     }
 
-    /// Parses a specified number of bytes from the current position
-    pub fn parse_bytes(&mut self, count: usize) -> Result<Vec<u8>, BinaryCursorError> {
-        let mut buf = vec![0u8; count];
-        self.data.read_exact(&mut buf)?;
-        Ok(buf)
+    /// Installs `hook` to be called with a [`TraceEvent`] after every parse method call
+    ///
+    /// This replaces any existing hook or collected events. Events are reported when a
+    /// method returns, so those for methods called by another parse method arrive before
+    /// the event for the outer method, with a greater [`depth`](TraceEvent::depth).
+    pub fn set_trace_hook<H>(&mut self, hook: H)
+    where
+        H: FnMut(TraceEvent) + Send + 'static,
+    {
+        self.trace = Some(Trace::new(TraceSink::Hook(Box::new(hook))));
     }
 
-    /// Parses an i8 from the current position
-    pub fn parse_i8(&mut self) -> Result<i8, BinaryCursorError> {
-        let mut buf = [0u8; 1];
-        self.data.read_exact(&mut buf)?;
-        Ok(i8::from_le_bytes(buf))
+    /// Turns tracing off, removing any hook and discarding any collected events
+    pub fn clear_tracing(&mut self) {
+        self.trace = None;
     }
 
-    /// Parses an i16 in little-endian format from the current position
-    pub fn parse_i16_le(&mut self) -> Result<i16, BinaryCursorError> {
-        let mut buf = [0u8; 2];
-        self.data.read_exact(&mut buf)?;
-        Ok(i16::from_le_bytes(buf))
+    /// Returns the events collected by a cursor created with [`with_tracing`](Self::with_tracing)
+    ///
+    /// This is empty if tracing is off or a hook is installed instead.
+    pub fn trace_events(&self) -> &[TraceEvent] {
+        match &self.trace {
+            Some(Trace {
+                sink: TraceSink::Collect(events),
+                ..
+            }) => events,
+            _ => &[],
+        }
     }
 
-    /// Parses an i32 in little-endian format from the current position
-    pub fn parse_i32_le(&mut self) -> Result<i32, BinaryCursorError> {
-        let mut buf = [0u8; 4];
-        self.data.read_exact(&mut buf)?;
-        Ok(i32::from_le_bytes(buf))
+    /// Removes and returns the events collected so far, leaving tracing on
+    pub fn take_trace_events(&mut self) -> Vec<TraceEvent> {
+        match &mut self.trace {
+            Some(Trace {
+                sink: TraceSink::Collect(events),
+                ..
+            }) => std::mem::take(events),
+            _ => vec![],
+        }
     }
 
-    /// Parses an i64 in little-endian format from the current position
-    pub fn parse_i64_le(&mut self) -> Result<i64, BinaryCursorError> {
-        let mut buf = [0u8; 8];
-        self.data.read_exact(&mut buf)?;
-        Ok(i64::from_le_bytes(buf))
+    /// Runs `parse` for the parse method `operation`, reporting a [`TraceEvent`] if tracing
+    /// is enabled
+    #[inline]
+    pub(crate) fn traced<U, F>(
+        &mut self,
+        operation: &'static str,
+        parse: F,
+    ) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        if self.trace.is_none() {
+            return parse(self);
+        }
+        self.traced_slow(operation, parse)
     }
 
-    /// Returns the current position in the data stream
-    pub fn position(&self) -> u64 {
-        self.data.position()
+    /// Runs `parse` for `operation` and reports it to the active trace, kept out of line so
+    /// that untraced parse methods stay small enough to inline
+    #[inline(never)]
+    fn traced_slow<U, F>(
+        &mut self,
+        operation: &'static str,
+        parse: F,
+    ) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        let Some(trace) = &mut self.trace else {
+            return parse(self);
+        };
+        let depth = trace.depth;
+        trace.depth += 1;
+        let offset = self.data.position();
+
+        let result = parse(self);
+        let len = self.data.position().abs_diff(offset);
+        if let Some(trace) = &mut self.trace {
+            trace.depth = depth;
+            trace.emit(TraceEvent {
+                operation,
+                offset,
+                len,
+                depth,
+                success: result.is_ok(),
+            });
+        }
+        result
     }
 
-    /// Sets the current position in the data stream
-    pub fn set_position(&mut self, pos: u64) {
-        self.data.set_position(pos);
+    /// Returns the options this cursor was created with
+    pub fn options(&self) -> &CursorOptions {
+        &self.options
     }
 
-    /// Parses multiple items using the provided parser function
+    /// Runs a parser with the default byte order temporarily set to `endianness`
     ///
-    /// This is similar to nom's `count` combinator, but works with the `BinaryCursor` interface.
+    /// Methods without an explicit `_le` or `_be` suffix, and [`FromCursor`] implementations
+    /// built on them, use `endianness` while the parser runs. The cursor's own setting is
+    /// restored afterwards, whether the parser succeeds or fails.
+    ///
+    /// [`FromCursor`]: crate::from_cursor::FromCursor
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, Endianness};
     ///
-    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let data = vec![0x00, 0x01, 0x01, 0x00];
     /// let mut cursor = BinaryCursor::new(data);
     ///
-    /// let values = cursor.count(|c| c.parse_u8(), 4).unwrap();
-    /// assert_eq!(values, vec![0x01, 0x02, 0x03, 0x04]);
+    /// let big = cursor.with_endianness(Endianness::Big, |c| c.parse_u16()).unwrap();
+    /// assert_eq!(big, 1);
+    /// assert_eq!(cursor.parse_u16().unwrap(), 1);
     /// ```
-    pub fn count<U, F>(&mut self, mut parser: F, count: usize) -> Result<Vec<U>, BinaryCursorError>
+    pub fn with_endianness<U, F>(
+        &mut self,
+        endianness: Endianness,
+        parser: F,
+    ) -> Result<U, BinaryCursorError>
     where
-        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
     {
-        let mut items = Vec::with_capacity(count);
-        for _ in 0..count {
-            items.push(parser(self)?);
-        }
-        Ok(items)
+        let previous = std::mem::replace(&mut self.options.endianness, endianness);
+        let result = parser(self);
+        self.options.endianness = previous;
+        result
     }
-}
-// endregion: Cursor implementation
 
-// region: CursorJump implementation
-/// A helper type for temporary position changes
-///
-/// This type provides a way to temporarily change the position of a `BinaryCursor`
-/// and automatically restore it when the `BinaryCursorJump` is dropped.
-/// Works with any type T that implements `AsRef<[u8]>`.
-pub struct BinaryCursorJump<'a, T: AsRef<[u8]>> {
-    /// Reference to the cursor being manipulated
-    pub cursor: &'a mut BinaryCursor<T>,
-}
+    /// Saves the current position to the location stack
+    pub fn push_location(&mut self) {
+        self.location_stack.push(self.data.position());
+    }
 
-impl<'a, T> BinaryCursorJump<'a, T>
-where
-    T: AsRef<[u8]>,
-{
-    /// Creates a new `BinaryCursorJump` for the given cursor
-    pub fn new(cursor: &'a mut BinaryCursor<T>) -> Self {
-        Self { cursor }
+    /// Removes and returns the most recently saved position from the location stack
+    pub fn pop_location(&mut self) -> Option<u64> {
+        self.location_stack.pop()
     }
 
-    /// Temporarily jumps to the specified position
+    /// Restores the most recently saved position from the location stack
     ///
-    /// The position will be automatically restored when the `BinaryCursorJump` is dropped.
-    pub fn jump(&mut self, location: u64) -> Result<(), BinaryCursorError> {
-        self.cursor.push_location();
-        self.cursor.set_position(location);
-        Ok(())
+    /// Returns `true` if a position was restored, `false` if the stack was empty
+    pub fn restore_location(&mut self) -> bool {
+        if let Some(pos) = self.location_stack.pop() {
+            self.data.set_position(pos);
+            true
+        } else {
+            false
+        }
     }
 
-    /// Temporarily jumps to a position relative to the current cursor location
+    /// Restores the most recently saved position, returning an error if the stack is empty
     ///
-    /// The position will be automatically restored when the `BinaryCursorJump` is dropped.
-    /// A positive offset moves forward, while a negative offset moves backward.
+    /// This is for code where an unbalanced restore is always a bug that should be surfaced,
+    /// rather than ignored as with [`restore_location`](Self::restore_location).
+    pub fn try_restore_location(&mut self) -> Result<(), BinaryCursorError> {
+        if self.restore_location() {
+            Ok(())
+        } else {
+            Err(BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Cannot restore location: the location stack is empty",
+            )))
+        }
+    }
+
+    /// Returns the number of positions saved on the location stack
+    pub fn location_depth(&self) -> usize {
+        self.location_stack.len()
+    }
+
+    /// Returns the most recently saved position without removing it
+    pub fn peek_location(&self) -> Option<u64> {
+        self.location_stack.last().copied()
+    }
+
+    /// Removes every saved position from the location stack, without moving the cursor
+    pub fn clear_locations(&mut self) {
+        self.location_stack.clear();
+    }
+
+    /// Discards saved positions until the location stack is `depth` entries deep
+    ///
+    /// This does nothing if the stack is already no deeper than `depth`, and does not move the
+    /// cursor. Together with [`location_depth`](Self::location_depth) it lets a fallible
+    /// section that pushes locations restore the stack afterwards.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, BinaryCursorJump};
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
     ///
-    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let data = vec![0x01, 0x02, 0x03];
     /// let mut cursor = BinaryCursor::new(data);
-    /// cursor.set_position(1);
     ///
-    /// {
-    ///     let mut jump = BinaryCursorJump::new(&mut cursor);
-    ///     jump.jump_relative(2).unwrap();
-    ///     assert_eq!(jump.cursor.position(), 3);
-    /// }
-    /// assert_eq!(cursor.position(), 1);
+    /// let depth = cursor.location_depth();
+    /// cursor.push_location();
+    /// cursor.set_position(2);
+    /// cursor.push_location();
+    /// // ... a parse fails before either location is restored
+    /// cursor.truncate_locations(depth);
+    /// assert_eq!(cursor.location_depth(), 0);
+    /// assert_eq!(cursor.position(), 2);
     /// ```
-    pub fn jump_relative(&mut self, offset: i64) -> Result<(), BinaryCursorError> {
-        self.cursor.push_location();
-        let current_pos = self.cursor.position();
-        let new_pos = if offset >= 0 {
-            current_pos.checked_add(offset as u64)
-        } else {
-            current_pos.checked_sub(offset.unsigned_abs())
+    pub fn truncate_locations(&mut self, depth: usize) {
+        self.location_stack.truncate(depth);
+    }
+
+    /// Saves the current position under `name`, replacing any bookmark with the same name
+    ///
+    /// Bookmarks are independent of the location stack and can be revisited in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// cursor.set_position(1);
+    /// cursor.set_bookmark("strings");
+    /// cursor.set_position(3);
+    ///
+    /// cursor.goto_bookmark("strings").unwrap();
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0x02);
+    /// ```
+    pub fn set_bookmark(&mut self, name: &str) {
+        self.bookmarks
+            .insert(name.to_string(), self.data.position());
+    }
+
+    /// Returns the position saved under `name`, if there is one
+    pub fn bookmark(&self, name: &str) -> Option<u64> {
+        self.bookmarks.get(name).copied()
+    }
+
+    /// Moves to the position saved under `name`
+    ///
+    /// Returns an error and leaves the position unchanged if there is no such bookmark.
+    pub fn goto_bookmark(&mut self, name: &str) -> Result<(), BinaryCursorError> {
+        let position = self.bookmark_or_err(name)?;
+        self.data.set_position(position);
+        Ok(())
+    }
+
+    /// Removes the bookmark `name`, returning the position it held
+    pub fn remove_bookmark(&mut self, name: &str) -> Option<u64> {
+        self.bookmarks.remove(name)
+    }
+
+    /// Looks up the bookmark `name`, returning an error if it does not exist
+    fn bookmark_or_err(&self, name: &str) -> Result<u64, BinaryCursorError> {
+        self.bookmark(name).ok_or_else(|| {
+            BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Unknown bookmark '{}'", name),
+            ))
+        })
+    }
+
+    /// Reads `N` bytes from the current position, checking alignment if it is enforced
+    #[inline]
+    fn read_array<const N: usize>(
+        &mut self,
+        operation: &'static str,
+    ) -> Result<[u8; N], BinaryCursorError> {
+        if self.options.enforce_alignment && !self.data.position().is_multiple_of(N as u64) {
+            return Err(self.misaligned(N, operation));
         }
-        .ok_or_else(|| {
+        self.read_array_unaligned(operation)
+    }
+
+    /// Reads `N` bytes from the current position for `operation`, without an alignment check
+    ///
+    /// The bytes are copied straight out of the underlying slice, and the error is only
+    /// constructed when the bounds check fails. On error the position is left unchanged.
+    #[inline]
+    pub(crate) fn read_array_unaligned<const N: usize>(
+        &mut self,
+        operation: &'static str,
+    ) -> Result<[u8; N], BinaryCursorError> {
+        let position = self.data.position();
+        let data = self.data.get_ref().as_ref();
+        let bytes = usize::try_from(position)
+            .ok()
+            .and_then(|start| data.get(start..start.checked_add(N)?));
+        match bytes {
+            Some(bytes) => {
+                let buf = bytes.try_into().expect("the slice is exactly N bytes");
+                self.data.set_position(position + N as u64);
+                Ok(buf)
+            }
+            None => Err(self.eof(N, operation)),
+        }
+    }
+
+    /// Builds the error for a read of `len` bytes that runs past the end of the data
+    #[cold]
+    #[inline(never)]
+    fn eof(&self, len: usize, operation: &'static str) -> BinaryCursorError {
+        BinaryCursorError::UnexpectedEof {
+            operation,
+            position: self.data.position(),
+            requested: len,
+            available: self.unread().len(),
+        }
+    }
+
+    /// Builds the error for a read of `len` bytes that does not start on a multiple of `len`
+    #[cold]
+    #[inline(never)]
+    fn misaligned(&self, len: usize, operation: &'static str) -> BinaryCursorError {
+        BinaryCursorError::InvalidData {
+            operation,
+            position: self.data.position(),
+            message: format!("position is not aligned to {} bytes", len),
+        }
+    }
+
+    /// Returns the next `len` bytes without consuming them, or an EOF error for `operation`
+    #[inline]
+    fn peek_slice(&self, len: usize, operation: &'static str) -> Result<&[u8], BinaryCursorError> {
+        match self.unread().get(..len) {
+            Some(bytes) => Ok(bytes),
+            None => Err(self.eof(len, operation)),
+        }
+    }
+
+    /// Fills `buf` from the current position and advances past the bytes read
+    ///
+    /// On error the position is left unchanged.
+    fn read_into(
+        &mut self,
+        buf: &mut [u8],
+        operation: &'static str,
+    ) -> Result<(), BinaryCursorError> {
+        buf.copy_from_slice(self.peek_slice(buf.len(), operation)?);
+        self.data
+            .set_position(self.data.position() + buf.len() as u64);
+        Ok(())
+    }
+
+    /// Checks a caller-sized read of `len` bytes at `position` against the allocation limit
+    fn check_alloc(
+        &self,
+        len: u64,
+        operation: &'static str,
+        position: u64,
+    ) -> Result<(), BinaryCursorError> {
+        match self.options.max_alloc {
+            Some(max) if len > max as u64 => Err(BinaryCursorError::InvalidData {
+                operation,
+                position,
+                message: format!("{} bytes exceeds allocation limit of {} bytes", len, max),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parses a single u8 from the current position
+    pub fn parse_u8(&mut self) -> Result<u8, BinaryCursorError> {
+        self.traced("parse_u8", |cursor| {
+            Ok(cursor.read_array::<1>("parse_u8")?[0])
+        })
+    }
+
+    /// Parses a u16 in little-endian format from the current position
+    pub fn parse_u16_le(&mut self) -> Result<u16, BinaryCursorError> {
+        self.traced("parse_u16_le", |cursor| {
+            Ok(u16::from_le_bytes(cursor.read_array("parse_u16_le")?))
+        })
+    }
+
+    /// Parses a u32 in little-endian format from the current position
+    pub fn parse_u32_le(&mut self) -> Result<u32, BinaryCursorError> {
+        self.traced("parse_u32_le", |cursor| {
+            Ok(u32::from_le_bytes(cursor.read_array("parse_u32_le")?))
+        })
+    }
+
+    /// Parses a u64 in little-endian format from the current position
+    pub fn parse_u64_le(&mut self) -> Result<u64, BinaryCursorError> {
+        self.traced("parse_u64_le", |cursor| {
+            Ok(u64::from_le_bytes(cursor.read_array("parse_u64_le")?))
+        })
+    }
+
+    /// Parses an f32 in little-endian format from the current position
+    pub fn parse_f32_le(&mut self) -> Result<f32, BinaryCursorError> {
+        self.traced("parse_f32_le", |cursor| {
+            Ok(f32::from_le_bytes(cursor.read_array("parse_f32_le")?))
+        })
+    }
+
+    /// Parses an f64 (double precision) in little-endian format from the current position
+    pub fn parse_f64_le(&mut self) -> Result<f64, BinaryCursorError> {
+        self.traced("parse_f64_le", |cursor| {
+            Ok(f64::from_le_bytes(cursor.read_array("parse_f64_le")?))
+        })
+    }
+
+    /// Parses `N` bytes from the current position into a fixed-size array
+    ///
+    /// Unlike [`parse_bytes`](Self::parse_bytes) this does not allocate, and it is not subject
+    /// to alignment checks. On error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0xCA, 0xFE, 0xBA, 0xBE, 0x01];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let magic: [u8; 4] = cursor.parse_array().unwrap();
+    /// assert_eq!(magic, [0xCA, 0xFE, 0xBA, 0xBE]);
+    /// ```
+    pub fn parse_array<const N: usize>(&mut self) -> Result<[u8; N], BinaryCursorError> {
+        self.traced("parse_array", |cursor| {
+            cursor.read_array_unaligned("parse_array")
+        })
+    }
+
+    /// Parses a specified number of bytes from the current position
+    ///
+    /// The bytes are bounds-checked before anything is allocated, and if the cursor has an
+    /// allocation limit, a `count` above it is rejected.
+    pub fn parse_bytes(&mut self, count: usize) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_bytes", |cursor| {
+            cursor.check_alloc(count as u64, "parse_bytes", cursor.data.position())?;
+            let buf = cursor.peek_slice(count, "parse_bytes")?.to_vec();
+            cursor
+                .data
+                .set_position(cursor.data.position() + count as u64);
+            Ok(buf)
+        })
+    }
+
+    /// Parses `count` little-endian items of `N` bytes each, converting them with `convert`
+    ///
+    /// The whole run is bounds-checked and checked against the allocation limit before the
+    /// output is allocated. A `count` whose byte length overflows is rejected as invalid
+    /// input. On error the position is left unchanged.
+    fn parse_slice_le<U, const N: usize>(
+        &mut self,
+        count: usize,
+        operation: &'static str,
+        convert: fn([u8; N]) -> U,
+    ) -> Result<Vec<U>, BinaryCursorError> {
+        let position = self.data.position();
+        let byte_len = count.checked_mul(N).ok_or_else(|| {
             BinaryCursorError::ParseError(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
-                "Position would overflow/underflow",
+                format!("length of {} items of {} bytes is too large", count, N),
             ))
         })?;
-        self.cursor.set_position(new_pos);
-        Ok(())
+        self.check_alloc(byte_len as u64, operation, position)?;
+
+        let items = self
+            .peek_slice(byte_len, operation)?
+            .chunks_exact(N)
+            .map(|chunk| convert(chunk.try_into().expect("chunks are exactly N bytes")))
+            .collect();
+        self.data.set_position(position + byte_len as u64);
+        Ok(items)
+    }
+
+    /// Parses `count` little-endian u16s from the current position in one bounds-checked read
+    ///
+    /// This is much faster than calling [`parse_u16_le`](Self::parse_u16_le) through
+    /// [`count`](Self::count), and a `count` larger than the remaining data fails before
+    /// anything is allocated. On error the position is left unchanged.
+    pub fn parse_u16_slice_le(&mut self, count: usize) -> Result<Vec<u16>, BinaryCursorError> {
+        self.traced("parse_u16_slice_le", |cursor| {
+            cursor.parse_slice_le(count, "parse_u16_slice_le", u16::from_le_bytes)
+        })
+    }
+
+    /// Parses `count` little-endian u32s from the current position in one bounds-checked read
+    pub fn parse_u32_slice_le(&mut self, count: usize) -> Result<Vec<u32>, BinaryCursorError> {
+        self.traced("parse_u32_slice_le", |cursor| {
+            cursor.parse_slice_le(count, "parse_u32_slice_le", u32::from_le_bytes)
+        })
+    }
+
+    /// Parses `count` little-endian i16s from the current position in one bounds-checked read
+    pub fn parse_i16_slice_le(&mut self, count: usize) -> Result<Vec<i16>, BinaryCursorError> {
+        self.traced("parse_i16_slice_le", |cursor| {
+            cursor.parse_slice_le(count, "parse_i16_slice_le", i16::from_le_bytes)
+        })
+    }
+
+    /// Parses `count` little-endian f32s from the current position in one bounds-checked read
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0xC0];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_f32_slice_le(2).unwrap(), vec![1.0, -2.0]);
+    /// assert!(cursor.at_end());
+    /// ```
+    pub fn parse_f32_slice_le(&mut self, count: usize) -> Result<Vec<f32>, BinaryCursorError> {
+        self.traced("parse_f32_slice_le", |cursor| {
+            cursor.parse_slice_le(count, "parse_f32_slice_le", f32::from_le_bytes)
+        })
+    }
+
+    /// Parses an i8 from the current position
+    pub fn parse_i8(&mut self) -> Result<i8, BinaryCursorError> {
+        self.traced("parse_i8", |cursor| {
+            Ok(i8::from_le_bytes(cursor.read_array("parse_i8")?))
+        })
+    }
+
+    /// Parses an i16 in little-endian format from the current position
+    pub fn parse_i16_le(&mut self) -> Result<i16, BinaryCursorError> {
+        self.traced("parse_i16_le", |cursor| {
+            Ok(i16::from_le_bytes(cursor.read_array("parse_i16_le")?))
+        })
+    }
+
+    /// Parses an i32 in little-endian format from the current position
+    pub fn parse_i32_le(&mut self) -> Result<i32, BinaryCursorError> {
+        self.traced("parse_i32_le", |cursor| {
+            Ok(i32::from_le_bytes(cursor.read_array("parse_i32_le")?))
+        })
+    }
+
+    /// Parses an i64 in little-endian format from the current position
+    pub fn parse_i64_le(&mut self) -> Result<i64, BinaryCursorError> {
+        self.traced("parse_i64_le", |cursor| {
+            Ok(i64::from_le_bytes(cursor.read_array("parse_i64_le")?))
+        })
+    }
+
+    /// Parses a u16 in the cursor's configured byte order from the current position
+    pub fn parse_u16(&mut self) -> Result<u16, BinaryCursorError> {
+        self.traced("parse_u16", |cursor| {
+            let buf = cursor.read_array("parse_u16")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => u16::from_le_bytes(buf),
+                Endianness::Big => u16::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses a u32 in the cursor's configured byte order from the current position
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursorBuilder, Endianness};
+    ///
+    /// let data = vec![0x00, 0x00, 0x01, 0x02];
+    /// let mut cursor = BinaryCursorBuilder::new()
+    ///     .endianness(Endianness::Big)
+    ///     .build(data)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cursor.parse_u32().unwrap(), 0x0102);
+    /// ```
+    pub fn parse_u32(&mut self) -> Result<u32, BinaryCursorError> {
+        self.traced("parse_u32", |cursor| {
+            let buf = cursor.read_array("parse_u32")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => u32::from_le_bytes(buf),
+                Endianness::Big => u32::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses a u64 in the cursor's configured byte order from the current position
+    pub fn parse_u64(&mut self) -> Result<u64, BinaryCursorError> {
+        self.traced("parse_u64", |cursor| {
+            let buf = cursor.read_array("parse_u64")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => u64::from_le_bytes(buf),
+                Endianness::Big => u64::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses an i16 in the cursor's configured byte order from the current position
+    pub fn parse_i16(&mut self) -> Result<i16, BinaryCursorError> {
+        self.traced("parse_i16", |cursor| {
+            let buf = cursor.read_array("parse_i16")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => i16::from_le_bytes(buf),
+                Endianness::Big => i16::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses an i32 in the cursor's configured byte order from the current position
+    pub fn parse_i32(&mut self) -> Result<i32, BinaryCursorError> {
+        self.traced("parse_i32", |cursor| {
+            let buf = cursor.read_array("parse_i32")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => i32::from_le_bytes(buf),
+                Endianness::Big => i32::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses an i64 in the cursor's configured byte order from the current position
+    pub fn parse_i64(&mut self) -> Result<i64, BinaryCursorError> {
+        self.traced("parse_i64", |cursor| {
+            let buf = cursor.read_array("parse_i64")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => i64::from_le_bytes(buf),
+                Endianness::Big => i64::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses an f32 in the cursor's configured byte order from the current position
+    pub fn parse_f32(&mut self) -> Result<f32, BinaryCursorError> {
+        self.traced("parse_f32", |cursor| {
+            let buf = cursor.read_array("parse_f32")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => f32::from_le_bytes(buf),
+                Endianness::Big => f32::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses an f64 in the cursor's configured byte order from the current position
+    pub fn parse_f64(&mut self) -> Result<f64, BinaryCursorError> {
+        self.traced("parse_f64", |cursor| {
+            let buf = cursor.read_array("parse_f64")?;
+            Ok(match cursor.options.endianness {
+                Endianness::Little => f64::from_le_bytes(buf),
+                Endianness::Big => f64::from_be_bytes(buf),
+            })
+        })
+    }
+
+    /// Parses an IEEE 754 half-precision float in little-endian format, widened to an f32
+    ///
+    /// Every half-precision value, including subnormals, infinities and NaN, is exactly
+    /// representable as an f32.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x00, 0x3C, 0x00, 0xC1];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_f16_le().unwrap(), 1.0);
+    /// assert_eq!(cursor.parse_f16_le().unwrap(), -2.5);
+    /// ```
+    pub fn parse_f16_le(&mut self) -> Result<f32, BinaryCursorError> {
+        self.traced("parse_f16_le", |cursor| {
+            Ok(f16_to_f32(u16::from_le_bytes(
+                cursor.read_array("parse_f16_le")?,
+            )))
+        })
+    }
+
+    /// Parses an IEEE 754 half-precision float in big-endian format, widened to an f32
+    pub fn parse_f16_be(&mut self) -> Result<f32, BinaryCursorError> {
+        self.traced("parse_f16_be", |cursor| {
+            Ok(f16_to_f32(u16::from_be_bytes(
+                cursor.read_array("parse_f16_be")?,
+            )))
+        })
+    }
+
+    /// Parses an unsigned integer stored in `num_bytes` bytes (1 to 8) with the given byte order
+    ///
+    /// This is useful for formats where the width of an integer field is only known at
+    /// runtime, such as SQLite serial types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, Endianness};
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_uint(3, Endianness::Big).unwrap(), 0x010203);
+    /// ```
+    pub fn parse_uint(
+        &mut self,
+        num_bytes: usize,
+        endianness: Endianness,
+    ) -> Result<u64, BinaryCursorError> {
+        self.traced("parse_uint", |cursor| {
+            if num_bytes == 0 || num_bytes > 8 {
+                return Err(BinaryCursorError::ParseError(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Integer width must be between 1 and 8 bytes, got {}",
+                        num_bytes
+                    ),
+                )));
+            }
+
+            let mut buf = [0u8; 8];
+            match endianness {
+                Endianness::Little => {
+                    cursor.read_into(&mut buf[..num_bytes], "parse_uint")?;
+                    Ok(u64::from_le_bytes(buf))
+                }
+                Endianness::Big => {
+                    cursor.read_into(&mut buf[8 - num_bytes..], "parse_uint")?;
+                    Ok(u64::from_be_bytes(buf))
+                }
+            }
+        })
+    }
+
+    /// Parses a signed integer stored in `num_bytes` bytes (1 to 8) with the given byte order
+    ///
+    /// The value is sign-extended from the top bit of the field, so a 3-byte field
+    /// containing `0xFFFFFF` yields `-1`.
+    pub fn parse_int(
+        &mut self,
+        num_bytes: usize,
+        endianness: Endianness,
+    ) -> Result<i64, BinaryCursorError> {
+        self.traced("parse_int", |cursor| {
+            let value = cursor.parse_uint(num_bytes, endianness)?;
+            let shift = 64 - (num_bytes as u32 * 8);
+            Ok(((value << shift) as i64) >> shift)
+        })
+    }
+
+    /// Parses a u8 and returns it as a [`BitField`] for reading individual flags
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// // TCP flags with SYN and ACK set
+    /// let data = vec![0x12];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let flags = cursor.parse_u8_bits().unwrap();
+    /// assert!(flags.bit(1).unwrap()); // SYN
+    /// assert!(flags.bit(4).unwrap()); // ACK
+    /// assert!(!flags.bit(0).unwrap()); // FIN
+    /// ```
+    pub fn parse_u8_bits(&mut self) -> Result<BitField<u8>, BinaryCursorError> {
+        self.traced("parse_u8_bits", |cursor| {
+            Ok(BitField::new(cursor.parse_u8()?))
+        })
+    }
+
+    /// Parses a little-endian u16 and returns it as a [`BitField`]
+    pub fn parse_u16_bits_le(&mut self) -> Result<BitField<u16>, BinaryCursorError> {
+        self.traced("parse_u16_bits_le", |cursor| {
+            Ok(BitField::new(cursor.parse_u16_le()?))
+        })
+    }
+
+    /// Parses a big-endian u16 and returns it as a [`BitField`]
+    pub fn parse_u16_bits_be(&mut self) -> Result<BitField<u16>, BinaryCursorError> {
+        self.traced("parse_u16_bits_be", |cursor| {
+            Ok(BitField::new(u16::from_be_bytes(
+                cursor.read_array("parse_u16_bits_be")?,
+            )))
+        })
+    }
+
+    /// Parses a little-endian u32 and returns it as a [`BitField`]
+    pub fn parse_u32_bits_le(&mut self) -> Result<BitField<u32>, BinaryCursorError> {
+        self.traced("parse_u32_bits_le", |cursor| {
+            Ok(BitField::new(cursor.parse_u32_le()?))
+        })
+    }
+
+    /// Parses a big-endian u32 and returns it as a [`BitField`]
+    pub fn parse_u32_bits_be(&mut self) -> Result<BitField<u32>, BinaryCursorError> {
+        self.traced("parse_u32_bits_be", |cursor| {
+            Ok(BitField::new(u32::from_be_bytes(
+                cursor.read_array("parse_u32_bits_be")?,
+            )))
+        })
+    }
+
+    /// Parses `total_bytes` bytes as one little-endian integer and splits it into bit fields
+    ///
+    /// Fields are given as `(name, width)` pairs and are extracted consecutively from bit 0
+    /// (the least significant bit) upward, which is the convention used by on-disk
+    /// structures such as FAT date/time stamps. The names are only used in error messages.
+    /// If the widths add up to more than `total_bytes * 8` bits, an error is returned before
+    /// anything is read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// // FAT date for 2021-06-15
+    /// let data = vec![0xCF, 0x52];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let fields = cursor
+    ///     .parse_packed_le(2, &[("day", 5), ("month", 4), ("year", 7)])
+    ///     .unwrap();
+    /// assert_eq!(fields, vec![15, 6, 41]);
+    /// ```
+    pub fn parse_packed_le(
+        &mut self,
+        total_bytes: usize,
+        fields: &[(&'static str, u8)],
+    ) -> Result<Vec<u64>, BinaryCursorError> {
+        self.traced("parse_packed_le", |cursor| {
+            let invalid = |message| {
+                Err(BinaryCursorError::ParseError(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    message,
+                )))
+            };
+            let Some(total_bits) = total_bytes.checked_mul(8) else {
+                return invalid(format!("{} bytes is too many to read as bits", total_bytes));
+            };
+            let mut end_bit = 0usize;
+            for (name, width) in fields {
+                end_bit += *width as usize;
+                if end_bit > total_bits {
+                    return invalid(format!(
+                        "Field '{}' ends at bit {} but only {} bits are being read",
+                        name, end_bit, total_bits
+                    ));
+                }
+            }
+
+            let mut value = cursor.parse_uint(total_bytes, Endianness::Little)?;
+            Ok(fields
+                .iter()
+                .map(|(_, width)| {
+                    // A 64-bit wide field uses the whole value, which cannot be expressed as a mask
+                    let field = match width {
+                        64 => value,
+                        _ => value & ((1u64 << width) - 1),
+                    };
+                    value = value.checked_shr(*width as u32).unwrap_or(0);
+                    field
+                })
+                .collect())
+        })
+    }
+
+    /// Parses `num_bytes` bytes of packed binary-coded decimal, high nibble first
+    ///
+    /// Each nibble holds one decimal digit, so this is the encoding used for numeric fields
+    /// in formats such as ISO 8583. A nibble above 9, or a value too large for a u64, is
+    /// reported as invalid data at the offending byte. On error the position is left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// // ISO 8583 amount "000000012345"
+    /// let data = vec![0x00, 0x00, 0x00, 0x01, 0x23, 0x45];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_bcd(6).unwrap(), 12345);
+    /// ```
+    pub fn parse_bcd(&mut self, num_bytes: usize) -> Result<u64, BinaryCursorError> {
+        self.traced("parse_bcd", |cursor| {
+            let start = cursor.data.position();
+            let bytes = cursor.peek_slice(num_bytes, "parse_bcd")?;
+
+            let mut value = 0u64;
+            for (i, byte) in bytes.iter().enumerate() {
+                let position = start + i as u64;
+                for digit in [byte >> 4, byte & 0x0F] {
+                    if digit > 9 {
+                        return Err(invalid_bcd_digit("parse_bcd", position, digit));
+                    }
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(digit as u64))
+                        .ok_or_else(|| BinaryCursorError::InvalidData {
+                            operation: "parse_bcd",
+                            position,
+                            message: "BCD value does not fit in u64".to_string(),
+                        })?;
+                }
+            }
+
+            cursor.data.set_position(start + num_bytes as u64);
+            Ok(value)
+        })
+    }
+
+    /// Parses `num_bytes` bytes of nibble-swapped decimal semi-octets as a digit string
+    ///
+    /// This is the encoding used for phone numbers in GSM SMS PDUs: the low nibble of each
+    /// byte holds the first digit and the high nibble the second. A nibble of `0xF` is a
+    /// filler that ends the number, and only further fillers may follow it. Digits are
+    /// returned as a string because leading zeros are significant. On error the position is
+    /// left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x13, 0x46, 0x61, 0x00, 0x89, 0xF6];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_semi_octets(6).unwrap(), "31641600986");
+    /// ```
+    pub fn parse_semi_octets(&mut self, num_bytes: usize) -> Result<String, BinaryCursorError> {
+        self.traced("parse_semi_octets", |cursor| {
+            let start = cursor.data.position();
+            let bytes = cursor.peek_slice(num_bytes, "parse_semi_octets")?;
+
+            let mut digits = String::with_capacity(num_bytes * 2);
+            let mut filled = false;
+            for (i, byte) in bytes.iter().enumerate() {
+                let position = start + i as u64;
+                for digit in [byte & 0x0F, byte >> 4] {
+                    match digit {
+                        0x0F => filled = true,
+                        _ if filled => {
+                            return Err(BinaryCursorError::InvalidData {
+                                operation: "parse_semi_octets",
+                                position,
+                                message: format!("digit {:X} follows a 0xF filler", digit),
+                            });
+                        }
+                        0..=9 => digits.push((b'0' + digit) as char),
+                        _ => return Err(invalid_bcd_digit("parse_semi_octets", position, digit)),
+                    }
+                }
+            }
+
+            cursor.data.set_position(start + num_bytes as u64);
+            Ok(digits)
+        })
+    }
+
+    /// Returns the bytes from the current position to the end of the data
+    ///
+    /// This is empty if the position has been set past the end.
+    #[inline]
+    fn unread(&self) -> &[u8] {
+        let data = self.data.get_ref().as_ref();
+        usize::try_from(self.data.position())
+            .ok()
+            .and_then(|pos| data.get(pos..))
+            .unwrap_or(&[])
+    }
+
+    /// Consumes `expected` from the current position, or returns an error if the data differs
+    ///
+    /// This is intended for magic numbers and signatures. On a mismatch the error message
+    /// contains both the expected and the actual bytes in hex, and the position is left
+    /// unchanged so that an alternative signature can be tried. Data too short to hold the
+    /// tag is reported as an unexpected EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"GIF89a";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert!(cursor.expect_tag(b"GIF87a").is_err());
+    /// cursor.expect_tag(b"GIF89a").unwrap();
+    /// assert_eq!(cursor.position(), 6);
+    /// ```
+    pub fn expect_tag(&mut self, expected: &[u8]) -> Result<(), BinaryCursorError> {
+        self.traced("expect_tag", |cursor| {
+            let position = cursor.data.position();
+            let remaining = cursor.unread();
+            let actual =
+                remaining
+                    .get(..expected.len())
+                    .ok_or(BinaryCursorError::UnexpectedEof {
+                        operation: "expect_tag",
+                        position,
+                        requested: expected.len(),
+                        available: remaining.len(),
+                    })?;
+
+            if actual != expected {
+                return Err(BinaryCursorError::InvalidData {
+                    operation: "expect_tag",
+                    position,
+                    message: format!(
+                        "expected tag [{}], found [{}]",
+                        hex_bytes(expected),
+                        hex_bytes(actual)
+                    ),
+                });
+            }
+
+            cursor.data.set_position(position + expected.len() as u64);
+            Ok(())
+        })
+    }
+
+    /// Consumes `expected` if the data at the current position starts with it
+    ///
+    /// Returns `false` without moving the position if the bytes differ or there are not
+    /// enough of them, which makes this suitable for sniffing between several formats.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"II*\0";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert!(!cursor.matches_tag(b"MM\0*").unwrap());
+    /// assert!(cursor.matches_tag(b"II*\0").unwrap());
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn matches_tag(&mut self, expected: &[u8]) -> Result<bool, BinaryCursorError> {
+        self.traced("matches_tag", |cursor| {
+            if !cursor.unread().starts_with(expected) {
+                return Ok(false);
+            }
+
+            cursor
+                .data
+                .set_position(cursor.data.position() + expected.len() as u64);
+            Ok(true)
+        })
+    }
+
+    /// Returns the absolute offset of the next occurrence of `needle`, searching forward
+    /// from the current position
+    ///
+    /// A match at the current position is found, as is one ending exactly at the end of the
+    /// data. An empty needle matches at the current position. The position is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"junk\xFF\xD8\xFFjpeg";
+    /// let cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert_eq!(cursor.find(b"\xFF\xD8\xFF"), Some(4));
+    /// assert_eq!(cursor.find(b"png"), None);
+    /// assert_eq!(cursor.position(), 0);
+    /// ```
+    pub fn find(&self, needle: &[u8]) -> Option<u64> {
+        let position = self.data.position();
+        if needle.is_empty() {
+            return Some(position);
+        }
+
+        self.unread()
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .map(|offset| position + offset as u64)
+    }
+
+    /// Moves to the next occurrence of `needle` and returns its absolute offset
+    ///
+    /// This is [`find`](Self::find) followed by a move to the match, which is useful for
+    /// resynchronizing on a marker in a corrupt stream. If there is no match, an error is
+    /// returned and the position is left unchanged.
+    pub fn seek_to(&mut self, needle: &[u8]) -> Result<u64, BinaryCursorError> {
+        let offset = self
+            .find(needle)
+            .ok_or_else(|| BinaryCursorError::InvalidData {
+                operation: "seek_to",
+                position: self.data.position(),
+                message: format!("pattern [{}] not found", hex_bytes(needle)),
+            })?;
+        self.data.set_position(offset);
+        Ok(offset)
+    }
+
+    /// Returns the bytes from the current position up to, but not including, the next
+    /// occurrence of `delimiter`
+    ///
+    /// The cursor is left at the start of the delimiter. A multi-byte delimiter is only
+    /// matched as a whole. If the delimiter does not occur before the end of the data, an EOF
+    /// error is returned and the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"Host: a\rb\r\n";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert_eq!(cursor.take_until(b"\r\n").unwrap(), b"Host: a\rb");
+    /// assert_eq!(cursor.position(), 9);
+    /// ```
+    pub fn take_until(&mut self, delimiter: &[u8]) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("take_until", |cursor| {
+            cursor.take_until_delimiter(delimiter, false, "take_until")
+        })
+    }
+
+    /// Returns the bytes up to the next occurrence of `delimiter` and moves past the delimiter
+    ///
+    /// This behaves like [`take_until`](Self::take_until), except that on success the cursor
+    /// is left just after the delimiter, which is not included in the result.
+    pub fn take_until_and_consume(
+        &mut self,
+        delimiter: &[u8],
+    ) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("take_until_and_consume", |cursor| {
+            cursor.take_until_delimiter(delimiter, true, "take_until_and_consume")
+        })
+    }
+
+    /// Returns the bytes up to the next occurrence of the single byte `delimiter`
+    ///
+    /// The cursor is left at the delimiter, as with [`take_until`](Self::take_until).
+    pub fn take_until_byte(&mut self, delimiter: u8) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("take_until_byte", |cursor| {
+            cursor.take_until_delimiter(&[delimiter], false, "take_until_byte")
+        })
+    }
+
+    /// Implements the `take_until` family, optionally consuming the delimiter
+    fn take_until_delimiter(
+        &mut self,
+        delimiter: &[u8],
+        consume: bool,
+        operation: &'static str,
+    ) -> Result<Vec<u8>, BinaryCursorError> {
+        let start = self.data.position();
+        let Some(offset) = self.find(delimiter) else {
+            let available = self.unread().len();
+            return Err(BinaryCursorError::UnexpectedEof {
+                operation,
+                position: start,
+                requested: available + delimiter.len(),
+                available,
+            });
+        };
+
+        let bytes = self.unread()[..(offset - start) as usize].to_vec();
+        let end = if consume {
+            offset + delimiter.len() as u64
+        } else {
+            offset
+        };
+        self.data.set_position(end);
+        Ok(bytes)
+    }
+
+    /// Parses an unsigned LEB128 (varint) value from the current position
+    ///
+    /// The cursor advances by exactly the number of bytes in the encoding. Encodings longer
+    /// than 10 bytes, or whose value does not fit in a u64, are rejected rather than
+    /// silently wrapped, and running out of data mid-value is an error. In strict mode an
+    /// encoding with redundant trailing zero groups is also rejected. On error the position
+    /// is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0xE5, 0x8E, 0x26];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_uleb128().unwrap(), 624485);
+    /// assert_eq!(cursor.position(), 3);
+    /// ```
+    pub fn parse_uleb128(&mut self) -> Result<u64, BinaryCursorError> {
+        self.traced("parse_uleb128", |cursor| {
+            let start = cursor.data.position();
+            let remaining = cursor.unread();
+            let mut result = 0u64;
+
+            for (i, &byte) in remaining.iter().take(LEB128_MAX_LEN).enumerate() {
+                if i == LEB128_MAX_LEN - 1 && byte > 0x01 {
+                    return Err(BinaryCursorError::InvalidData {
+                        operation: "parse_uleb128",
+                        position: start,
+                        message: "value overflows u64".to_string(),
+                    });
+                }
+
+                result |= u64::from(byte & 0x7F) << (7 * i);
+                if byte & 0x80 == 0 {
+                    if cursor.options.strict && i > 0 && byte == 0x00 {
+                        return Err(non_canonical("parse_uleb128", start));
+                    }
+                    cursor.data.set_position(start + i as u64 + 1);
+                    return Ok(result);
+                }
+            }
+
+            Err(leb128_unterminated("parse_uleb128", start, remaining.len()))
+        })
+    }
+
+    /// Parses a signed LEB128 value from the current position
+    ///
+    /// The value is sign-extended from bit 6 of the final byte. See
+    /// [`parse_uleb128`](Self::parse_uleb128) for the length limit and error semantics; in
+    /// strict mode a final byte that only repeats the sign of the previous one is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0xC0, 0xBB, 0x78];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_sleb128().unwrap(), -123456);
+    /// ```
+    pub fn parse_sleb128(&mut self) -> Result<i64, BinaryCursorError> {
+        self.traced("parse_sleb128", |cursor| {
+            let start = cursor.data.position();
+            let remaining = cursor.unread();
+            let mut result = 0i64;
+
+            for (i, &byte) in remaining.iter().take(LEB128_MAX_LEN).enumerate() {
+                let payload = byte & 0x7F;
+                if i == LEB128_MAX_LEN - 1 && payload != 0x00 && payload != 0x7F {
+                    return Err(BinaryCursorError::InvalidData {
+                        operation: "parse_sleb128",
+                        position: start,
+                        message: "value overflows i64".to_string(),
+                    });
+                }
+
+                let shift = 7 * i as u32;
+                result |= i64::from(payload) << shift;
+                if byte & 0x80 == 0 {
+                    if cursor.options.strict && i > 0 {
+                        let previous_sign = remaining[i - 1] & 0x40;
+                        if (byte == 0x00 && previous_sign == 0)
+                            || (byte == 0x7F && previous_sign != 0)
+                        {
+                            return Err(non_canonical("parse_sleb128", start));
+                        }
+                    }
+                    if shift + 7 < 64 && payload & 0x40 != 0 {
+                        result |= -1 << (shift + 7);
+                    }
+                    cursor.data.set_position(start + i as u64 + 1);
+                    return Ok(result);
+                }
+            }
+
+            Err(leb128_unterminated("parse_sleb128", start, remaining.len()))
+        })
+    }
+
+    /// Parses a zigzag-encoded varint, as used by protobuf `sint32` and `sint64` fields
+    ///
+    /// Zigzag encoding maps signed values to unsigned ones so that small magnitudes have
+    /// short encodings: 0, -1, 1, -2 are stored as 0, 1, 2, 3.
+    pub fn parse_varint_zigzag(&mut self) -> Result<i64, BinaryCursorError> {
+        self.traced("parse_varint_zigzag", |cursor| {
+            let value = cursor.parse_uleb128()?;
+            Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+        })
+    }
+
+    /// Parses a NUL-terminated byte string from the current position
+    ///
+    /// The terminating 0x00 is consumed but not included in the result. If the end of the
+    /// data is reached before a terminator is found, an error is returned and the position
+    /// is left unchanged.
+    pub fn parse_cstr_raw(&mut self) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_cstr_raw", |cursor| {
+            cursor.read_cstr_raw(usize::MAX, "parse_cstr_raw")
+        })
+    }
+
+    /// Parses a NUL-terminated byte string of at most `max_len` bytes (excluding the terminator)
+    ///
+    /// This bounds how far the cursor will scan for a terminator, so corrupt input cannot
+    /// cause a scan to the end of a large buffer. If no terminator is found within `max_len`
+    /// bytes, or the end of the data is reached first, an error is returned and the position
+    /// is left unchanged.
+    pub fn parse_cstr_raw_max(&mut self, max_len: usize) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_cstr_raw_max", |cursor| {
+            cursor.read_cstr_raw(max_len, "parse_cstr_raw_max")
+        })
+    }
+
+    /// Reads a NUL-terminated byte string of at most `max_len` bytes for `operation`
+    ///
+    /// On error the position is left unchanged.
+    pub(crate) fn read_cstr_raw(
+        &mut self,
+        max_len: usize,
+        operation: &'static str,
+    ) -> Result<Vec<u8>, BinaryCursorError> {
+        let start = self.data.position();
+        let remaining = self.unread();
+        let window = &remaining[..remaining.len().min(max_len.saturating_add(1))];
+
+        match window.iter().position(|&b| b == 0) {
+            Some(len) => {
+                let bytes = window[..len].to_vec();
+                self.data.set_position(start + len as u64 + 1);
+                Ok(bytes)
+            }
+            None if window.len() < remaining.len() => Err(BinaryCursorError::InvalidData {
+                operation,
+                position: start,
+                message: format!("no NUL terminator within {} bytes", max_len),
+            }),
+            None => Err(BinaryCursorError::UnexpectedEof {
+                operation,
+                position: start,
+                requested: remaining.len() + 1,
+                available: remaining.len(),
+            }),
+        }
+    }
+
+    /// Parses a NUL-terminated UTF-8 string from the current position
+    ///
+    /// Invalid UTF-8 is reported as an error and leaves the position unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"hello\0world\0";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert_eq!(cursor.parse_cstr().unwrap(), "hello");
+    /// assert_eq!(cursor.parse_cstr().unwrap(), "world");
+    /// assert_eq!(cursor.position(), 12);
+    /// ```
+    pub fn parse_cstr(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_cstr", |cursor| {
+            cursor.read_cstr(usize::MAX, "parse_cstr")
+        })
+    }
+
+    /// Parses a NUL-terminated UTF-8 string of at most `max_len` bytes (excluding the terminator)
+    ///
+    /// See [`parse_cstr_raw_max`](Self::parse_cstr_raw_max) for the length cap semantics.
+    pub fn parse_cstr_max(&mut self, max_len: usize) -> Result<String, BinaryCursorError> {
+        self.traced("parse_cstr_max", |cursor| {
+            cursor.read_cstr(max_len, "parse_cstr_max")
+        })
+    }
+
+    /// Reads a NUL-terminated UTF-8 string of at most `max_len` bytes for `operation`
+    fn read_cstr(
+        &mut self,
+        max_len: usize,
+        operation: &'static str,
+    ) -> Result<String, BinaryCursorError> {
+        let start = self.data.position();
+        let bytes = self.read_cstr_raw(max_len, operation)?;
+        String::from_utf8(bytes).map_err(|e| {
+            self.data.set_position(start);
+            BinaryCursorError::InvalidData {
+                operation,
+                position: start,
+                message: e.to_string(),
+            }
+        })
+    }
+
+    /// Parses a NUL-terminated string, replacing invalid UTF-8 sequences with U+FFFD
+    pub fn parse_cstr_lossy(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_cstr_lossy", |cursor| {
+            let bytes = cursor.read_cstr_raw(usize::MAX, "parse_cstr_lossy")?;
+            Ok(String::from_utf8_lossy(&bytes).into_owned())
+        })
+    }
+
+    /// Parses a 16-byte UUID stored in the RFC 4122 big-endian layout
+    ///
+    /// On error the position is left unchanged.
+    pub fn parse_uuid_be(&mut self) -> Result<Uuid, BinaryCursorError> {
+        self.traced("parse_uuid_be", |cursor| {
+            Ok(Uuid::from_bytes(
+                cursor.read_array_unaligned("parse_uuid_be")?,
+            ))
+        })
+    }
+
+    /// Parses a 16-byte GUID stored in the Microsoft mixed-endian layout
+    ///
+    /// This is the layout used by GPT partition tables and most Windows structures: the
+    /// first three groups are little-endian and the last two are stored as they are. On
+    /// error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// // The GPT type GUID of an EFI System Partition, as stored on disk
+    /// let data = vec![
+    ///     0x28, 0x73, 0x2A, 0xC1, 0x1F, 0xF8, 0xD2, 0x11, 0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E,
+    ///     0xC9, 0x3B,
+    /// ];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let guid = cursor.parse_uuid_le_mixed().unwrap();
+    /// assert_eq!(guid.to_string(), "c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
+    /// ```
+    pub fn parse_uuid_le_mixed(&mut self) -> Result<Uuid, BinaryCursorError> {
+        self.traced("parse_uuid_le_mixed", |cursor| {
+            Ok(Uuid::from_bytes_le_mixed(
+                cursor.read_array_unaligned("parse_uuid_le_mixed")?,
+            ))
+        })
+    }
+
+    /// Parses a 4-byte IPv4 address stored in network byte order
+    ///
+    /// On error the position is left unchanged.
+    pub fn parse_ipv4(&mut self) -> Result<Ipv4Addr, BinaryCursorError> {
+        self.traced("parse_ipv4", |cursor| {
+            Ok(Ipv4Addr::from(
+                cursor.read_array_unaligned::<4>("parse_ipv4")?,
+            ))
+        })
+    }
+
+    /// Parses a 16-byte IPv6 address stored in network byte order
+    ///
+    /// On error the position is left unchanged.
+    pub fn parse_ipv6(&mut self) -> Result<Ipv6Addr, BinaryCursorError> {
+        self.traced("parse_ipv6", |cursor| {
+            Ok(Ipv6Addr::from(
+                cursor.read_array_unaligned::<16>("parse_ipv6")?,
+            ))
+        })
+    }
+
+    /// Parses an IPv4 address followed by a big-endian u16 port, as in a `sockaddr_in`
+    ///
+    /// On error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    /// use std::net::{Ipv4Addr, SocketAddrV4};
+    ///
+    /// let data = vec![192, 168, 1, 10, 0x1F, 0x90];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let addr = cursor.parse_socketaddr_v4_be().unwrap();
+    /// assert_eq!(addr, SocketAddrV4::new(Ipv4Addr::new(192, 168, 1, 10), 8080));
+    /// ```
+    pub fn parse_socketaddr_v4_be(&mut self) -> Result<SocketAddrV4, BinaryCursorError> {
+        self.traced("parse_socketaddr_v4_be", |cursor| {
+            cursor.parse_socketaddr_v4(Endianness::Big)
+        })
+    }
+
+    /// Parses an IPv4 address followed by a little-endian u16 port
+    ///
+    /// On error the position is left unchanged.
+    pub fn parse_socketaddr_v4_le(&mut self) -> Result<SocketAddrV4, BinaryCursorError> {
+        self.traced("parse_socketaddr_v4_le", |cursor| {
+            cursor.parse_socketaddr_v4(Endianness::Little)
+        })
+    }
+
+    /// Parses an IPv6 address followed by a big-endian u16 port
+    ///
+    /// Only the address and port are read; the flow information and scope ID of the result
+    /// are 0. On error the position is left unchanged.
+    pub fn parse_socketaddr_v6_be(&mut self) -> Result<SocketAddrV6, BinaryCursorError> {
+        self.traced("parse_socketaddr_v6_be", |cursor| {
+            cursor.parse_socketaddr_v6(Endianness::Big)
+        })
+    }
+
+    /// Parses an IPv6 address followed by a little-endian u16 port
+    ///
+    /// Only the address and port are read; the flow information and scope ID of the result
+    /// are 0. On error the position is left unchanged.
+    pub fn parse_socketaddr_v6_le(&mut self) -> Result<SocketAddrV6, BinaryCursorError> {
+        self.traced("parse_socketaddr_v6_le", |cursor| {
+            cursor.parse_socketaddr_v6(Endianness::Little)
+        })
+    }
+
+    /// Parses an IPv4 address followed by a u16 port in `port_endianness`
+    fn parse_socketaddr_v4(
+        &mut self,
+        port_endianness: Endianness,
+    ) -> Result<SocketAddrV4, BinaryCursorError> {
+        let [a, b, c, d, hi, lo] = self.read_array_unaligned("parse_socketaddr_v4")?;
+        Ok(SocketAddrV4::new(
+            Ipv4Addr::new(a, b, c, d),
+            port_from_bytes([hi, lo], port_endianness),
+        ))
+    }
+
+    /// Parses an IPv6 address followed by a u16 port in `port_endianness`
+    fn parse_socketaddr_v6(
+        &mut self,
+        port_endianness: Endianness,
+    ) -> Result<SocketAddrV6, BinaryCursorError> {
+        let bytes: [u8; 18] = self.read_array_unaligned("parse_socketaddr_v6")?;
+        let address: [u8; 16] = bytes[..16].try_into().expect("slice is 16 bytes");
+        Ok(SocketAddrV6::new(
+            Ipv6Addr::from(address),
+            port_from_bytes([bytes[16], bytes[17]], port_endianness),
+            0,
+            0,
+        ))
+    }
+
+    /// Parses a little-endian u32 count of seconds since the Unix epoch
+    ///
+    /// With alignment enforced, this must start at a multiple of 4 bytes, as for
+    /// [`parse_u32_le`](Self::parse_u32_le).
+    pub fn parse_unix_timestamp_u32_le(&mut self) -> Result<SystemTime, BinaryCursorError> {
+        self.traced("parse_unix_timestamp_u32_le", |cursor| {
+            let seconds = u32::from_le_bytes(cursor.read_array("parse_unix_timestamp_u32_le")?);
+            Ok(UNIX_EPOCH + Duration::from_secs(seconds.into()))
+        })
+    }
+
+    /// Parses a little-endian u64 count of seconds since the Unix epoch
+    ///
+    /// A value too large for [`SystemTime`] on this platform is an error. With alignment
+    /// enforced, the value must start at a multiple of 8 bytes. On error the position is left
+    /// unchanged.
+    pub fn parse_unix_timestamp_u64_le(&mut self) -> Result<SystemTime, BinaryCursorError> {
+        self.traced("parse_unix_timestamp_u64_le", |cursor| {
+            let position = cursor.data.position();
+            let seconds = u64::from_le_bytes(cursor.read_array("parse_unix_timestamp_u64_le")?);
+            UNIX_EPOCH
+                .checked_add(Duration::from_secs(seconds))
+                .ok_or_else(|| {
+                    cursor.data.set_position(position);
+                    BinaryCursorError::InvalidData {
+                        operation: "parse_unix_timestamp_u64_le",
+                        position,
+                        message: format!("Unix timestamp {} is out of range", seconds),
+                    }
+                })
+        })
+    }
+
+    /// Parses a little-endian Windows `FILETIME`: a u64 count of 100ns ticks since 1601-01-01
+    ///
+    /// Times before the Unix epoch are reported as an error carrying the raw tick count.
+    /// It is read as a u64, so with alignment enforced it must start at a multiple of 8
+    /// bytes. On error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// // 2000-01-01 00:00:00 UTC
+    /// let data = 125_911_584_000_000_000u64.to_le_bytes();
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// let time = cursor.parse_filetime_le().unwrap();
+    /// assert_eq!(time, UNIX_EPOCH + Duration::from_secs(946_684_800));
+    /// ```
+    pub fn parse_filetime_le(&mut self) -> Result<SystemTime, BinaryCursorError> {
+        self.traced("parse_filetime_le", |cursor| {
+            let position = cursor.data.position();
+            let ticks = u64::from_le_bytes(cursor.read_array("parse_filetime_le")?);
+            let Some(since_epoch) = ticks.checked_sub(FILETIME_UNIX_EPOCH) else {
+                cursor.data.set_position(position);
+                return Err(BinaryCursorError::InvalidData {
+                    operation: "parse_filetime_le",
+                    position,
+                    message: format!("FILETIME {} is before the Unix epoch", ticks),
+                });
+            };
+            let duration = Duration::new(
+                since_epoch / 10_000_000,
+                (since_epoch % 10_000_000) as u32 * 100,
+            );
+            UNIX_EPOCH.checked_add(duration).ok_or_else(|| {
+                cursor.data.set_position(position);
+                BinaryCursorError::InvalidData {
+                    operation: "parse_filetime_le",
+                    position,
+                    message: format!("FILETIME {} is out of range", ticks),
+                }
+            })
+        })
+    }
+
+    /// Parses a packed MS-DOS time followed by a date, each a little-endian u16
+    ///
+    /// This is the order used by ZIP headers and FAT directory entries. A month, day or time
+    /// of day outside its valid range is an error naming the raw field, and the position is
+    /// left unchanged. With alignment enforced, the pair must start at a multiple of 2 bytes,
+    /// the alignment of each u16.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, DosDateTime};
+    ///
+    /// let data = vec![0x09, 0x74, 0x97, 0x4F];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let time = cursor.parse_dos_datetime_le().unwrap();
+    /// assert_eq!((time.year, time.month, time.day), (2019, 12, 23));
+    /// assert_eq!((time.hour, time.minute, time.second), (14, 32, 18));
+    /// ```
+    pub fn parse_dos_datetime_le(&mut self) -> Result<DosDateTime, BinaryCursorError> {
+        self.traced("parse_dos_datetime_le", |cursor| {
+            let position = cursor.data.position();
+            if cursor.options.enforce_alignment && !position.is_multiple_of(2) {
+                return Err(cursor.misaligned(2, "parse_dos_datetime_le"));
+            }
+            let [t0, t1, d0, d1] = cursor.read_array_unaligned("parse_dos_datetime_le")?;
+            DosDateTime::from_packed(u16::from_le_bytes([d0, d1]), u16::from_le_bytes([t0, t1]))
+                .map_err(|message| {
+                    cursor.data.set_position(position);
+                    BinaryCursorError::InvalidData {
+                        operation: "parse_dos_datetime_le",
+                        position,
+                        message,
+                    }
+                })
+        })
+    }
+
+    /// Parses a Pascal string as raw bytes: a u8 length followed by that many bytes
+    ///
+    /// A length byte larger than the remaining data is an error, and the position is left
+    /// unchanged.
+    pub fn parse_pstr_raw(&mut self) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_pstr_raw", |cursor| {
+            cursor.len_prefixed_bytes("parse_pstr_raw", |[len]| len.into())
+        })
+    }
+
+    /// Parses a Pascal string (a u8 length followed by that many bytes) as UTF-8
+    ///
+    /// Invalid UTF-8 is an error, and the position is left unchanged on any error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"\x05hello\x00";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert_eq!(cursor.parse_pstr().unwrap(), "hello");
+    /// assert_eq!(cursor.parse_pstr().unwrap(), "");
+    /// assert!(cursor.at_end());
+    /// ```
+    pub fn parse_pstr(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_pstr", |cursor| {
+            cursor.len_prefixed_str("parse_pstr", |[len]| len.into())
+        })
+    }
+
+    /// Parses a Pascal string, replacing invalid UTF-8 sequences with U+FFFD
+    pub fn parse_pstr_lossy(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_pstr_lossy", |cursor| {
+            let bytes = cursor.len_prefixed_bytes("parse_pstr_lossy", |[len]| len.into())?;
+            Ok(String::from_utf8_lossy(&bytes).into_owned())
+        })
+    }
+
+    /// Parses a Pascal string padded to an even total length, as in classic Mac OS resources
+    ///
+    /// When the length byte and characters together take an odd number of bytes, one
+    /// padding byte follows and is consumed as well. Its value is not checked. A missing
+    /// padding byte is an error, and the position is left unchanged on any error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"\x04Main\x00\x03App";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert_eq!(cursor.parse_pstr_even_padded().unwrap(), "Main");
+    /// assert_eq!(cursor.position(), 6);
+    /// assert_eq!(cursor.parse_pstr_even_padded().unwrap(), "App");
+    /// assert!(cursor.at_end());
+    /// ```
+    pub fn parse_pstr_even_padded(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_pstr_even_padded", |cursor| {
+            cursor.atomic(|cursor| {
+                let string =
+                    cursor.len_prefixed_str("parse_pstr_even_padded", |[len]| len.into())?;
+                if string.len() % 2 == 0 {
+                    let position = cursor.data.position();
+                    cursor.region(position, 1, "parse_pstr_even_padded")?;
+                    cursor.data.set_position(position + 1);
+                }
+                Ok(string)
+            })
+        })
+    }
+
+    /// Parses a MIDI-style variable-length quantity from the current position
+    ///
+    /// Each byte contributes its low 7 bits, most significant group first, and the high bit
+    /// marks that another byte follows. As in the Standard MIDI File specification, the
+    /// encoding may be at most 4 bytes long, giving a maximum value of 0x0FFFFFFF. Longer
+    /// encodings and running out of data mid-value are errors that leave the position
+    /// unchanged. In strict mode an encoding with a redundant leading 0x80 byte is also
+    /// rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x81, 0x80, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_vlq().unwrap(), 0x4000);
+    /// assert_eq!(cursor.position(), 3);
+    /// ```
+    pub fn parse_vlq(&mut self) -> Result<u32, BinaryCursorError> {
+        self.traced("parse_vlq", |cursor| {
+            const VLQ_MAX_LEN: usize = 4;
+
+            let start = cursor.data.position();
+            let remaining = cursor.unread();
+            let mut result = 0u32;
+
+            if cursor.options.strict && remaining.first() == Some(&0x80) {
+                return Err(non_canonical("parse_vlq", start));
+            }
+
+            for (i, &byte) in remaining.iter().take(VLQ_MAX_LEN).enumerate() {
+                result = (result << 7) | u32::from(byte & 0x7F);
+                if byte & 0x80 == 0 {
+                    cursor.data.set_position(start + i as u64 + 1);
+                    return Ok(result);
+                }
+            }
+
+            if remaining.len() >= VLQ_MAX_LEN {
+                Err(BinaryCursorError::InvalidData {
+                    operation: "parse_vlq",
+                    position: start,
+                    message: format!("encoding is longer than {} bytes", VLQ_MAX_LEN),
+                })
+            } else {
+                Err(BinaryCursorError::UnexpectedEof {
+                    operation: "parse_vlq",
+                    position: start,
+                    requested: remaining.len() + 1,
+                    available: remaining.len(),
+                })
+            }
+        })
+    }
+
+    /// Parses a fixed-width string field of `len` bytes, removing the given padding
+    ///
+    /// The cursor always advances by exactly `len` bytes on success, regardless of where the
+    /// padding starts. With [`Padding::Null`] the string is truncated at the first NUL byte,
+    /// so any bytes after it are ignored; a field consisting entirely of padding yields an
+    /// empty string. Invalid UTF-8 or a field extending past the end of the data is an error
+    /// and leaves the position unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, Padding};
+    ///
+    /// let data = b"readme\0\0CD001   ";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// assert_eq!(cursor.parse_fixed_str(8, Padding::Null).unwrap(), "readme");
+    /// assert_eq!(cursor.parse_fixed_str(8, Padding::Space).unwrap(), "CD001");
+    /// assert_eq!(cursor.position(), 16);
+    /// ```
+    pub fn parse_fixed_str(
+        &mut self,
+        len: usize,
+        padding: Padding,
+    ) -> Result<String, BinaryCursorError> {
+        self.traced("parse_fixed_str", |cursor| {
+            cursor.atomic(|cursor| {
+                let start = cursor.data.position();
+                let field = cursor.parse_bytes(len)?;
+                String::from_utf8(padding.trim(&field).to_vec()).map_err(|e| {
+                    BinaryCursorError::InvalidData {
+                        operation: "parse_fixed_str",
+                        position: start,
+                        message: e.to_string(),
+                    }
+                })
+            })
+        })
+    }
+
+    /// Parses a fixed-width string field, replacing invalid UTF-8 sequences with U+FFFD
+    ///
+    /// See [`parse_fixed_str`](Self::parse_fixed_str) for the padding and position semantics.
+    pub fn parse_fixed_str_lossy(
+        &mut self,
+        len: usize,
+        padding: Padding,
+    ) -> Result<String, BinaryCursorError> {
+        self.traced("parse_fixed_str_lossy", |cursor| {
+            cursor.atomic(|cursor| {
+                let field = cursor.parse_bytes(len)?;
+                Ok(String::from_utf8_lossy(padding.trim(&field)).into_owned())
+            })
+        })
+    }
+
+    /// Parses `num_code_units` UTF-16 code units in little-endian order as a `String`
+    ///
+    /// The cursor advances by 2 bytes per code unit. Surrogate pairs are combined, while
+    /// unpaired surrogates are reported as an error that leaves the position unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![b'h', 0x00, b'i', 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_utf16_le(2).unwrap(), "hi");
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn parse_utf16_le(&mut self, num_code_units: usize) -> Result<String, BinaryCursorError> {
+        self.traced("parse_utf16_le", |cursor| {
+            cursor.parse_utf16(num_code_units, Endianness::Little)
+        })
+    }
+
+    /// Parses `num_code_units` UTF-16 code units in big-endian order as a `String`
+    ///
+    /// See [`parse_utf16_le`](Self::parse_utf16_le) for the position and error semantics.
+    pub fn parse_utf16_be(&mut self, num_code_units: usize) -> Result<String, BinaryCursorError> {
+        self.traced("parse_utf16_be", |cursor| {
+            cursor.parse_utf16(num_code_units, Endianness::Big)
+        })
+    }
+
+    /// Parses a little-endian UTF-16 string terminated by a 0x0000 code unit
+    ///
+    /// The terminator is consumed but not included in the result. Reaching the end of the
+    /// data before a terminator is found is an error that leaves the position unchanged.
+    pub fn parse_utf16_cstr_le(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_utf16_cstr_le", |cursor| {
+            cursor.parse_utf16_cstr(Endianness::Little)
+        })
+    }
+
+    /// Parses a big-endian UTF-16 string terminated by a 0x0000 code unit
+    ///
+    /// See [`parse_utf16_cstr_le`](Self::parse_utf16_cstr_le) for the position and error
+    /// semantics.
+    pub fn parse_utf16_cstr_be(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_utf16_cstr_be", |cursor| {
+            cursor.parse_utf16_cstr(Endianness::Big)
+        })
+    }
+
+    fn parse_utf16(
+        &mut self,
+        num_code_units: usize,
+        endianness: Endianness,
+    ) -> Result<String, BinaryCursorError> {
+        let byte_len = num_code_units.checked_mul(2).ok_or_else(|| {
+            BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UTF-16 length of {} code units is too large",
+                    num_code_units
+                ),
+            ))
+        })?;
+
+        self.atomic(|cursor| {
+            let start = cursor.data.position();
+            let bytes = cursor.parse_bytes(byte_len)?;
+            decode_utf16(&bytes, endianness, start)
+        })
+    }
+
+    fn parse_utf16_cstr(&mut self, endianness: Endianness) -> Result<String, BinaryCursorError> {
+        let start = self.data.position();
+        let remaining = self.unread();
+
+        let len = remaining
+            .chunks_exact(2)
+            .position(|unit| unit == [0, 0])
+            .ok_or(BinaryCursorError::UnexpectedEof {
+                operation: utf16_operation(endianness),
+                position: start,
+                requested: (remaining.len() / 2 + 1) * 2,
+                available: remaining.len(),
+            })?;
+
+        let value = decode_utf16(&remaining[..len * 2], endianness, start)?;
+        self.data.set_position(start + (len as u64 + 1) * 2);
+        Ok(value)
+    }
+
+    /// Reads the `N` bytes ending at the current position and moves the position back by `N`
+    fn read_array_rev<const N: usize>(
+        &mut self,
+        operation: &'static str,
+    ) -> Result<[u8; N], BinaryCursorError> {
+        let mut buf = [0u8; N];
+        self.read_rev_into(&mut buf, operation)?;
+        Ok(buf)
+    }
+
+    /// Returns the `len` bytes ending at the current position without moving, or an EOF
+    /// error for `operation`
+    fn peek_rev_slice(
+        &self,
+        len: usize,
+        operation: &'static str,
+    ) -> Result<&[u8], BinaryCursorError> {
+        let end = self.data.position();
+        let data = self.data.get_ref().as_ref();
+        usize::try_from(end)
+            .ok()
+            .and_then(|end| data.get(..end))
+            .and_then(|before| before.get(before.len().checked_sub(len)?..))
+            .ok_or(BinaryCursorError::UnexpectedEof {
+                operation,
+                position: end,
+                requested: len,
+                available: if end <= data.len() as u64 {
+                    end as usize
+                } else {
+                    0
+                },
+            })
+    }
+
+    /// Fills `buf` with the bytes ending at the current position and moves the position back
+    ///
+    /// On error the position is left unchanged.
+    fn read_rev_into(
+        &mut self,
+        buf: &mut [u8],
+        operation: &'static str,
+    ) -> Result<(), BinaryCursorError> {
+        buf.copy_from_slice(self.peek_rev_slice(buf.len(), operation)?);
+        self.data
+            .set_position(self.data.position() - buf.len() as u64);
+        Ok(())
+    }
+
+    /// Parses the u8 immediately before the current position, moving the position back by 1
+    pub fn parse_u8_rev(&mut self) -> Result<u8, BinaryCursorError> {
+        self.traced("parse_u8_rev", |cursor| {
+            Ok(cursor.read_array_rev::<1>("parse_u8_rev")?[0])
+        })
+    }
+
+    /// Parses the little-endian u16 ending at the current position, moving the position back by 2
+    pub fn parse_u16_le_rev(&mut self) -> Result<u16, BinaryCursorError> {
+        self.traced("parse_u16_le_rev", |cursor| {
+            Ok(u16::from_le_bytes(
+                cursor.read_array_rev("parse_u16_le_rev")?,
+            ))
+        })
+    }
+
+    /// Parses the little-endian u32 ending at the current position, moving the position back by 4
+    ///
+    /// This is intended for footer-first formats that are read backwards from the end of
+    /// the data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0xAA, 0x02, 0x00, 0x00, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    /// cursor.set_position(5);
+    ///
+    /// assert_eq!(cursor.parse_u32_le_rev().unwrap(), 2);
+    /// assert_eq!(cursor.position(), 1);
+    /// ```
+    pub fn parse_u32_le_rev(&mut self) -> Result<u32, BinaryCursorError> {
+        self.traced("parse_u32_le_rev", |cursor| {
+            Ok(u32::from_le_bytes(
+                cursor.read_array_rev("parse_u32_le_rev")?,
+            ))
+        })
+    }
+
+    /// Parses the little-endian u64 ending at the current position, moving the position back by 8
+    pub fn parse_u64_le_rev(&mut self) -> Result<u64, BinaryCursorError> {
+        self.traced("parse_u64_le_rev", |cursor| {
+            Ok(u64::from_le_bytes(
+                cursor.read_array_rev("parse_u64_le_rev")?,
+            ))
+        })
+    }
+
+    /// Parses the `count` bytes ending at the current position, moving the position back by `count`
+    ///
+    /// The bytes are returned in their stored (forward) order. They are bounds-checked
+    /// before anything is allocated, and the allocation limit applies as for
+    /// [`parse_bytes`](Self::parse_bytes). On error the position is left unchanged.
+    pub fn parse_bytes_rev(&mut self, count: usize) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_bytes_rev", |cursor| {
+            cursor.check_alloc(count as u64, "parse_bytes_rev", cursor.data.position())?;
+            let buf = cursor.peek_rev_slice(count, "parse_bytes_rev")?.to_vec();
+            cursor
+                .data
+                .set_position(cursor.data.position() - count as u64);
+            Ok(buf)
+        })
+    }
+
+    /// Returns a reader for consuming the data bit by bit from the current position
+    ///
+    /// Bytes are taken from the cursor as bits are needed, so while a byte is partially
+    /// consumed the cursor's position already points past it. The cursor is borrowed for as
+    /// long as the reader is alive, which means byte-level methods cannot be called mid-byte;
+    /// dropping the reader implicitly aligns to the next byte boundary, discarding any unread
+    /// bits of the current byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, BitOrder};
+    ///
+    /// let data = vec![0b1011_0011, 0b1100_0000, 0xAA];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// {
+    ///     let mut bits = cursor.bits(BitOrder::MsbFirst);
+    ///     assert_eq!(bits.read_bits(3).unwrap(), 0b101);
+    ///     assert_eq!(bits.read_bits(7).unwrap(), 0b1001111);
+    ///     assert!(!bits.is_aligned());
+    /// }
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0xAA);
+    /// ```
+    pub fn bits(&mut self, order: BitOrder) -> BinaryCursorBits<'_, T> {
+        BinaryCursorBits {
+            cursor: self,
+            order,
+            current: 0,
+            bits_left: 0,
+        }
+    }
+
+    /// Returns a guard whose parse methods read backwards from the current position
+    ///
+    /// The underlying cursor remains available through the guard's `cursor` field for
+    /// forward reads, so directions can be mixed within one scope.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    /// cursor.set_position(7);
+    ///
+    /// let mut rev = cursor.rev_mode();
+    /// assert_eq!(rev.parse_u32_le().unwrap(), 3);
+    /// assert_eq!(rev.parse_u16_le().unwrap(), 2);
+    /// assert_eq!(rev.parse_u8().unwrap(), 1);
+    /// assert_eq!(rev.cursor.position(), 0);
+    /// ```
+    pub fn rev_mode(&mut self) -> BinaryCursorRev<'_, T> {
+        BinaryCursorRev { cursor: self }
+    }
+
+    /// Returns the `len` bytes starting at absolute offset `start`, checking they are in bounds
+    pub(crate) fn region(
+        &self,
+        start: u64,
+        len: u64,
+        operation: &'static str,
+    ) -> Result<&[u8], BinaryCursorError> {
+        let data = self.data.get_ref().as_ref();
+        match start.checked_add(len) {
+            Some(end) if end <= data.len() as u64 => Ok(&data[start as usize..end as usize]),
+            _ => Err(BinaryCursorError::UnexpectedEof {
+                operation,
+                position: start,
+                requested: usize::try_from(len).unwrap_or(usize::MAX),
+                available: (data.len() as u64).saturating_sub(start) as usize,
+            }),
+        }
+    }
+
+    /// Copies the `len` bytes starting at absolute offset `start` into a new `Vec`
+    ///
+    /// The cursor position is not changed. The allocation limit applies as for
+    /// [`parse_bytes`](Self::parse_bytes).
+    pub fn extract_region(&self, start: u64, len: u64) -> Result<Vec<u8>, BinaryCursorError> {
+        self.check_alloc(len, "extract_region", start)?;
+        Ok(self.region(start, len, "extract_region")?.to_vec())
+    }
+
+    /// Streams the `len` bytes starting at absolute offset `start` to a writer
+    ///
+    /// The region is written in fixed-size chunks and the number of bytes written is
+    /// returned. The whole region is bounds-checked before anything is written, and the
+    /// cursor position is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let cursor = BinaryCursor::new(data);
+    ///
+    /// let mut out = vec![];
+    /// assert_eq!(cursor.extract_region_to(1, 2, &mut out).unwrap(), 2);
+    /// assert_eq!(out, vec![0x02, 0x03]);
+    /// ```
+    pub fn extract_region_to(
+        &self,
+        start: u64,
+        len: u64,
+        w: &mut impl Write,
+    ) -> Result<u64, BinaryCursorError> {
+        const CHUNK_SIZE: usize = 64 * 1024;
+
+        let region = self.region(start, len, "extract_region_to")?;
+        for chunk in region.chunks(CHUNK_SIZE) {
+            w.write_all(chunk)?;
+        }
+        Ok(len)
+    }
+
+    /// Folds `f` over the `len` bytes starting at absolute offset `start`
+    ///
+    /// This is the building block for checksums over data that has already been parsed: the
+    /// bytes are read directly from the underlying buffer and the position is not changed.
+    /// A region that is not entirely within the data is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let cursor = BinaryCursor::new(data);
+    ///
+    /// // A 16-bit additive checksum
+    /// let sum = cursor
+    ///     .fold_range(1, 3, 0u16, |sum, byte| sum.wrapping_add(byte as u16))
+    ///     .unwrap();
+    /// assert_eq!(sum, 9);
+    /// ```
+    pub fn fold_range<A, F>(
+        &self,
+        start: u64,
+        len: u64,
+        init: A,
+        f: F,
+    ) -> Result<A, BinaryCursorError>
+    where
+        F: FnMut(A, u8) -> A,
+    {
+        Ok(self
+            .region(start, len, "fold_range")?
+            .iter()
+            .copied()
+            .fold(init, f))
+    }
+
+    /// Returns the wrapping 8-bit sum of the `len` bytes starting at absolute offset `start`
+    ///
+    /// Use [`fold_range`](Self::fold_range) for wider sums. The position is not changed.
+    pub fn sum_u8_of_range(&self, start: u64, len: u64) -> Result<u8, BinaryCursorError> {
+        Ok(self
+            .region(start, len, "sum_u8_of_range")?
+            .iter()
+            .fold(0u8, |sum, byte| sum.wrapping_add(*byte)))
+    }
+
+    /// Returns the CRC-32 of the `len` bytes starting at absolute offset `start`
+    ///
+    /// This is the IEEE 802.3 CRC-32 used by PNG, ZIP and gzip. The position is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let cursor = BinaryCursor::new(b"123456789");
+    /// assert_eq!(cursor.crc32_of_range(0, 9).unwrap(), 0xCBF43926);
+    /// ```
+    pub fn crc32_of_range(&self, start: u64, len: u64) -> Result<u32, BinaryCursorError> {
+        Ok(crc32(self.region(start, len, "crc32_of_range")?))
+    }
+
+    /// Checks that the CRC-32 of the `len` bytes starting at `start` is `expected`
+    ///
+    /// A mismatch is reported as invalid data at `start`, with a message giving the range
+    /// and both checksums. The position is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let cursor = BinaryCursor::new(b"123456789");
+    /// assert!(cursor.verify_crc32(0, 9, 0xCBF43926).is_ok());
+    ///
+    /// let error = cursor.verify_crc32(0, 8, 0xCBF43926).unwrap_err();
+    /// assert!(error.to_string().contains("expected 0xCBF43926"));
+    /// ```
+    pub fn verify_crc32(
+        &self,
+        start: u64,
+        len: u64,
+        expected: u32,
+    ) -> Result<(), BinaryCursorError> {
+        let computed = crc32(self.region(start, len, "verify_crc32")?);
+        if computed != expected {
+            return Err(BinaryCursorError::InvalidData {
+                operation: "verify_crc32",
+                position: start,
+                message: format!(
+                    "CRC-32 mismatch over bytes {}..{}: expected 0x{:08X}, computed 0x{:08X}",
+                    start,
+                    start + len,
+                    expected,
+                    computed
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Creates a cursor over the `len` bytes starting at absolute offset `start`
+    ///
+    /// The new cursor borrows the parent's data without copying, starts at position 0, and
+    /// cannot read past the end of the region. It inherits the parent's options, and the
+    /// parent's position is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let cursor = BinaryCursor::new(data);
+    ///
+    /// let mut member = cursor.member_cursor(2, 2).unwrap();
+    /// assert_eq!(member.parse_u16_le().unwrap(), 0x0403);
+    /// assert!(member.parse_u8().is_err());
+    /// ```
+    pub fn member_cursor(
+        &self,
+        start: u64,
+        len: u64,
+    ) -> Result<BinaryCursor<&[u8]>, BinaryCursorError> {
+        let region = self.region(start, len, "member_cursor")?;
+        Ok(BinaryCursor::with_options(region, self.options))
+    }
+
+    /// Creates a cursor for each `(offset, len)` region, like [`member_cursor`](Self::member_cursor)
+    ///
+    /// This is intended for files whose index lists independent sections, which can then be
+    /// parsed in parallel: the cursors borrow the parent's data without copying, and
+    /// `BinaryCursor<&[u8]>` is `Send`. Regions may overlap. Every region is checked before
+    /// any cursor is created, and the error for one that runs past the end of the data names
+    /// its index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x02, 0x00, 0x00, 0x00];
+    /// let cursor = BinaryCursor::new(data);
+    ///
+    /// let sections = cursor.split_regions(&[(0, 2), (2, 4)]).unwrap();
+    /// let totals: Vec<u32> = std::thread::scope(|scope| {
+    ///     let handles: Vec<_> = sections
+    ///         .into_iter()
+    ///         .map(|mut section| scope.spawn(move || section.parse_u8().unwrap() as u32))
+    ///         .collect();
+    ///     handles.into_iter().map(|h| h.join().unwrap()).collect()
+    /// });
+    /// assert_eq!(totals, vec![1, 2]);
+    ///
+    /// let error = cursor.split_regions(&[(0, 2), (4, 4)]).unwrap_err();
+    /// assert!(error.to_string().contains("region 1 (4 bytes at offset 4)"));
+    /// ```
+    pub fn split_regions(
+        &self,
+        regions: &[(u64, u64)],
+    ) -> Result<Vec<BinaryCursor<&[u8]>>, BinaryCursorError> {
+        let len = self.len();
+        if let Some((index, &(start, region_len))) =
+            regions.iter().enumerate().find(|(_, (start, region_len))| {
+                start.checked_add(*region_len).is_none_or(|end| end > len)
+            })
+        {
+            return Err(BinaryCursorError::InvalidData {
+                operation: "split_regions",
+                position: start,
+                message: format!(
+                    "region {} ({} bytes at offset {}) runs past the end of the data ({} bytes)",
+                    index, region_len, start, len
+                ),
+            });
+        }
+
+        regions
+            .iter()
+            .map(|&(start, region_len)| self.member_cursor(start, region_len))
+            .collect()
+    }
+
+    /// Creates a cursor over the next `len` bytes and advances past them
+    ///
+    /// This is intended for length-prefixed chunks whose contents must be parsed without
+    /// reading into the following data. The new cursor borrows the parent's data without
+    /// copying, starts at position 0, and cannot read past the end of the window even when
+    /// the parent has more data. The parent's position is advanced by `len` as soon as the
+    /// sub-cursor is created, so it does not matter how much of the window is actually read.
+    /// If fewer than `len` bytes remain, an error is returned and the position is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x02, 0xAA, 0xBB, 0xCC];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let len = cursor.parse_u8().unwrap();
+    /// let mut chunk = cursor.sub_cursor(len as u64).unwrap();
+    /// assert_eq!(chunk.parse_u8().unwrap(), 0xAA);
+    /// assert!(chunk.parse_u16_le().is_err());
+    ///
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0xCC);
+    /// ```
+    pub fn sub_cursor(&mut self, len: u64) -> Result<BinaryCursor<&[u8]>, BinaryCursorError> {
+        let start = self.data.position();
+        self.region(start, len, "sub_cursor")?;
+        self.data.set_position(start + len);
+
+        let window = &self.data.get_ref().as_ref()[start as usize..(start + len) as usize];
+        Ok(BinaryCursor::with_options(window, self.options))
+    }
+
+    /// Returns the current position in the data stream
+    pub fn position(&self) -> u64 {
+        self.data.position()
+    }
+
+    /// Sets the current position in the data stream
+    ///
+    /// Any position is accepted, including one past the end of the data, in which case the
+    /// mistake only surfaces as an EOF error on the next read. Prefer
+    /// [`try_set_position`](Self::try_set_position), [`advance`](Self::advance) or
+    /// [`rewind`](Self::rewind) when the position comes from the data being parsed.
+    pub fn set_position(&mut self, pos: u64) {
+        self.data.set_position(pos);
+    }
+
+    /// Sets the current position, returning an error if it is past the end of the data
+    ///
+    /// Setting the position to exactly the end of the data is allowed. On error the position
+    /// is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// cursor.try_set_position(3).unwrap();
+    /// let error = cursor.try_set_position(4).unwrap_err();
+    /// assert!(error.to_string().contains("position 4 is past the end of the data (3 bytes)"));
+    /// assert_eq!(cursor.position(), 3);
+    /// ```
+    pub fn try_set_position(&mut self, pos: u64) -> Result<(), BinaryCursorError> {
+        self.checked_move(Some(pos), "try_set_position", || pos.to_string())
+    }
+
+    /// Moves the position forward by `n` bytes, returning an error if that passes the end
+    ///
+    /// This is the checked counterpart of [`rewind`](Self::rewind). Moving to exactly the end
+    /// of the data is allowed. On error the position is left unchanged.
+    pub fn advance(&mut self, n: u64) -> Result<(), BinaryCursorError> {
+        let position = self.data.position();
+        self.checked_move(position.checked_add(n), "advance", || {
+            format!("{} + {}", position, n)
+        })
+    }
+
+    /// Moves the position back by `n` bytes, returning an error if that passes the start
+    ///
+    /// On error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    /// cursor.set_position(2);
+    ///
+    /// cursor.rewind(1).unwrap();
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0x02);
+    /// assert!(cursor.rewind(3).is_err());
+    /// ```
+    pub fn rewind(&mut self, n: u64) -> Result<(), BinaryCursorError> {
+        let position = self.data.position();
+        match position.checked_sub(n) {
+            Some(target) => self.checked_move(Some(target), "rewind", || target.to_string()),
+            None => Err(BinaryCursorError::InvalidData {
+                operation: "rewind",
+                position,
+                message: format!("cannot rewind {} bytes from position {}", n, position),
+            }),
+        }
+    }
+
+    /// Moves the position as described by `from`, returning the new position
+    ///
+    /// This works like [`Seek::seek`], except that a position past the end of the data is an
+    /// error, as is one before the start, rather than being clamped or wrapped. Seeking to
+    /// exactly the end is allowed. On error the position is left unchanged. It is the checked
+    /// counterpart of the [`Seek`] implementation, as [`try_set_position`](Self::try_set_position)
+    /// is of [`set_position`](Self::set_position).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    /// use std::io::SeekFrom;
+    ///
+    /// // A body followed by a 4-byte footer holding its length
+    /// let data = vec![0xAA, 0xBB, 0x02, 0x00, 0x00, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.try_seek(SeekFrom::End(-4)).unwrap(), 2);
+    /// assert_eq!(cursor.parse_u32_le().unwrap(), 2);
+    /// assert!(cursor.try_seek(SeekFrom::End(-7)).is_err());
+    /// assert!(cursor.try_seek(SeekFrom::Current(1)).is_err());
+    /// assert_eq!(cursor.position(), 6);
+    /// ```
+    pub fn try_seek(&mut self, from: SeekFrom) -> Result<u64, BinaryCursorError> {
+        let target = self.seek_target(from, "try_seek")?;
+        self.data.set_position(target);
+        Ok(target)
+    }
+
+    /// Resolves `from` to an absolute position within the data for `operation`
+    pub(crate) fn seek_target(
+        &self,
+        from: SeekFrom,
+        operation: &'static str,
+    ) -> Result<u64, BinaryCursorError> {
+        let len = self.len();
+        let position = self.data.position();
+        let (base, offset) = match from {
+            SeekFrom::Start(target) => (target, 0),
+            SeekFrom::Current(offset) => (position, offset),
+            SeekFrom::End(offset) => (len, offset),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(target) if target <= len => Ok(target),
+            Some(target) => Err(BinaryCursorError::InvalidData {
+                operation,
+                position,
+                message: format!(
+                    "position {} is past the end of the data ({} bytes)",
+                    target, len
+                ),
+            }),
+            None if offset < 0 => Err(BinaryCursorError::InvalidData {
+                operation,
+                position,
+                message: format!(
+                    "cannot seek {} bytes back from position {}",
+                    offset.unsigned_abs(),
+                    base
+                ),
+            }),
+            None => Err(BinaryCursorError::InvalidData {
+                operation,
+                position,
+                message: format!("position {} + {} overflows", base, offset),
+            }),
+        }
+    }
+
+    /// Moves to `target` if it is within the data, describing it with `describe` otherwise
+    ///
+    /// A `target` of `None` means the computation overflowed.
+    fn checked_move(
+        &mut self,
+        target: Option<u64>,
+        operation: &'static str,
+        describe: impl FnOnce() -> String,
+    ) -> Result<(), BinaryCursorError> {
+        let len = self.len();
+        match target {
+            Some(pos) if pos <= len => {
+                self.data.set_position(pos);
+                Ok(())
+            }
+            _ => Err(BinaryCursorError::InvalidData {
+                operation,
+                position: self.data.position(),
+                message: format!(
+                    "position {} is past the end of the data ({} bytes)",
+                    describe(),
+                    len
+                ),
+            }),
+        }
+    }
+
+    /// Returns the total length of the data in bytes, regardless of the current position
+    pub fn len(&self) -> u64 {
+        self.data.get_ref().as_ref().len() as u64
+    }
+
+    /// Returns `true` if the data contains no bytes at all
+    ///
+    /// Use [`at_end`](Self::at_end) to check whether there is anything left to read.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes from the current position to the end of the data
+    ///
+    /// This is 0 if the position has been set past the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x02, 0x00, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let mut records = vec![];
+    /// while cursor.remaining() >= 2 {
+    ///     records.push(cursor.parse_u16_le().unwrap());
+    /// }
+    /// assert_eq!(records, vec![1, 2]);
+    /// assert_eq!(cursor.remaining(), 1);
+    /// ```
+    pub fn remaining(&self) -> u64 {
+        self.len().saturating_sub(self.data.position())
+    }
+
+    /// Returns `true` if there are no bytes left to read from the current position
+    pub fn at_end(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the bytes from the start of the data up to the current position
+    ///
+    /// This is the whole of the data if the position has been set past the end.
+    pub fn consumed(&self) -> &[u8] {
+        let data = self.data.get_ref().as_ref();
+        let end =
+            usize::try_from(self.data.position()).map_or(data.len(), |pos| pos.min(data.len()));
+        &data[..end]
+    }
+
+    /// Returns the bytes from the current position to the end of the data, without copying
+    ///
+    /// This is empty if the position has been set past the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// cursor.parse_u8().unwrap();
+    /// assert_eq!(cursor.consumed(), &[0x01]);
+    /// assert_eq!(cursor.remaining_slice(), &[0x02, 0x03]);
+    /// ```
+    pub fn remaining_slice(&self) -> &[u8] {
+        self.unread()
+    }
+
+    /// Returns the bytes from the current position to the end of the data, and moves to the
+    /// end
+    ///
+    /// This is intended for handing the rest of a buffer to another parser. If the position
+    /// has been set past the end, the result is empty and the position is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![b'P', b'K', 0xAA, 0xBB];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// cursor.expect_tag(b"PK").unwrap();
+    /// assert_eq!(cursor.take_remaining(), &[0xAA, 0xBB]);
+    /// assert!(cursor.at_end());
+    /// ```
+    pub fn take_remaining(&mut self) -> &[u8] {
+        let len = self.len();
+        let start = self.data.position();
+        if start < len {
+            self.data.set_position(len);
+        }
+
+        let data = self.data.get_ref().as_ref();
+        &data[(start.min(len) as usize)..]
+    }
+
+    /// Returns whether at least `n` bytes remain after the current position
+    pub fn has_remaining(&self, n: u64) -> bool {
+        self.remaining() >= n
+    }
+
+    /// Checks that at least `n` bytes remain after the current position
+    ///
+    /// This lets a fixed-size record be checked as a whole before any of its fields are
+    /// parsed. The error is an [`UnexpectedEof`](BinaryCursorError::UnexpectedEof) for the
+    /// operation `ensure_remaining`, carrying `n`, the bytes remaining and the current
+    /// position. The position is never changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0u8; 7];
+    /// let cursor = BinaryCursor::new(data);
+    ///
+    /// assert!(cursor.ensure_remaining(7).is_ok());
+    /// let error = cursor.ensure_remaining(24).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Unexpected EOF at offset 0 in ensure_remaining: requested 24 bytes, 7 available"
+    /// );
+    /// ```
+    pub fn ensure_remaining(&self, n: u64) -> Result<(), BinaryCursorError> {
+        let remaining = self.remaining();
+        if remaining >= n {
+            return Ok(());
+        }
+        Err(BinaryCursorError::UnexpectedEof {
+            operation: "ensure_remaining",
+            position: self.data.position(),
+            requested: usize::try_from(n).unwrap_or(usize::MAX),
+            available: remaining as usize,
+        })
+    }
+
+    /// Formats the bytes within `radius` of the current position as a hex dump
+    ///
+    /// Each line shows the offset of its first byte, up to 16 bytes in hex and the same
+    /// bytes as ASCII, with `.` for anything unprintable. Lines are aligned to multiples of
+    /// 16 and only bytes inside the window are shown. A line of `^^` marks the byte at the
+    /// current position, or the slot after the last byte if the cursor is at the end. The
+    /// window is clamped to the data, so this never fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"Hello, world!\x00\x01\x02";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    /// cursor.set_position(7);
+    ///
+    /// let dump = cursor.context(4);
+    /// let lines: Vec<&str> = dump.lines().collect();
+    /// assert_eq!(
+    ///     lines[0],
+    ///     "00000000           6C 6F 2C 20 77  6F 72 6C                 |   lo, worl     |"
+    /// );
+    /// assert_eq!(lines[1], "                               ^^");
+    /// ```
+    pub fn context(&self, radius: usize) -> String {
+        self.hexdump(self.data.position(), radius)
+    }
+
+    /// Formats the bytes within `radius` of `position` as described for
+    /// [`context`](Self::context)
+    fn hexdump(&self, position: u64, radius: usize) -> String {
+        const ROW: u64 = 16;
+        let data = self.data.get_ref().as_ref();
+        let len = data.len() as u64;
+        let position = position.min(len);
+        let start = position.saturating_sub(radius as u64);
+        let end = position.saturating_add(radius as u64).min(len);
+
+        let mut lines = vec![];
+        let mut row = start / ROW * ROW;
+        while row < end.max(position + 1) {
+            let mut hex = format!("{:08X} ", row);
+            let mut ascii = String::with_capacity(ROW as usize);
+            for col in 0..ROW {
+                if col == ROW / 2 {
+                    hex.push(' ');
+                }
+                let offset = row + col;
+                if (start..end).contains(&offset) {
+                    let byte = data[offset as usize];
+                    hex.push_str(&format!(" {:02X}", byte));
+                    ascii.push(if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    });
+                } else {
+                    hex.push_str("   ");
+                    ascii.push(' ');
+                }
+            }
+            lines.push(format!("{}  |{}|", hex, ascii));
+
+            if (row..row + ROW).contains(&position) {
+                let col = position - row;
+                let indent = 10 + 3 * col + u64::from(col >= ROW / 2);
+                lines.push(format!("{}^^", " ".repeat(indent as usize)));
+            }
+            row += ROW;
+        }
+        lines.join("\n")
+    }
+
+    /// Advances the position by `n` bytes without reading them
+    ///
+    /// This is intended for padding and reserved fields. Skipping to exactly the end of the
+    /// data is allowed; skipping past it is an error that leaves the position unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x00, 0x02];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// cursor.skip(3).unwrap();
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0x02);
+    /// assert!(cursor.skip(1).is_err());
+    /// ```
+    pub fn skip(&mut self, n: u64) -> Result<(), BinaryCursorError> {
+        let start = self.data.position();
+        self.region(start, n, "skip")?;
+        self.data.set_position(start + n);
+        Ok(())
+    }
+
+    /// Advances the position to the next multiple of `alignment`
+    ///
+    /// This does nothing if the position is already aligned. An `alignment` of 0 is an
+    /// error, as is a boundary that lies past the end of the data; in both cases the
+    /// position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0x01);
+    /// cursor.align(4).unwrap();
+    /// assert_eq!(cursor.parse_u32_le().unwrap(), 2);
+    /// ```
+    pub fn align(&mut self, alignment: u64) -> Result<(), BinaryCursorError> {
+        if alignment == 0 {
+            return Err(BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Alignment must be greater than 0",
+            )));
+        }
+
+        let position = self.data.position();
+        let offset = position % alignment;
+        if offset == 0 {
+            return Ok(());
+        }
+
+        let padding = alignment - offset;
+        self.region(position, padding, "align")?;
+        self.data.set_position(position + padding);
+        Ok(())
+    }
+
+    /// Parses multiple items using the provided parser function
+    ///
+    /// This is similar to nom's `count` combinator, but works with the `BinaryCursor` interface.
+    /// Space for the items is reserved according to the data actually remaining rather than
+    /// `count`, so a corrupt count cannot cause a huge allocation before the first item
+    /// fails. On error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let values = cursor.count(|c| c.parse_u8(), 4).unwrap();
+    /// assert_eq!(values, vec![0x01, 0x02, 0x03, 0x04]);
+    /// ```
+    pub fn count<U, F>(&mut self, mut parser: F, count: usize) -> Result<Vec<U>, BinaryCursorError>
+    where
+        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        self.atomic(|cursor| {
+            let mut items = Vec::with_capacity(cursor.bounded_capacity(count));
+            for _ in 0..count {
+                items.push(parser(cursor)?);
+            }
+            Ok(items)
+        })
+    }
+
+    /// Parses `count` items like [`count`](Self::count), but rejects a `count` above `max`
+    ///
+    /// This is intended for counts read from the data, which can be checked against a
+    /// sanity limit before any item is parsed. The error names the offending count, and the
+    /// position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x02];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let count = cursor.parse_u32_le().unwrap() as usize;
+    /// let error = cursor.count_with_limit(|c| c.parse_u8(), count, 1024).unwrap_err();
+    /// assert!(error.to_string().contains("count 4294967295 exceeds the limit of 1024"));
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn count_with_limit<U, F>(
+        &mut self,
+        parser: F,
+        count: usize,
+        max: usize,
+    ) -> Result<Vec<U>, BinaryCursorError>
+    where
+        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        if count > max {
+            return Err(BinaryCursorError::InvalidData {
+                operation: "count_with_limit",
+                position: self.data.position(),
+                message: format!("count {} exceeds the limit of {}", count, max),
+            });
+        }
+        self.count(parser, count)
+    }
+
+    /// Returns how many of `count` items to reserve space for up front
+    ///
+    /// Every item that consumes data needs at least one byte, so reserving more than the
+    /// remaining length can only waste memory.
+    fn bounded_capacity(&self, count: usize) -> usize {
+        count.min(usize::try_from(self.remaining()).unwrap_or(usize::MAX))
+    }
+
+    /// Applies a parser `N` times and returns the results as a fixed-size array
+    ///
+    /// This is the allocation-free counterpart of [`count`](Self::count) for lengths known at
+    /// compile time, and does not require `U: Default`. If any item fails, the items already
+    /// parsed are dropped, the error is returned and the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x02, 0x00, 0x03, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let vertex: [u16; 3] = cursor.count_array(|c| c.parse_u16_le()).unwrap();
+    /// assert_eq!(vertex, [1, 2, 3]);
+    /// ```
+    pub fn count_array<U, F, const N: usize>(
+        &mut self,
+        mut parser: F,
+    ) -> Result<[U; N], BinaryCursorError>
+    where
+        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        self.atomic(|cursor| {
+            let mut error = None;
+            let items: [Option<U>; N] = std::array::from_fn(|_| match error {
+                Some(_) => None,
+                None => parser(cursor).map_err(|e| error = Some(e)).ok(),
+            });
+            match error {
+                Some(e) => Err(e),
+                None => Ok(items.map(|item| item.expect("every item was parsed"))),
+            }
+        })
+    }
+
+    /// Runs a parser, returning `None` and rolling back if it fails
+    ///
+    /// On success the value is returned and the position the parser left the cursor at is
+    /// kept. On failure the position is restored to where it was before the attempt, even if
+    /// the parser consumed part of its input, and the error is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.opt(|c| c.parse_u32_le()).unwrap(), None);
+    /// assert_eq!(cursor.position(), 0);
+    /// assert_eq!(cursor.opt(|c| c.parse_u16_le()).unwrap(), Some(0x0201));
+    /// ```
+    pub fn opt<U, F>(&mut self, parser: F) -> Result<Option<U>, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        Ok(self.atomic(parser).ok())
+    }
+
+    /// Runs a parser and checks its value with `predicate`
+    ///
+    /// A value the predicate rejects is reported as [`BinaryCursorError::InvalidData`] for
+    /// the operation `verify`, recording the offset where the value started and the value
+    /// itself. On any error the position is restored to that offset, so an alternative
+    /// interpretation can be tried.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x00, 0x02, 0x00, 0x08];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let small = |c: &mut BinaryCursor<Vec<u8>>| c.verify(|c| c.parse_u16_le(), |v| *v <= 1024);
+    /// assert_eq!(small(&mut cursor).unwrap(), 512);
+    ///
+    /// let error = small(&mut cursor).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Invalid data at offset 2 in verify: value 2048 was rejected"
+    /// );
+    /// assert_eq!(cursor.position(), 2);
+    /// ```
+    pub fn verify<U, P, V>(&mut self, parser: P, predicate: V) -> Result<U, BinaryCursorError>
+    where
+        P: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+        V: FnOnce(&U) -> bool,
+        U: std::fmt::Debug,
+    {
+        self.atomic(|cursor| {
+            let position = cursor.data.position();
+            let value = parser(cursor)?;
+            if predicate(&value) {
+                Ok(value)
+            } else {
+                Err(BinaryCursorError::InvalidData {
+                    operation: "verify",
+                    position,
+                    message: format!("value {:?} was rejected", value),
+                })
+            }
+        })
+    }
+
+    /// Runs a parser and converts its value with the fallible function `f`
+    ///
+    /// A conversion failure is reported as [`BinaryCursorError::InvalidData`] for the
+    /// operation `map_res`, recording the offset where the value started and the conversion
+    /// error's message. On any error the position is restored to that offset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x7F, 0x80];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let as_i8 = |c: &mut BinaryCursor<Vec<u8>>| c.map_res(|c| c.parse_u8(), i8::try_from);
+    /// assert_eq!(as_i8(&mut cursor).unwrap(), 127);
+    /// assert!(as_i8(&mut cursor).is_err());
+    /// assert_eq!(cursor.position(), 1);
+    /// ```
+    pub fn map_res<U, V, E, P, F>(&mut self, parser: P, f: F) -> Result<V, BinaryCursorError>
+    where
+        P: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+        F: FnOnce(U) -> Result<V, E>,
+        E: std::fmt::Display,
+    {
+        self.atomic(|cursor| {
+            let position = cursor.data.position();
+            let value = parser(cursor)?;
+            f(value).map_err(|e| BinaryCursorError::InvalidData {
+                operation: "map_res",
+                position,
+                message: e.to_string(),
+            })
+        })
+    }
+
+    /// Tries each parser in turn, returning the value of the first one that succeeds
+    ///
+    /// The position is rolled back between attempts, so every alternative starts from the
+    /// same offset. If every parser fails, the last error is returned and the position is
+    /// left unchanged; an empty list of parsers is also an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"MM\0*";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// let big_endian = cursor
+    ///     .alt(&mut [
+    ///         &mut |c| c.expect_tag(b"II*\0").map(|_| false),
+    ///         &mut |c| c.expect_tag(b"MM\0*").map(|_| true),
+    ///     ])
+    ///     .unwrap();
+    /// assert!(big_endian);
+    /// ```
+    pub fn alt<U>(
+        &mut self,
+        parsers: &mut [Alternative<'_, T, U>],
+    ) -> Result<U, BinaryCursorError> {
+        let mut last_error = None;
+        for parser in parsers {
+            match self.atomic(parser) {
+                Ok(value) => return Ok(value),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "alt requires at least one parser",
+            ))
+        }))
+    }
+
+    /// Applies a parser repeatedly until it fails, returning the items it produced
+    ///
+    /// This is intended for "records until the end of the data" layouts. Each attempt is
+    /// atomic, so when the parser fails, even after consuming part of an item, the position
+    /// is restored to just after the last complete item. The failure that ends the
+    /// repetition is not reported, and no items at all is a valid result. The only error is
+    /// a parser that succeeds without consuming any bytes, which would otherwise repeat
+    /// forever; in that case the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x02, 0x00, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let values = cursor.many0(|c| c.parse_u16_le()).unwrap();
+    /// assert_eq!(values, vec![1, 2]);
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn many0<U, F>(&mut self, mut parser: F) -> Result<Vec<U>, BinaryCursorError>
+    where
+        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        self.atomic(|cursor| {
+            let mut items = vec![];
+            loop {
+                let start = cursor.data.position();
+                match cursor.atomic(&mut parser) {
+                    Ok(_) if cursor.data.position() == start => {
+                        return Err(no_progress("many0", start));
+                    }
+                    Ok(item) => items.push(item),
+                    Err(_) => return Ok(items),
+                }
+            }
+        })
+    }
+
+    /// Returns an iterator that applies a parser lazily until the end of the data
+    ///
+    /// Iteration ends cleanly once no bytes remain. Unlike [`many0`](Self::many0), a record
+    /// that fails to parse is reported: the iterator yields the error and then stops, with
+    /// the position restored to just after the last complete record. A parser that
+    /// succeeds without consuming any bytes is reported the same way. Because the iterator
+    /// borrows the cursor, the position always reflects how far iteration got.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x02, 0x00, 0x03, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let first_two: Vec<u16> = cursor
+    ///     .iter_with(|c| c.parse_u16_le())
+    ///     .take(2)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(first_two, vec![1, 2]);
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn iter_with<U, P>(&mut self, parser: P) -> RecordIter<'_, T, P>
+    where
+        P: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        RecordIter {
+            cursor: self,
+            parser,
+            done: false,
+        }
+    }
+
+    /// Applies a parser repeatedly until a sentinel parser succeeds
+    ///
+    /// Before each item the sentinel is tried; when it succeeds it is consumed and its value
+    /// is returned together with the items parsed so far. A failed sentinel attempt never
+    /// consumes anything. If the item parser fails before the sentinel is found, or succeeds
+    /// without consuming any bytes, the error is returned and the position is left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0xFF, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let (values, _) = cursor
+    ///     .many_till(|c| c.parse_u8(), |c| c.expect_tag(&[0xFF]))
+    ///     .unwrap();
+    /// assert_eq!(values, vec![0x01, 0x02]);
+    /// assert_eq!(cursor.position(), 3);
+    /// ```
+    pub fn many_till<U, V, F, S>(
+        &mut self,
+        mut parser: F,
+        mut sentinel: S,
+    ) -> Result<(Vec<U>, V), BinaryCursorError>
+    where
+        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+        S: FnMut(&mut Self) -> Result<V, BinaryCursorError>,
+    {
+        self.atomic(|cursor| {
+            let mut items = vec![];
+            loop {
+                if let Ok(end) = cursor.atomic(&mut sentinel) {
+                    return Ok((items, end));
+                }
+
+                let start = cursor.data.position();
+                items.push(parser(cursor)?);
+                if cursor.data.position() == start {
+                    return Err(no_progress("many_till", start));
+                }
+            }
+        })
+    }
+
+    /// Parses items separated by a separator, returning the items
+    ///
+    /// Parsing stops at the first item or separator that fails, with the position left just
+    /// after the last complete item, so a trailing separator is not consumed. As with
+    /// [`many0`](Self::many0), those failures are not reported and an empty list is a valid
+    /// result. The only error is a separator and item that together consume no bytes, which
+    /// would otherwise repeat forever; in that case the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = b"ab,cd,ef;";
+    /// let mut cursor = BinaryCursor::new(&data[..]);
+    ///
+    /// let items = cursor
+    ///     .separated_list(|c| c.parse_array::<2>(), |c| c.expect_tag(b","))
+    ///     .unwrap();
+    /// assert_eq!(items, vec![*b"ab", *b"cd", *b"ef"]);
+    /// assert_eq!(cursor.position(), 8);
+    /// ```
+    pub fn separated_list<U, V, F, S>(
+        &mut self,
+        mut item_parser: F,
+        mut separator_parser: S,
+    ) -> Result<Vec<U>, BinaryCursorError>
+    where
+        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+        S: FnMut(&mut Self) -> Result<V, BinaryCursorError>,
+    {
+        self.atomic(|cursor| {
+            let mut items = vec![];
+            match cursor.atomic(&mut item_parser) {
+                Ok(item) => items.push(item),
+                Err(_) => return Ok(items),
+            }
+
+            loop {
+                let start = cursor.data.position();
+                let next = cursor.atomic(|cursor| {
+                    separator_parser(cursor)?;
+                    item_parser(cursor)
+                });
+                match next {
+                    Ok(_) if cursor.data.position() == start => {
+                        return Err(no_progress("separated_list", start));
+                    }
+                    Ok(item) => items.push(item),
+                    Err(_) => return Ok(items),
+                }
+            }
+        })
+    }
+
+    /// Parses an opening delimiter, a value and a closing delimiter, returning the value
+    ///
+    /// This is intended for framing such as `0x7E ... 0x7E` or a tag and end tag pair. If
+    /// any stage fails, the position is left unchanged and the error is wrapped with a
+    /// [`context`](BinaryCursorError::context) label naming the stage: `open delimiter`,
+    /// `delimited value` or `close delimiter`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x7E, 0x01, 0x02, 0x7E, 0x7E, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let frame = |c: &mut BinaryCursor<Vec<u8>>| {
+    ///     c.delimited(|c| c.expect_tag(&[0x7E]), |c| c.parse_u16_le(), |c| c.expect_tag(&[0x7E]))
+    /// };
+    /// assert_eq!(frame(&mut cursor).unwrap(), 0x0201);
+    ///
+    /// let error = frame(&mut cursor).unwrap_err();
+    /// assert_eq!(error.fields().context, vec!["delimited value"]);
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn delimited<O, U, C, FO, FI, FC>(
+        &mut self,
+        open_parser: FO,
+        inner_parser: FI,
+        close_parser: FC,
+    ) -> Result<U, BinaryCursorError>
+    where
+        FO: FnOnce(&mut Self) -> Result<O, BinaryCursorError>,
+        FI: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+        FC: FnOnce(&mut Self) -> Result<C, BinaryCursorError>,
+    {
+        self.atomic(|cursor| {
+            open_parser(cursor).map_err(|e| e.context("open delimiter"))?;
+            let value = inner_parser(cursor).map_err(|e| e.context("delimited value"))?;
+            close_parser(cursor).map_err(|e| e.context("close delimiter"))?;
+            Ok(value)
+        })
+    }
+
+    /// Parses a length prefix with `len_parser`, then reads that many bytes
+    ///
+    /// The prefix can be any integer type convertible to `usize`, so any of the integer
+    /// parse methods can be used. The payload is bounds-checked before anything is
+    /// allocated, so a corrupt prefix on truncated data fails cleanly. On error from either
+    /// stage the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x02, 0x00, 0xAA, 0xBB, 0xCC];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let payload = cursor.length_data(|c| c.parse_u16_le()).unwrap();
+    /// assert_eq!(payload, vec![0xAA, 0xBB]);
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn length_data<N, L>(&mut self, len_parser: L) -> Result<Vec<u8>, BinaryCursorError>
+    where
+        N: TryInto<usize>,
+        L: FnOnce(&mut Self) -> Result<N, BinaryCursorError>,
+    {
+        self.traced("length_data", |cursor| {
+            cursor.atomic(|cursor| {
+                let len = cursor.parse_length(len_parser, "length_data")?;
+                cursor.region(cursor.data.position(), len as u64, "length_data")?;
+                cursor.parse_bytes(len)
+            })
+        })
+    }
+
+    /// Parses a count prefix with `len_parser`, then applies `item_parser` that many times
+    ///
+    /// See [`length_data`](Self::length_data) for the accepted prefix types. Space for the
+    /// items is reserved according to the data actually remaining rather than the count, so
+    /// a corrupt prefix cannot cause a huge allocation up front. On error from either stage
+    /// the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x02, 0x01, 0x00, 0x02, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let items = cursor
+    ///     .length_count(|c| c.parse_u8(), |c| c.parse_u16_le())
+    ///     .unwrap();
+    /// assert_eq!(items, vec![1, 2]);
+    /// ```
+    pub fn length_count<N, L, U, P>(
+        &mut self,
+        len_parser: L,
+        mut item_parser: P,
+    ) -> Result<Vec<U>, BinaryCursorError>
+    where
+        N: TryInto<usize>,
+        L: FnOnce(&mut Self) -> Result<N, BinaryCursorError>,
+        P: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        self.traced("length_count", |cursor| {
+            cursor.atomic(|cursor| {
+                let count = cursor.parse_length(len_parser, "length_count")?;
+                let mut items = Vec::with_capacity(cursor.bounded_capacity(count));
+                for _ in 0..count {
+                    items.push(item_parser(cursor)?);
+                }
+                Ok(items)
+            })
+        })
+    }
+
+    /// Runs a length prefix parser and converts its result to `usize`
+    fn parse_length<N, L>(
+        &mut self,
+        len_parser: L,
+        operation: &'static str,
+    ) -> Result<usize, BinaryCursorError>
+    where
+        N: TryInto<usize>,
+        L: FnOnce(&mut Self) -> Result<N, BinaryCursorError>,
+    {
+        let position = self.data.position();
+        len_parser(self)?
+            .try_into()
+            .map_err(|_| BinaryCursorError::InvalidData {
+                operation,
+                position,
+                message: "length prefix does not fit in usize".to_string(),
+            })
+    }
+
+    /// Parses a u8 length, then reads that many bytes
+    ///
+    /// See [`parse_len_prefixed_bytes_u16_le`](Self::parse_len_prefixed_bytes_u16_le) for how
+    /// the length is checked.
+    pub fn parse_len_prefixed_bytes_u8(&mut self) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_len_prefixed_bytes_u8", |cursor| {
+            cursor.len_prefixed_bytes("parse_len_prefixed_bytes_u8", |[len]| len.into())
+        })
+    }
+
+    /// Parses a little-endian u16 length, then reads that many bytes
+    ///
+    /// The declared length is checked against the remaining data, and against the allocation
+    /// limit if there is one, before anything is allocated, so a corrupt prefix fails cleanly
+    /// however large it claims to be. On error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x02, 0x00, 0xAA, 0xBB, 0xFF, 0xFF, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_len_prefixed_bytes_u16_le().unwrap(), vec![0xAA, 0xBB]);
+    /// assert!(cursor.parse_len_prefixed_bytes_u16_le().is_err());
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn parse_len_prefixed_bytes_u16_le(&mut self) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_len_prefixed_bytes_u16_le", |cursor| {
+            cursor.len_prefixed_bytes("parse_len_prefixed_bytes_u16_le", |b| {
+                u16::from_le_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses a big-endian u16 length, then reads that many bytes
+    ///
+    /// See [`parse_len_prefixed_bytes_u16_le`](Self::parse_len_prefixed_bytes_u16_le) for how
+    /// the length is checked.
+    pub fn parse_len_prefixed_bytes_u16_be(&mut self) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_len_prefixed_bytes_u16_be", |cursor| {
+            cursor.len_prefixed_bytes("parse_len_prefixed_bytes_u16_be", |b| {
+                u16::from_be_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses a little-endian u32 length, then reads that many bytes
+    ///
+    /// See [`parse_len_prefixed_bytes_u16_le`](Self::parse_len_prefixed_bytes_u16_le) for how
+    /// the length is checked.
+    pub fn parse_len_prefixed_bytes_u32_le(&mut self) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_len_prefixed_bytes_u32_le", |cursor| {
+            cursor.len_prefixed_bytes("parse_len_prefixed_bytes_u32_le", |b| {
+                u32::from_le_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses a big-endian u32 length, then reads that many bytes
+    ///
+    /// See [`parse_len_prefixed_bytes_u16_le`](Self::parse_len_prefixed_bytes_u16_le) for how
+    /// the length is checked.
+    pub fn parse_len_prefixed_bytes_u32_be(&mut self) -> Result<Vec<u8>, BinaryCursorError> {
+        self.traced("parse_len_prefixed_bytes_u32_be", |cursor| {
+            cursor.len_prefixed_bytes("parse_len_prefixed_bytes_u32_be", |b| {
+                u32::from_be_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses a u8 length, then reads that many bytes as a UTF-8 string
+    ///
+    /// Invalid UTF-8 is an error, and the position is left unchanged on any error.
+    pub fn parse_len_prefixed_str_u8(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_len_prefixed_str_u8", |cursor| {
+            cursor.len_prefixed_str("parse_len_prefixed_str_u8", |[len]| len.into())
+        })
+    }
+
+    /// Parses a little-endian u16 length, then reads that many bytes as a UTF-8 string
+    ///
+    /// Invalid UTF-8 is an error, and the position is left unchanged on any error.
+    pub fn parse_len_prefixed_str_u16_le(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_len_prefixed_str_u16_le", |cursor| {
+            cursor.len_prefixed_str("parse_len_prefixed_str_u16_le", |b| {
+                u16::from_le_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses a big-endian u16 length, then reads that many bytes as a UTF-8 string
+    ///
+    /// Invalid UTF-8 is an error, and the position is left unchanged on any error.
+    pub fn parse_len_prefixed_str_u16_be(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_len_prefixed_str_u16_be", |cursor| {
+            cursor.len_prefixed_str("parse_len_prefixed_str_u16_be", |b| {
+                u16::from_be_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses a little-endian u32 length, then reads that many bytes as a UTF-8 string
+    ///
+    /// Invalid UTF-8 is an error, and the position is left unchanged on any error.
+    pub fn parse_len_prefixed_str_u32_le(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_len_prefixed_str_u32_le", |cursor| {
+            cursor.len_prefixed_str("parse_len_prefixed_str_u32_le", |b| {
+                u32::from_le_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses a big-endian u32 length, then reads that many bytes as a UTF-8 string
+    ///
+    /// Invalid UTF-8 is an error, and the position is left unchanged on any error.
+    pub fn parse_len_prefixed_str_u32_be(&mut self) -> Result<String, BinaryCursorError> {
+        self.traced("parse_len_prefixed_str_u32_be", |cursor| {
+            cursor.len_prefixed_str("parse_len_prefixed_str_u32_be", |b| {
+                u32::from_be_bytes(b).into()
+            })
+        })
+    }
+
+    /// Parses an `N`-byte length prefix decoded by `decode`, then reads that many bytes
+    ///
+    /// The payload is bounds-checked and checked against the allocation limit before it is
+    /// copied. On error the position is left unchanged.
+    fn len_prefixed_bytes<const N: usize>(
+        &mut self,
+        operation: &'static str,
+        decode: fn([u8; N]) -> u64,
+    ) -> Result<Vec<u8>, BinaryCursorError> {
+        self.atomic(|cursor| {
+            let len = decode(cursor.read_array(operation)?);
+            let position = cursor.data.position();
+            let bytes = {
+                let payload = cursor.region(position, len, operation)?;
+                cursor.check_alloc(len, operation, position)?;
+                payload.to_vec()
+            };
+            cursor.data.set_position(position + len);
+            Ok(bytes)
+        })
+    }
+
+    /// Like [`len_prefixed_bytes`](Self::len_prefixed_bytes), but validates the payload as UTF-8
+    fn len_prefixed_str<const N: usize>(
+        &mut self,
+        operation: &'static str,
+        decode: fn([u8; N]) -> u64,
+    ) -> Result<String, BinaryCursorError> {
+        self.atomic(|cursor| {
+            let position = cursor.data.position() + N as u64;
+            let bytes = cursor.len_prefixed_bytes(operation, decode)?;
+            String::from_utf8(bytes).map_err(|e| BinaryCursorError::InvalidData {
+                operation,
+                position,
+                message: e.to_string(),
+            })
+        })
+    }
+
+    /// Runs a parser at absolute offset `position`, then returns to the current position
+    ///
+    /// This is intended for following offset pointers: the parser's value is returned and
+    /// the original position is restored whether it succeeds or fails. It is implemented
+    /// with [`BinaryCursorJump`], so it counts towards the cursor's depth limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x03, 0xAA, 0x00, b'h', b'i', 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let name_offset = cursor.parse_u8().unwrap();
+    /// let name = cursor.at(name_offset as u64, |c| c.parse_cstr()).unwrap();
+    /// assert_eq!(name, "hi");
+    /// assert_eq!(cursor.position(), 1);
+    /// ```
+    pub fn at<U, F>(&mut self, position: u64, parser: F) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        let mut jump = BinaryCursorJump::new(self);
+        jump.jump(position)?;
+        parser(jump.cursor)
+    }
+
+    /// Runs a parser at an offset relative to the current position, then returns
+    ///
+    /// See [`at`](Self::at) for the restore semantics and
+    /// [`BinaryCursorJump::jump_relative`] for how the offset is applied.
+    pub fn at_offset<U, F>(&mut self, offset: i64, parser: F) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        let mut jump = BinaryCursorJump::new(self);
+        jump.jump_relative(offset)?;
+        parser(jump.cursor)
+    }
+
+    /// Parses the u8 at absolute offset `position` without moving the cursor
+    ///
+    /// This and the other `_at` methods are shorthands for [`at`](Self::at) with a single
+    /// parse method, for following offset pointers.
+    pub fn u8_at(&mut self, position: u64) -> Result<u8, BinaryCursorError> {
+        self.at(position, Self::parse_u8)
+    }
+
+    /// Parses the little-endian u16 at absolute offset `position` without moving the cursor
+    pub fn u16_le_at(&mut self, position: u64) -> Result<u16, BinaryCursorError> {
+        self.at(position, Self::parse_u16_le)
+    }
+
+    /// Parses the little-endian u32 at absolute offset `position` without moving the cursor
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x00, 0x00, 0x00, 0x00, 0xEF, 0xBE, 0xAD, 0xDE];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.u32_le_at(4).unwrap(), 0xDEADBEEF);
+    /// assert_eq!(cursor.position(), 0);
+    /// ```
+    pub fn u32_le_at(&mut self, position: u64) -> Result<u32, BinaryCursorError> {
+        self.at(position, Self::parse_u32_le)
+    }
+
+    /// Parses the little-endian u64 at absolute offset `position` without moving the cursor
+    pub fn u64_le_at(&mut self, position: u64) -> Result<u64, BinaryCursorError> {
+        self.at(position, Self::parse_u64_le)
+    }
+
+    /// Parses the `len` bytes at absolute offset `position` without moving the cursor
+    ///
+    /// This behaves like [`extract_region`](Self::extract_region), except that it counts
+    /// towards the depth limit and its read is traced like any other parse method.
+    pub fn bytes_at(&mut self, position: u64, len: usize) -> Result<Vec<u8>, BinaryCursorError> {
+        self.at(position, |cursor| cursor.parse_bytes(len))
+    }
+
+    /// Parses a little-endian u32 offset field and returns the absolute position it refers to
+    ///
+    /// The offset is added to the position described by `base`. The result is not checked
+    /// against the data length, since some formats point into other files; use
+    /// [`deref_offset_u32_le`](Self::deref_offset_u32_le) to follow it. On error the
+    /// position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, OffsetBase};
+    ///
+    /// let data = vec![0xFF, 0xFF, 0x04, 0x00, 0x00, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    /// cursor.set_position(2);
+    ///
+    /// let target = cursor.parse_offset_u32_le(OffsetBase::CurrentFieldStart).unwrap();
+    /// assert_eq!(target, 6);
+    /// assert_eq!(cursor.position(), 6);
+    /// ```
+    pub fn parse_offset_u32_le(&mut self, base: OffsetBase) -> Result<u64, BinaryCursorError> {
+        self.traced("parse_offset_u32_le", |cursor| {
+            Ok(cursor.read_offset_u32_le(base, "parse_offset_u32_le")?.1)
+        })
+    }
+
+    /// Parses a little-endian u32 offset field, then runs `parser` at the position it refers to
+    ///
+    /// The offset is resolved as for [`parse_offset_u32_le`](Self::parse_offset_u32_le).
+    /// On success the cursor is left just after the offset field, as if only the field had
+    /// been read. A resolved position past the end of the data is an error naming it, and
+    /// on any error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, OffsetBase};
+    ///
+    /// let data = vec![0x05, 0x00, 0x00, 0x00, 0x01, b'o', b'k', 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let name = cursor
+    ///     .deref_offset_u32_le(OffsetBase::Start, |c| c.parse_cstr())
+    ///     .unwrap();
+    /// assert_eq!(name, "ok");
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0x01);
+    /// ```
+    pub fn deref_offset_u32_le<U, F>(
+        &mut self,
+        base: OffsetBase,
+        parser: F,
+    ) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        self.traced("deref_offset_u32_le", |cursor| {
+            cursor.atomic(|cursor| {
+                let field_start = cursor.data.position();
+                let (_, target) = cursor.read_offset_u32_le(base, "deref_offset_u32_le")?;
+                cursor.follow_offset(field_start, target, "deref_offset_u32_le", parser)
+            })
+        })
+    }
+
+    /// Like [`deref_offset_u32_le`](Self::deref_offset_u32_le), but treats an offset of 0 as
+    /// a null pointer
+    ///
+    /// For a null pointer the offset field is consumed and `Ok(None)` is returned without
+    /// running `parser`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, OffsetBase};
+    ///
+    /// let data = vec![0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let first = cursor
+    ///     .deref_optional_offset_u32_le(OffsetBase::CurrentFieldStart, |c| c.parse_u8())
+    ///     .unwrap();
+    /// assert_eq!(first, None);
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn deref_optional_offset_u32_le<U, F>(
+        &mut self,
+        base: OffsetBase,
+        parser: F,
+    ) -> Result<Option<U>, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        self.traced("deref_optional_offset_u32_le", |cursor| {
+            cursor.atomic(|cursor| {
+                let field_start = cursor.data.position();
+                let (offset, target) =
+                    cursor.read_offset_u32_le(base, "deref_optional_offset_u32_le")?;
+                if offset == 0 {
+                    return Ok(None);
+                }
+                cursor
+                    .follow_offset(field_start, target, "deref_optional_offset_u32_le", parser)
+                    .map(Some)
+            })
+        })
+    }
+
+    /// Reads a little-endian u32 offset field for `operation`, returning the raw offset and
+    /// the absolute position it resolves to against `base`
+    fn read_offset_u32_le(
+        &mut self,
+        base: OffsetBase,
+        operation: &'static str,
+    ) -> Result<(u32, u64), BinaryCursorError> {
+        let field_start = self.data.position();
+        let offset = u32::from_le_bytes(self.read_array(operation)?);
+        let base_position = match base {
+            OffsetBase::Start => 0,
+            OffsetBase::CurrentFieldStart => field_start,
+            OffsetBase::Absolute(position) => position,
+        };
+        match base_position.checked_add(offset as u64) {
+            Some(target) => Ok((offset, target)),
+            None => {
+                self.data.set_position(field_start);
+                Err(BinaryCursorError::InvalidData {
+                    operation,
+                    position: field_start,
+                    message: format!("offset {} from base {} overflows", offset, base_position),
+                })
+            }
+        }
+    }
+
+    /// Runs `parser` at `target`, which was resolved from the offset field at `field_start`
+    /// and must be within the data
+    fn follow_offset<U, F>(
+        &mut self,
+        field_start: u64,
+        target: u64,
+        operation: &'static str,
+        parser: F,
+    ) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        let len = self.len();
+        if target > len {
+            return Err(BinaryCursorError::InvalidData {
+                operation,
+                position: field_start,
+                message: format!(
+                    "offset resolves to {}, past the end of the data ({} bytes)",
+                    target, len
+                ),
+            });
+        }
+        self.at(target, parser)
+    }
+
+    /// Runs a parser with all-or-nothing position semantics
+    ///
+    /// If the parser succeeds, the position it left the cursor at is kept. If it fails, the
+    /// position is restored to where it was before the call, so partially consumed input is
+    /// never observable on error. The multi-field helpers on `BinaryCursor`, such as
+    /// [`count`](Self::count), [`delimited`](Self::delimited) and the length-prefixed
+    /// parsers, are built on this guarantee, as are the
+    /// [`FromCursor`](crate::from_cursor::FromCursor) implementations for tuples, arrays and
+    /// `#[derive(FromCursor)]` structs. This makes it safe to recover from a failed parse by
+    /// trying an alternative interpretation of the same bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let result = cursor.atomic(|c| {
+    ///     let a = c.parse_u16_le()?;
+    ///     let b = c.parse_u16_le()?;
+    ///     Ok((a, b))
+    /// });
+    /// assert!(result.is_err());
+    /// assert_eq!(cursor.position(), 0);
+    /// ```
+    pub fn atomic<U, F>(&mut self, parser: F) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        let start = self.data.position();
+        let result = parser(self);
+        if result.is_err() {
+            self.data.set_position(start);
+        }
+        result
+    }
+
+    /// Runs a parser with all-or-nothing position and location stack semantics
+    ///
+    /// This extends [`atomic`](Self::atomic) to the location stack: if the parser fails, the
+    /// position is restored and any locations it pushed but did not pop are discarded, so a
+    /// failed parse can never leave stray entries behind. Locations the parser popped from
+    /// below the starting depth cannot be brought back. On success both the position and the
+    /// stack are left as the parser left them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let result = cursor.transaction(|c| {
+    ///     c.push_location();
+    ///     c.set_position(2);
+    ///     c.parse_u16_le()
+    /// });
+    /// assert!(result.is_err());
+    /// assert_eq!(cursor.position(), 0);
+    /// assert_eq!(cursor.pop_location(), None);
+    /// ```
+    pub fn transaction<U, F>(&mut self, parser: F) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        let depth = self.location_depth();
+        let result = self.atomic(parser);
+        if result.is_err() {
+            self.truncate_locations(depth);
+        }
+        result
+    }
+
+    /// Creates an independent cursor over the same bytes, starting at the current position
+    ///
+    /// The fork borrows the data rather than copying it and shares this cursor's options
+    /// and bookmarks, but starts with an empty location stack. Nothing done with the fork
+    /// affects this cursor, which makes it suitable for speculatively trying one
+    /// interpretation of the data. Since the fork borrows this cursor, its progress is
+    /// adopted by taking its position and passing it to [`set_position`](Self::set_position)
+    /// once it has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x02, 0x00, 0xAA, 0xBB];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let end = {
+    ///     let mut fork = cursor.fork();
+    ///     assert_eq!(fork.parse_u16_le().unwrap(), 2);
+    ///     fork.position()
+    /// };
+    /// assert_eq!(cursor.position(), 0);
+    ///
+    /// cursor.set_position(end);
+    /// assert_eq!(cursor.parse_u8().unwrap(), 0xAA);
+    /// ```
+    pub fn fork(&self) -> BinaryCursor<&[u8]> {
+        let mut fork = BinaryCursor::with_options(self.data.get_ref().as_ref(), self.options);
+        fork.data.set_position(self.data.position());
+        fork.bookmarks = self.bookmarks.clone();
+        fork
+    }
+}
+
+/// The longest LEB128 encoding that can hold a 64-bit value
+const LEB128_MAX_LEN: usize = 10;
+
+/// The number of 100ns `FILETIME` ticks between 1601-01-01 and the Unix epoch
+const FILETIME_UNIX_EPOCH: u64 = 116_444_736_000_000_000;
+
+/// Builds the error for a LEB128 value at `position` with no terminating byte
+///
+/// This is either an over-long encoding or one cut off by the end of the data.
+fn leb128_unterminated(
+    operation: &'static str,
+    position: u64,
+    available: usize,
+) -> BinaryCursorError {
+    if available >= LEB128_MAX_LEN {
+        BinaryCursorError::InvalidData {
+            operation,
+            position,
+            message: format!("encoding is longer than {} bytes", LEB128_MAX_LEN),
+        }
+    } else {
+        BinaryCursorError::UnexpectedEof {
+            operation,
+            position,
+            requested: available + 1,
+            available,
+        }
+    }
+}
+
+/// Builds the error for a repeated parser at `position` that succeeded without consuming input
+fn no_progress(operation: &'static str, position: u64) -> BinaryCursorError {
+    BinaryCursorError::InvalidData {
+        operation,
+        position,
+        message: "parser succeeded without consuming any bytes".to_string(),
+    }
+}
+
+/// Formats bytes as space-separated upper-case hex pairs, for error messages
+fn hex_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Lookup table for the reflected IEEE CRC-32 polynomial, built at compile time
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the IEEE CRC-32 of `bytes`
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Builds the error for a nibble at `position` that is not a decimal digit
+fn invalid_bcd_digit(operation: &'static str, position: u64, digit: u8) -> BinaryCursorError {
+    BinaryCursorError::InvalidData {
+        operation,
+        position,
+        message: format!("nibble 0x{:X} is not a decimal digit", digit),
+    }
+}
+
+/// Widens the bits of an IEEE 754 half-precision float to an f32
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits >> 15) as u32) << 31;
+    let exponent = ((bits >> 10) & 0x1F) as u32;
+    let mantissa = (bits & 0x3FF) as u32;
+
+    match exponent {
+        // Zero and subnormals: mantissa * 2^-24, which is exact in an f32
+        0 => {
+            let magnitude = mantissa as f32 / (1u32 << 24) as f32;
+            if sign != 0 { -magnitude } else { magnitude }
+        }
+        // Infinities and NaN, keeping the NaN payload
+        0x1F => f32::from_bits(sign | 0x7F80_0000 | (mantissa << 13)),
+        _ => f32::from_bits(sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)),
+    }
+}
+
+/// Builds the error for a variable-length integer at `position` that is not minimally encoded
+fn non_canonical(operation: &'static str, position: u64) -> BinaryCursorError {
+    BinaryCursorError::InvalidData {
+        operation,
+        position,
+        message: "non-canonical encoding".to_string(),
+    }
+}
+
+/// Returns the name of the UTF-16 parse method for the given byte order, for error reporting
+fn utf16_operation(endianness: Endianness) -> &'static str {
+    match endianness {
+        Endianness::Little => "parse_utf16_le",
+        Endianness::Big => "parse_utf16_be",
+    }
+}
+
+/// Decodes UTF-16 code units stored in `bytes`, which started at `position`, with the given
+/// byte order
+fn decode_utf16(
+    bytes: &[u8],
+    endianness: Endianness,
+    position: u64,
+) -> Result<String, BinaryCursorError> {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|unit| match endianness {
+            Endianness::Little => u16::from_le_bytes([unit[0], unit[1]]),
+            Endianness::Big => u16::from_be_bytes([unit[0], unit[1]]),
+        })
+        .collect();
+
+    String::from_utf16(&units).map_err(|e| BinaryCursorError::InvalidData {
+        operation: utf16_operation(endianness),
+        position,
+        message: e.to_string(),
+    })
+}
+
+/// Decodes a u16 port number stored in `endianness`
+fn port_from_bytes(bytes: [u8; 2], endianness: Endianness) -> u16 {
+    match endianness {
+        Endianness::Little => u16::from_le_bytes(bytes),
+        Endianness::Big => u16::from_be_bytes(bytes),
+    }
+}
+// endregion: Cursor implementation
+
+// region: std::io implementations
+/// Reads from the current position, as for the inner [`Cursor`]
+///
+/// This allows a partially parsed cursor to be handed to code expecting [`Read`], such as a
+/// decompressor for an embedded stream. The position advances past whatever is read, so
+/// parsing can carry on afterwards. The location stack is not affected.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursor_binary_parser::binary_cursor::BinaryCursor;
+/// use std::io::Read;
+///
+/// let data = vec![0x03, b'a', b'b', b'c', 0x2A];
+/// let mut cursor = BinaryCursor::new(data);
+///
+/// let len = cursor.parse_u8().unwrap();
+/// let mut payload = String::new();
+/// (&mut cursor).take(len.into()).read_to_string(&mut payload).unwrap();
+/// assert_eq!(payload, "abc");
+/// assert_eq!(cursor.parse_u8().unwrap(), 0x2A);
+/// ```
+impl<T> Read for BinaryCursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.data.read(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        self.data.read_exact(buf)
+    }
+}
+
+/// Moves the position, exactly as [`BinaryCursor::set_position`] does
+///
+/// Seeking past the end is allowed, and seeking before the start is an error. The location
+/// stack is not affected.
+impl<T> Seek for BinaryCursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.data.seek(pos)
+    }
+
+    fn stream_position(&mut self) -> std::io::Result<u64> {
+        Ok(self.data.position())
+    }
+}
+
+/// Exposes the unread bytes as the buffer, so nothing is copied
+impl<T> BufRead for BinaryCursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.data.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.data.consume(amt)
+    }
+}
+// endregion: std::io implementations
+
+// region: CursorBuilder implementation
+/// A builder for creating a [`BinaryCursor`] with non-default [`CursorOptions`]
+///
+/// Options are checked when [`build`](Self::build) is called, so a builder can be configured
+/// in any order and reused for several cursors.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursor_binary_parser::binary_cursor::{BinaryCursorBuilder, Endianness};
+///
+/// let builder = BinaryCursorBuilder::new()
+///     .endianness(Endianness::Big)
+///     .strict(true)
+///     .max_alloc(1024);
+///
+/// let mut cursor = builder.build(vec![0x01, 0x02]).unwrap();
+/// assert_eq!(cursor.parse_u16().unwrap(), 0x0102);
+/// assert!(cursor.parse_bytes(2048).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BinaryCursorBuilder {
+    options: CursorOptions,
+}
+
+impl BinaryCursorBuilder {
+    /// Creates a builder with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the byte order used by methods without an explicit `_le` or `_be` suffix
+    pub fn endianness(mut self, endianness: Endianness) -> Self {
+        self.options.endianness = endianness;
+        self
+    }
+
+    /// Sets whether variable-length integers must use their shortest encoding
+    ///
+    /// This applies to [`parse_uleb128`](BinaryCursor::parse_uleb128),
+    /// [`parse_sleb128`](BinaryCursor::parse_sleb128) and
+    /// [`parse_vlq`](BinaryCursor::parse_vlq), and to everything built on them.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.options.strict = strict;
+        self
+    }
+
+    /// Limits the number of bytes a single caller-sized read may allocate
+    ///
+    /// This guards against length fields in untrusted data requesting huge buffers. It
+    /// applies to [`parse_bytes`](BinaryCursor::parse_bytes),
+    /// [`parse_bytes_rev`](BinaryCursor::parse_bytes_rev) and
+    /// [`extract_region`](BinaryCursor::extract_region), and to everything built on them.
+    pub fn max_alloc(mut self, bytes: usize) -> Self {
+        self.options.max_alloc = Some(bytes);
+        self
+    }
+
+    /// Limits how deep the location stack may grow through `BinaryCursorJump`
+    ///
+    /// This guards against offset chains in untrusted data recursing without bound. Calls to
+    /// [`push_location`](BinaryCursor::push_location) are not limited.
+    pub fn depth_limit(mut self, depth: usize) -> Self {
+        self.options.depth_limit = Some(depth);
+        self
+    }
+
+    /// Sets whether 2, 4 and 8 byte primitives must start at a multiple of their size
+    ///
+    /// Fixed-width timestamps are aligned like the integers they are stored as, and a DOS
+    /// date and time pair like a u16. Variable-width reads such as [`parse_uint`](BinaryCursor::parse_uint) and
+    /// [`parse_bytes`](BinaryCursor::parse_bytes) are not affected.
+    pub fn enforce_alignment(mut self, enforce: bool) -> Self {
+        self.options.enforce_alignment = enforce;
+        self
+    }
+
+    /// Creates a cursor over `data` with the configured options
+    ///
+    /// Returns an error if the options cannot be satisfied: a `max_alloc` of 0 would make
+    /// every sized read fail, and a `depth_limit` of 0 would make every jump fail.
+    pub fn build<T: AsRef<[u8]>>(self, data: T) -> Result<BinaryCursor<T>, BinaryCursorError> {
+        self.validate()?;
+        Ok(BinaryCursor::with_options(data, self.options))
+    }
+
+    /// Creates a streaming cursor over `reader` with the configured options
+    ///
+    /// The options are validated as for [`build`](Self::build), and the cursor starts at the
+    /// reader's current position.
+    pub fn build_streaming<R: Read + Seek>(
+        self,
+        reader: R,
+    ) -> Result<StreamingBinaryCursor<R>, BinaryCursorError> {
+        self.validate()?;
+        StreamingBinaryCursor::with_options(reader, self.options)
+    }
+
+    /// Checks that the configured options can be satisfied
+    fn validate(&self) -> Result<(), BinaryCursorError> {
+        let invalid = |message: &str| {
+            Err(BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                message.to_string(),
+            )))
+        };
+
+        if self.options.max_alloc == Some(0) {
+            return invalid("max_alloc must be greater than 0");
+        }
+        if self.options.depth_limit == Some(0) {
+            return invalid("depth_limit must be greater than 0");
+        }
+        Ok(())
+    }
+}
+// endregion: CursorBuilder implementation
+
+// region: CursorJump implementation
+/// A helper type for temporary position changes
+///
+/// This type provides a way to temporarily change the position of a `BinaryCursor`
+/// and automatically restore it when the `BinaryCursorJump` is dropped.
+/// Works with any type T that implements `AsRef<[u8]>`.
+///
+/// A guard can jump any number of times, such as when following a chain of offsets. On
+/// drop it restores the position from before its first jump and removes every location it
+/// saved, leaving the location stack as deep as when the guard was created.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursor_binary_parser::binary_cursor::{BinaryCursor, BinaryCursorJump};
+///
+/// let data = vec![0x02, 0x00, 0x04, 0x00, 0x2A];
+/// let mut cursor = BinaryCursor::new(data);
+/// {
+///     let mut jump = BinaryCursorJump::new(&mut cursor);
+///     jump.jump(0).unwrap();
+///     let next = jump.cursor.parse_u8().unwrap() as u64;
+///     jump.jump(next).unwrap();
+///     let next = jump.cursor.parse_u8().unwrap() as u64;
+///     jump.jump(next).unwrap();
+///     assert_eq!(jump.cursor.parse_u8().unwrap(), 0x2A);
+/// }
+/// assert_eq!(cursor.position(), 0);
+/// assert_eq!(cursor.location_depth(), 0);
+/// ```
+pub struct BinaryCursorJump<'a, T: AsRef<[u8]>> {
+    /// Reference to the cursor being manipulated
+    pub cursor: &'a mut BinaryCursor<T>,
+    /// The depth of the location stack when the guard was created
+    depth: usize,
+}
+
+impl<'a, T> BinaryCursorJump<'a, T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Creates a new `BinaryCursorJump` for the given cursor
+    pub fn new(cursor: &'a mut BinaryCursor<T>) -> Self {
+        let depth = cursor.location_depth();
+        Self { cursor, depth }
+    }
+
+    /// Checks that another position can be saved without exceeding the cursor's depth limit
+    fn check_depth(&self, operation: &'static str) -> Result<(), BinaryCursorError> {
+        match self.cursor.options.depth_limit {
+            Some(limit) if self.cursor.location_stack.len() >= limit => {
+                Err(BinaryCursorError::InvalidData {
+                    operation,
+                    position: self.cursor.position(),
+                    message: format!("location stack depth limit of {} reached", limit),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Temporarily jumps to the specified position
+    ///
+    /// The position will be automatically restored when the `BinaryCursorJump` is dropped.
+    /// Jumping to the end of the data is allowed, but a `location` past the end is an error,
+    /// since it usually comes from a corrupt offset field. If the location is out of range,
+    /// or the cursor has a depth limit and the location stack is already that deep, an error
+    /// is returned, the position is not changed and nothing is saved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, BinaryCursorJump};
+    ///
+    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let mut jump = BinaryCursorJump::new(&mut cursor);
+    /// assert!(jump.jump(4).is_ok());
+    /// let error = jump.jump(5).unwrap_err();
+    /// assert!(error.to_string().contains("jump target 5 is past the end of the data (4 bytes)"));
+    /// ```
+    pub fn jump(&mut self, location: u64) -> Result<(), BinaryCursorError> {
+        self.jump_to(location, "jump", true)
+    }
+
+    /// Temporarily jumps to the specified position without checking it against the data length
+    ///
+    /// This is for callers that deliberately position the cursor past the end. The depth
+    /// limit still applies, and the position is restored on drop as for
+    /// [`jump`](Self::jump).
+    pub fn jump_unchecked(&mut self, location: u64) -> Result<(), BinaryCursorError> {
+        self.jump_to(location, "jump_unchecked", false)
+    }
+
+    /// Saves the current position and moves to `location`, validating both first
+    fn jump_to(
+        &mut self,
+        location: u64,
+        operation: &'static str,
+        check_bounds: bool,
+    ) -> Result<(), BinaryCursorError> {
+        let len = self.cursor.len();
+        if check_bounds && location > len {
+            return Err(BinaryCursorError::InvalidData {
+                operation,
+                position: self.cursor.position(),
+                message: format!(
+                    "jump target {} is past the end of the data ({} bytes)",
+                    location, len
+                ),
+            });
+        }
+        self.check_depth(operation)?;
+        self.cursor.push_location();
+        self.cursor.set_position(location);
+        Ok(())
+    }
+
+    /// Temporarily jumps to a position relative to the current cursor location
+    ///
+    /// The position will be automatically restored when the `BinaryCursorJump` is dropped.
+    /// A positive offset moves forward, while a negative offset moves backward. The target is
+    /// checked as for [`jump`](Self::jump), and nothing is saved if it is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, BinaryCursorJump};
+    ///
+    /// let data = vec![0x01, 0x02, 0x03, 0x04];
+    /// let mut cursor = BinaryCursor::new(data);
+    /// cursor.set_position(1);
+    ///
+    /// {
+    ///     let mut jump = BinaryCursorJump::new(&mut cursor);
+    ///     jump.jump_relative(2).unwrap();
+    ///     assert_eq!(jump.cursor.position(), 3);
+    /// }
+    /// assert_eq!(cursor.position(), 1);
+    /// ```
+    pub fn jump_relative(&mut self, offset: i64) -> Result<(), BinaryCursorError> {
+        let current_pos = self.cursor.position();
+        let new_pos = if offset >= 0 {
+            current_pos.checked_add(offset as u64)
+        } else {
+            current_pos.checked_sub(offset.unsigned_abs())
+        }
+        .ok_or_else(|| {
+            BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Position would overflow/underflow",
+            ))
+        })?;
+        self.jump_to(new_pos, "jump_relative", true)
+    }
+
+    /// Temporarily jumps to the position described by `from`
+    ///
+    /// Positions are resolved as for [`BinaryCursor::try_seek`], so `SeekFrom::End(-n)` reaches
+    /// a footer `n` bytes before the end. The position will be automatically restored when
+    /// the `BinaryCursorJump` is dropped. On error nothing is pushed and the position is
+    /// left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, BinaryCursorJump};
+    /// use std::io::SeekFrom;
+    ///
+    /// let data = vec![0x01, 0x02, 0x03, 0x04, 0xCD, 0xAB];
+    /// let mut cursor = BinaryCursor::new(data);
+    /// {
+    ///     let mut jump = BinaryCursorJump::new(&mut cursor);
+    ///     jump.jump_seek(SeekFrom::End(-2)).unwrap();
+    ///     assert_eq!(jump.cursor.parse_u16_le().unwrap(), 0xABCD);
+    /// }
+    /// assert_eq!(cursor.position(), 0);
+    /// ```
+    pub fn jump_seek(&mut self, from: SeekFrom) -> Result<(), BinaryCursorError> {
+        let target = self.cursor.seek_target(from, "jump_seek")?;
+        self.jump_to(target, "jump_seek", true)
+    }
+
+    /// Temporarily jumps to the position saved under the bookmark `name`
+    ///
+    /// The position will be automatically restored when the `BinaryCursorJump` is dropped.
+    /// Returns an error and does not jump if there is no such bookmark.
+    pub fn jump_to_bookmark(&mut self, name: &str) -> Result<(), BinaryCursorError> {
+        let location = self.cursor.bookmark_or_err(name)?;
+        self.jump(location)
+    }
+
+    /// Consumes the guard, keeping the cursor wherever the jumped parsing left it
+    ///
+    /// The locations this guard saved are discarded without being restored. Locations saved
+    /// before the guard was created, such as those of an outer jump, are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::{BinaryCursor, BinaryCursorJump};
+    ///
+    /// let data = vec![0x03, 0x00, 0x00, 0x2A, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let next = cursor.parse_u8().unwrap() as u64;
+    /// let mut jump = BinaryCursorJump::new(&mut cursor);
+    /// jump.jump(next).unwrap();
+    /// assert_eq!(jump.cursor.parse_u8().unwrap(), 0x2A);
+    /// jump.commit();
+    ///
+    /// assert_eq!(cursor.position(), 4);
+    /// assert_eq!(cursor.pop_location(), None);
+    /// ```
+    pub fn commit(self) {
+        self.cursor.location_stack.truncate(self.depth);
+    }
+
+    /// Consumes the guard, restoring the position immediately
+    ///
+    /// This is the same as dropping the guard, for code that reads better with an explicit
+    /// restore.
+    pub fn abort(self) {
+        drop(self);
+    }
+}
+
+impl<'a, T> Drop for BinaryCursorJump<'a, T>
+where
+    T: AsRef<[u8]>,
+{
+    fn drop(&mut self) {
+        // Only restore if this guard saved a position, so that a guard whose jumps all
+        // failed cannot pop a location saved by someone else. The first location it saved
+        // is the position from before its first jump.
+        if self.cursor.location_depth() > self.depth {
+            self.cursor.location_stack.truncate(self.depth + 1);
+            self.cursor.restore_location();
+        }
+    }
+}
+// endregion: CursorJump implementation
+
+// region: CursorRev implementation
+/// A helper type for reading backwards from the current position
+///
+/// This type is returned by [`BinaryCursor::rev_mode`] and flips the direction of the
+/// common parse methods for as long as it is held: each method reads the bytes ending at
+/// the current position and moves the position back past them.
+pub struct BinaryCursorRev<'a, T: AsRef<[u8]>> {
+    /// Reference to the cursor being read backwards
+    pub cursor: &'a mut BinaryCursor<T>,
+}
+
+impl<'a, T> BinaryCursorRev<'a, T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Parses the u8 immediately before the current position
+    pub fn parse_u8(&mut self) -> Result<u8, BinaryCursorError> {
+        self.cursor.parse_u8_rev()
+    }
+
+    /// Parses the little-endian u16 ending at the current position
+    pub fn parse_u16_le(&mut self) -> Result<u16, BinaryCursorError> {
+        self.cursor.parse_u16_le_rev()
+    }
+
+    /// Parses the little-endian u32 ending at the current position
+    pub fn parse_u32_le(&mut self) -> Result<u32, BinaryCursorError> {
+        self.cursor.parse_u32_le_rev()
+    }
+
+    /// Parses the little-endian u64 ending at the current position
+    pub fn parse_u64_le(&mut self) -> Result<u64, BinaryCursorError> {
+        self.cursor.parse_u64_le_rev()
+    }
+
+    /// Parses the `count` bytes ending at the current position
+    pub fn parse_bytes(&mut self, count: usize) -> Result<Vec<u8>, BinaryCursorError> {
+        self.cursor.parse_bytes_rev(count)
+    }
+}
+// endregion: CursorRev implementation
+
+// region: RecordIter implementation
+/// An iterator that parses records until the end of a `BinaryCursor`
+///
+/// This type is returned by [`BinaryCursor::iter_with`]. It yields `Ok` items until no bytes
+/// remain, or a single `Err` followed by `None` if a record fails to parse.
+pub struct RecordIter<'a, T: AsRef<[u8]>, P> {
+    /// Reference to the cursor being iterated over
+    cursor: &'a mut BinaryCursor<T>,
+    parser: P,
+    /// Set once an error has been yielded
+    done: bool,
+}
+
+impl<'a, T, U, P> Iterator for RecordIter<'a, T, P>
+where
+    T: AsRef<[u8]>,
+    P: FnMut(&mut BinaryCursor<T>) -> Result<U, BinaryCursorError>,
+{
+    type Item = Result<U, BinaryCursorError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.cursor.remaining() == 0 {
+            return None;
+        }
+
+        let start = self.cursor.position();
+        let result = match self.cursor.atomic(&mut self.parser) {
+            Ok(_) if self.cursor.position() == start => Err(no_progress("iter_with", start)),
+            result => result,
+        };
+        self.done = result.is_err();
+        Some(result)
+    }
+}
+// endregion: RecordIter implementation
+
+// region: CursorBits implementation
+/// A helper type for reading individual bits from a `BinaryCursor`
+///
+/// This type is returned by [`BinaryCursor::bits`]. It keeps track of the byte currently
+/// being consumed, and when dropped any remaining bits of that byte are discarded so the
+/// cursor is left at a byte boundary.
+pub struct BinaryCursorBits<'a, T: AsRef<[u8]>> {
+    cursor: &'a mut BinaryCursor<T>,
+    order: BitOrder,
+    /// The byte currently being consumed
+    current: u8,
+    /// The number of unread bits remaining in `current`
+    bits_left: u8,
+}
+
+impl<'a, T> BinaryCursorBits<'a, T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Reads a single bit
+    pub fn read_bit(&mut self) -> Result<bool, BinaryCursorError> {
+        Ok(self.read_bits(1)? == 1)
+    }
+
+    /// Reads `n` bits (0 to 64) as an unsigned value
+    ///
+    /// Reads may span any number of byte boundaries. If there are not enough bits left in
+    /// the data, an error is returned and nothing is consumed.
+    pub fn read_bits(&mut self, n: u8) -> Result<u64, BinaryCursorError> {
+        if n > 64 {
+            return Err(BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Cannot read more than 64 bits at once, got {}", n),
+            )));
+        }
+
+        let bytes_needed = n.saturating_sub(self.bits_left).div_ceil(8) as usize;
+        let available = self.cursor.unread().len();
+        if bytes_needed > available {
+            return Err(BinaryCursorError::UnexpectedEof {
+                operation: "read_bits",
+                position: self.cursor.position(),
+                requested: bytes_needed,
+                available,
+            });
+        }
+
+        let mut value = 0u64;
+        for i in 0..n {
+            if self.bits_left == 0 {
+                self.current = self.cursor.parse_u8()?;
+                self.bits_left = 8;
+            }
+
+            let bit = match self.order {
+                BitOrder::MsbFirst => (self.current >> (self.bits_left - 1)) & 1,
+                BitOrder::LsbFirst => (self.current >> (8 - self.bits_left)) & 1,
+            };
+            self.bits_left -= 1;
+
+            value = match self.order {
+                BitOrder::MsbFirst => (value << 1) | u64::from(bit),
+                BitOrder::LsbFirst => value | (u64::from(bit) << i),
+            };
+        }
+        Ok(value)
+    }
+
+    /// Discards any unread bits of the current byte
+    ///
+    /// This is a no-op if the reader is already at a byte boundary.
+    pub fn align_to_byte(&mut self) {
+        self.bits_left = 0;
+    }
+
+    /// Returns `true` if no partially consumed byte is in flight
+    pub fn is_aligned(&self) -> bool {
+        self.bits_left == 0
+    }
+
+    /// Returns the absolute position in bits of the next bit to be read
+    pub fn bit_position(&self) -> u64 {
+        self.cursor.position() * 8 - u64::from(self.bits_left)
+    }
+}
+// endregion: CursorBits implementation
+
+// region: BitField implementation
+/// An integer whose individual bits and bit ranges can be read by index
+///
+/// This type is returned by [`BinaryCursor::parse_u8_bits`] and its wider siblings, and is
+/// intended for flag bytes and words that pack several small fields. Bits are numbered
+/// LSB-0: bit 0 is the least significant. Indices outside the integer are reported as
+/// errors rather than panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitField<T> {
+    value: T,
+}
+
+impl<T> BitField<T>
+where
+    T: Copy + Into<u64>,
+{
+    /// Creates a `BitField` over `value`
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// Returns the raw integer
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the number of bits in the integer
+    pub fn width(&self) -> u8 {
+        (std::mem::size_of::<T>() * 8) as u8
+    }
+
+    /// Returns whether bit `n` is set, counting from the least significant bit
+    pub fn bit(&self, n: u8) -> Result<bool, BinaryCursorError> {
+        if n >= self.width() {
+            return Err(BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Bit {} is outside a {}-bit field", n, self.width()),
+            )));
+        }
+        Ok(self.bits(n..n + 1)? == 1)
+    }
+
+    /// Returns the contiguous field covering bits `range`, counting from the least
+    /// significant bit and shifted down so that its lowest bit is bit 0 of the result
+    ///
+    /// An empty range yields 0. A range that ends past the width of the integer is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BitField;
+    ///
+    /// let field = BitField::new(0b1011_0110u8);
+    /// assert_eq!(field.bits(0..3).unwrap(), 0b110);
+    /// assert_eq!(field.bits(4..8).unwrap(), 0b1011);
+    /// assert!(field.bits(6..9).is_err());
+    /// ```
+    pub fn bits(&self, range: Range<u8>) -> Result<u64, BinaryCursorError> {
+        if range.start > range.end || range.end > self.width() {
+            return Err(BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Bit range {}..{} is outside a {}-bit field",
+                    range.start,
+                    range.end,
+                    self.width()
+                ),
+            )));
+        }
+
+        let len = range.end - range.start;
+        let shifted = self.value.into() >> range.start.min(63);
+        Ok(match len {
+            0 => 0,
+            64 => shifted,
+            _ => shifted & ((1u64 << len) - 1),
+        })
+    }
+}
+// endregion: BitField implementation
+
+// region: Uuid implementation
+/// A 128-bit UUID (or GUID), stored in the RFC 4122 byte order
+///
+/// This is returned by [`BinaryCursor::parse_uuid_be`] and
+/// [`BinaryCursor::parse_uuid_le_mixed`]. Whichever layout it was read from, [`as_bytes`]
+/// returns the canonical big-endian bytes and `Display` gives the usual lowercase
+/// 8-4-4-4-12 hex form.
+///
+/// [`as_bytes`]: Uuid::as_bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    /// Creates a `Uuid` from its 16 bytes in RFC 4122 (big-endian) order
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the 16 bytes in RFC 4122 (big-endian) order
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Creates a `Uuid` from bytes in the Microsoft GUID layout
+    ///
+    /// In that layout the first three groups (4, 2 and 2 bytes) are little-endian and the
+    /// remaining 8 bytes are stored as they are.
+    pub fn from_bytes_le_mixed(mut bytes: [u8; 16]) -> Self {
+        bytes[0..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+        Self(bytes)
+    }
+}
+
+impl From<[u8; 16]> for Uuid {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl std::fmt::Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+// endregion: Uuid implementation
+
+// region: DosDateTime implementation
+/// A calendar date and time decoded from the packed MS-DOS format used by FAT and ZIP
+///
+/// MS-DOS timestamps carry no time zone and have a resolution of two seconds, so this is
+/// returned as plain fields rather than a [`SystemTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DosDateTime {
+    /// The year, from 1980 to 2107
+    pub year: u16,
+    /// The month, from 1 to 12
+    pub month: u8,
+    /// The day of the month, from 1
+    pub day: u8,
+    /// The hour, from 0 to 23
+    pub hour: u8,
+    /// The minute, from 0 to 59
+    pub minute: u8,
+    /// The second, an even number from 0 to 58
+    pub second: u8,
+}
+
+impl DosDateTime {
+    /// Decodes a packed MS-DOS `date` and `time`, or describes the first invalid field
+    fn from_packed(date: u16, time: u16) -> Result<Self, String> {
+        let year = 1980 + (date >> 9);
+        let month = ((date >> 5) & 0x0F) as u8;
+        let day = (date & 0x1F) as u8;
+        let hour = (time >> 11) as u8;
+        let minute = ((time >> 5) & 0x3F) as u8;
+        let second = ((time & 0x1F) * 2) as u8;
+
+        let leap =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => {
+                return Err(format!(
+                    "DOS date 0x{:04X} has invalid month {}",
+                    date, month
+                ));
+            }
+        };
+        if day == 0 || day > days_in_month {
+            return Err(format!("DOS date 0x{:04X} has invalid day {}", date, day));
+        }
+        if hour > 23 || minute > 59 || second > 58 {
+            return Err(format!(
+                "DOS time 0x{:04X} is not a valid time of day ({:02}:{:02}:{:02})",
+                time, hour, minute, second
+            ));
+        }
+
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        })
+    }
+}
+// endregion: DosDateTime implementation
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_u8() {
+        let data = vec![0x42, 0x43, 0x44];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x42);
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_parse_u16_le() {
+        let data = vec![0x42, 0x24, 0x43, 0x25];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x2442);
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_parse_u32_le() {
+        let data = vec![0x42, 0x24, 0x00, 0x01, 0x43, 0x25, 0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_u32_le().unwrap(), 0x01002442);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_parse_u64_le() {
+        let data = vec![
+            0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // 18446744073709551614
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // 18446744073709551615
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_u64_le().unwrap(), 18446744073709551614);
+        assert_eq!(cursor.parse_u64_le().unwrap(), 18446744073709551615);
+        assert_eq!(cursor.parse_u64_le().unwrap(), 0);
+        assert_eq!(cursor.position(), 24);
+    }
+
+    #[test]
+    fn test_parse_f32_le() {
+        let data = vec![0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x40];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_f32_le().unwrap(), 1.0);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_parse_f64_le() {
+        let data = vec![
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // 1.0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, // 2.0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xBF, // -1.0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0.0
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_f64_le().unwrap(), 1.0);
+        assert_eq!(cursor.parse_f64_le().unwrap(), 2.0);
+        assert_eq!(cursor.parse_f64_le().unwrap(), -1.0);
+        assert_eq!(cursor.parse_f64_le().unwrap(), 0.0);
+        assert_eq!(cursor.position(), 32);
+    }
+
+    #[test]
+    fn test_parse_f16() {
+        let data = vec![
+            0x00, 0x3C, // 1.0
+            0x00, 0xC1, // -2.5
+            0x01, 0x00, // smallest subnormal, 2^-24
+            0x00, 0x7C, // +inf
+            0x00, 0xFC, // -inf
+            0x01, 0x7E, // NaN
+            0x7B, 0xFF, // 65504, the largest finite value, big-endian
+            0x80, 0x00, // -0.0, big-endian
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_f16_le().unwrap(), 1.0);
+        assert_eq!(cursor.parse_f16_le().unwrap(), -2.5);
+        assert_eq!(cursor.parse_f16_le().unwrap(), 2f32.powi(-24));
+        assert_eq!(cursor.parse_f16_le().unwrap(), f32::INFINITY);
+        assert_eq!(cursor.parse_f16_le().unwrap(), f32::NEG_INFINITY);
+        assert!(cursor.parse_f16_le().unwrap().is_nan());
+        assert_eq!(cursor.parse_f16_be().unwrap(), 65504.0);
+        let zero = cursor.parse_f16_be().unwrap();
+        assert!(zero == 0.0 && zero.is_sign_negative());
+        assert_eq!(cursor.position(), 16);
+
+        let error = cursor.parse_f16_le().unwrap_err();
+        assert_eq!(error.fields().operation, Some("parse_f16_le"));
+    }
+
+    #[test]
+    fn test_parse_f64_le_error_handling() {
+        let data = vec![0x42];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_f64_le().is_err());
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_bytes(4).unwrap(), vec![0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_location_stack() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let mut cursor = BinaryCursor::new(data);
+
+        cursor.push_location();
+        cursor.set_position(4);
+        assert_eq!(cursor.position(), 4);
+
+        assert!(cursor.restore_location());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_location_stack_beyond_4_gib() {
+        // Positions past the end are allowed, so no large buffer is needed
+        let far = u32::MAX as u64 + 10;
+        let mut cursor = BinaryCursor::new(vec![0x01]);
+
+        cursor.set_position(far);
+        cursor.push_location();
+        assert_eq!(cursor.peek_location(), Some(far));
+        cursor.set_position(0);
+        cursor.try_restore_location().unwrap();
+        assert_eq!(cursor.position(), far);
+    }
+
+    #[test]
+    fn test_location_depth_and_truncate() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.location_depth(), 0);
+        assert_eq!(cursor.peek_location(), None);
+
+        for pos in 0..3 {
+            cursor.set_position(pos);
+            cursor.push_location();
+        }
+        assert_eq!(cursor.location_depth(), 3);
+        assert_eq!(cursor.peek_location(), Some(2));
+
+        cursor.truncate_locations(5);
+        assert_eq!(cursor.location_depth(), 3);
+        cursor.truncate_locations(1);
+        assert_eq!(cursor.location_depth(), 1);
+        assert_eq!(cursor.peek_location(), Some(0));
+        assert_eq!(cursor.position(), 2);
+
+        cursor.clear_locations();
+        assert_eq!(cursor.location_depth(), 0);
+        let error = cursor.try_restore_location().unwrap_err();
+        assert!(error.to_string().contains("location stack is empty"));
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_binary_cursor_jump() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let mut cursor = BinaryCursor::new(data);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump(4).unwrap();
+            assert_eq!(jump.cursor.position(), 4);
+        }
+
+        assert_eq!(cursor.position(), 0u64);
+    }
+
+    #[test]
+    fn test_jump_bounds_checked() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            // The end position itself is valid
+            jump.jump(4).unwrap();
+            assert!(jump.cursor.at_end());
+        }
+        assert_eq!(cursor.position(), 1);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            let error = jump.jump(5).unwrap_err();
+            assert_eq!(error.fields().kind, "invalid_data");
+            assert!(error.to_string().contains("jump target 5"));
+            assert!(jump.jump_relative(4).is_err());
+            assert!(jump.jump_relative(-2).is_err());
+            assert_eq!(jump.cursor.location_depth(), 0);
+            assert_eq!(jump.cursor.position(), 1);
+        }
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_failed_jump_keeps_outer_location() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        let mut outer = BinaryCursorJump::new(&mut cursor);
+        outer.jump(2).unwrap();
+        {
+            let mut inner = BinaryCursorJump::new(outer.cursor);
+            assert!(inner.jump(10).is_err());
+        }
+        // The failed inner guard must not have popped the outer guard's location
+        assert_eq!(outer.cursor.position(), 2);
+        assert_eq!(outer.cursor.location_depth(), 1);
+        drop(outer);
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_try_set_position() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        cursor.try_set_position(4).unwrap();
+        assert!(cursor.at_end());
+        cursor.try_set_position(0).unwrap();
+
+        let error = cursor.try_set_position(5).unwrap_err();
+        assert_eq!(error.fields().operation, Some("try_set_position"));
+        assert_eq!(
+            error.fields().message,
+            "position 5 is past the end of the data (4 bytes)"
+        );
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_advance_and_rewind() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        cursor.advance(3).unwrap();
+        cursor.advance(1).unwrap();
+        assert!(cursor.advance(1).is_err());
+        assert_eq!(cursor.position(), 4);
+
+        cursor.rewind(4).unwrap();
+        assert_eq!(cursor.position(), 0);
+        let error = cursor.rewind(1).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("cannot rewind 1 bytes from position 0")
+        );
+        assert_eq!(cursor.position(), 0);
+
+        // Overflow is reported rather than wrapped
+        cursor.set_position(2);
+        let error = cursor.advance(u64::MAX).unwrap_err();
+        assert!(error.to_string().contains("2 + 18446744073709551615"));
+        assert_eq!(cursor.position(), 2);
+
+        // Rewinding from past the end back into range is fine
+        cursor.set_position(10);
+        assert!(cursor.rewind(5).is_err());
+        cursor.rewind(7).unwrap();
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_jump_multi_hop() {
+        // A section table at 8 points at a record at 12, which points at a name at 16
+        let data = vec![
+            0xEE, 0xEE, 0xEE, 0xEE, 0xEE, 0xEE, 0xEE, 0xEE, 12, 0, 0, 0, 16, 0, 0, 0, b'o', b'k',
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(2);
+        cursor.push_location();
+        cursor.set_position(3);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump(8).unwrap();
+            let record = jump.cursor.parse_u32_le().unwrap() as u64;
+            jump.jump(record).unwrap();
+            let name = jump.cursor.parse_u32_le().unwrap() as u64;
+            jump.jump(name).unwrap();
+            assert_eq!(jump.cursor.parse_bytes(2).unwrap(), b"ok");
+            assert_eq!(jump.cursor.location_depth(), 4);
+        }
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.location_depth(), 1);
+
+        // An excursion that fails partway through is unwound just the same
+        let result = (|| {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump(8)?;
+            jump.jump_relative(4)?;
+            jump.jump(100)?;
+            Ok::<_, BinaryCursorError>(())
+        })();
+        assert!(result.is_err());
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.location_depth(), 1);
+        assert_eq!(cursor.pop_location(), Some(2));
+    }
+
+    #[test]
+    fn test_jump_commit() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        let mut jump = BinaryCursorJump::new(&mut cursor);
+        jump.jump(3).unwrap();
+        assert_eq!(jump.cursor.parse_u8().unwrap(), 0x04);
+        jump.commit();
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.location_depth(), 0);
+
+        // Dropping without committing still restores
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump(0).unwrap();
+            jump.cursor.parse_u8().unwrap();
+        }
+        assert_eq!(cursor.position(), 4);
+
+        let mut jump = BinaryCursorJump::new(&mut cursor);
+        jump.jump(1).unwrap();
+        jump.abort();
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.location_depth(), 0);
+
+        // A guard that never jumped commits nothing
+        cursor.push_location();
+        BinaryCursorJump::new(&mut cursor).commit();
+        assert_eq!(cursor.pop_location(), Some(4));
+    }
+
+    #[test]
+    fn test_jump_commit_nested() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        {
+            let mut outer = BinaryCursorJump::new(&mut cursor);
+            outer.jump(2).unwrap();
+
+            let mut inner = BinaryCursorJump::new(outer.cursor);
+            inner.jump(4).unwrap();
+            inner.cursor.parse_u8().unwrap();
+            inner.commit();
+
+            assert_eq!(outer.cursor.position(), 5);
+            assert_eq!(outer.cursor.location_depth(), 1);
+            assert_eq!(outer.cursor.parse_u8().unwrap(), 0x06);
+        }
+        // The outer jump still restores to where it started
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.location_depth(), 0);
+    }
+
+    #[test]
+    fn test_split_regions() {
+        let mut data = vec![];
+        for section in 0..8u32 {
+            data.extend_from_slice(&4u32.to_le_bytes());
+            for value in 0..4 {
+                data.extend_from_slice(&(section * 10 + value).to_le_bytes());
+            }
+        }
+        let cursor = BinaryCursor::new(data);
+        let regions: Vec<(u64, u64)> = (0..8).map(|i| (i * 20, 20)).collect();
+
+        let sections = cursor.split_regions(&regions).unwrap();
+        let sums: Vec<u32> = std::thread::scope(|scope| {
+            let handles: Vec<_> = sections
+                .into_iter()
+                .map(|mut section| {
+                    scope.spawn(move || {
+                        let count = section.parse_u32_le().unwrap() as usize;
+                        let values = section.count(|c| c.parse_u32_le(), count).unwrap();
+                        // Reads cannot leave the region
+                        assert!(section.parse_u8().is_err());
+                        values.iter().sum()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(sums, (0..8).map(|s| s * 40 + 6).collect::<Vec<u32>>());
+        assert_eq!(cursor.position(), 0);
+
+        // Overlapping and empty regions are allowed
+        let sections = cursor.split_regions(&[(0, 8), (4, 8), (160, 0)]).unwrap();
+        assert_eq!(sections[1].len(), 8);
+        assert!(sections[2].is_empty());
+        assert!(cursor.split_regions(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_split_regions_bounds() {
+        let cursor = BinaryCursor::new(vec![0u8; 16]);
+
+        let error = cursor
+            .split_regions(&[(0, 4), (4, 4), (12, 5), (20, 1)])
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.position(), Some(12));
+        assert!(
+            error
+                .to_string()
+                .contains("region 2 (5 bytes at offset 12)")
+        );
+
+        let error = cursor.split_regions(&[(1, u64::MAX)]).unwrap_err();
+        assert!(error.to_string().contains("region 0"));
+    }
+
+    #[test]
+    fn test_jump_unchecked() {
+        let data = vec![0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump_unchecked(100).unwrap();
+            assert_eq!(jump.cursor.position(), 100);
+            assert!(jump.cursor.parse_u8().is_err());
+        }
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_sequential_parsing() {
+        let data = vec![0x42, 0x24, 0x00, 0x01, 0x43, 0x25, 0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.parse_u8().unwrap(), 0x42);
+        assert_eq!(cursor.position(), 1);
+
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0024);
+        assert_eq!(cursor.position(), 3);
+
+        assert_eq!(cursor.parse_u32_le().unwrap(), 0x01254301);
+        assert_eq!(cursor.position(), 7);
+
+        assert!(cursor.parse_u8().is_ok());
+        assert_eq!(cursor.position(), 8);
+    }
+
+    #[test]
+    fn test_count() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        let result = cursor.count(|c| c.parse_u8(), 4).unwrap();
+        assert_eq!(result, vec![0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_count_failure_restores_position() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        assert!(cursor.count(|c| c.parse_u16_le(), 3).is_err());
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_parse_slices_match_per_element() {
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut bulk = BinaryCursor::new(&data);
+        let mut single = BinaryCursor::new(&data);
+
+        assert_eq!(
+            bulk.parse_u16_slice_le(100).unwrap(),
+            single.count(|c| c.parse_u16_le(), 100).unwrap()
+        );
+        assert_eq!(
+            bulk.parse_i16_slice_le(100).unwrap(),
+            single.count(|c| c.parse_i16_le(), 100).unwrap()
+        );
+        assert_eq!(
+            bulk.parse_u32_slice_le(100).unwrap(),
+            single.count(|c| c.parse_u32_le(), 100).unwrap()
+        );
+        let bulk_floats = bulk.parse_f32_slice_le(100).unwrap();
+        let single_floats = single.count(|c| c.parse_f32_le(), 100).unwrap();
+        assert!(
+            bulk_floats
+                .iter()
+                .zip(&single_floats)
+                .all(|(a, b)| a.to_bits() == b.to_bits())
+        );
+        assert_eq!(bulk.position(), single.position());
+        assert_eq!(bulk.parse_u32_slice_le(0).unwrap(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_parse_slices_bounds() {
+        let data = vec![0x01, 0x00, 0x02, 0x00, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+
+        let error = cursor.parse_u16_slice_le(3).unwrap_err();
+        assert_eq!(error.fields().operation, Some("parse_u16_slice_le"));
+        assert_eq!(error.fields().requested, Some(6));
+        assert_eq!(cursor.position(), 0);
+
+        // Huge counts fail without allocating, including ones whose byte length overflows
+        let error = cursor.parse_f32_slice_le(usize::MAX / 2).unwrap_err();
+        assert_eq!(error.fields().kind, "parse_error");
+        assert_eq!(error.fields().requested, None);
+        assert!(error.to_string().contains("too large"));
+        assert!(cursor.parse_u32_slice_le(1 << 40).is_err());
+        assert_eq!(cursor.position(), 0);
+
+        let mut cursor = BinaryCursorBuilder::new()
+            .max_alloc(3)
+            .build(vec![0u8; 8])
+            .unwrap();
+        let error = cursor.parse_u16_slice_le(2).unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+    }
+
+    #[test]
+    fn test_parse_array() {
+        let data: Vec<u8> = (0..20).collect();
+        let mut cursor = BinaryCursor::new(data);
+
+        let empty: [u8; 0] = cursor.parse_array().unwrap();
+        assert_eq!(empty, []);
+        assert_eq!(cursor.position(), 0);
+
+        let key: [u8; 16] = cursor.parse_array().unwrap();
+        assert_eq!(key[0], 0);
+        assert_eq!(key[15], 15);
+        assert_eq!(cursor.position(), 16);
+
+        let error = cursor.parse_array::<16>().unwrap_err();
+        assert_eq!(error.fields().operation, Some("parse_array"));
+        assert_eq!(error.fields().available, Some(4));
+        assert_eq!(cursor.position(), 16);
+    }
+
+    #[test]
+    fn test_count_array() {
+        let data = vec![0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        let none: [u16; 0] = cursor.count_array(|c| c.parse_u16_le()).unwrap();
+        assert_eq!(none, []);
+        let values: [u16; 3] = cursor.count_array(|c| c.parse_u16_le()).unwrap();
+        assert_eq!(values, [1, 2, 3]);
+
+        // Truncated: the last u16 is incomplete
+        cursor.set_position(2);
+        let error = cursor
+            .count_array::<_, _, 3>(|c| c.parse_u16_le())
+            .unwrap_err();
+        assert_eq!(error.position(), Some(6));
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_count_array_drops_partial_items() {
+        let marker = std::rc::Rc::new(());
+        let data = vec![0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+
+        let result = cursor.count_array::<_, _, 4>(|c| {
+            c.parse_u8()?;
+            Ok(marker.clone())
+        });
+        assert!(result.is_err());
+        assert_eq!(std::rc::Rc::strong_count(&marker), 1);
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_opt() {
+        let data = vec![0x01, 0x02, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.opt(|c| c.expect_tag(&[0xFF])).unwrap(), None);
+        assert_eq!(cursor.opt(|c| c.parse_u8()).unwrap(), Some(0x01));
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_opt_partial_struct_restored() {
+        // A (u8, u16, u32) record where the final field is truncated
+        let data = vec![0x01, 0x02, 0x00, 0x03, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+
+        let record = cursor
+            .opt(|c| Ok((c.parse_u8()?, c.parse_u16_le()?, c.parse_u32_le()?)))
+            .unwrap();
+        assert_eq!(record, None);
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x01);
+    }
+
+    #[test]
+    fn test_alt() {
+        // A version 2 header is a u8 version followed by a u32; version 1 uses a u16
+        let data = vec![0x01, 0x34, 0x12, 0xFF];
+        let mut cursor = BinaryCursor::new(data);
+
+        let value = cursor
+            .alt(&mut [
+                &mut |c| {
+                    c.expect_tag(&[0x02])?;
+                    c.parse_u32_le()
+                },
+                &mut |c| {
+                    c.expect_tag(&[0x01])?;
+                    c.parse_u16_le().map(u32::from)
+                },
+            ])
+            .unwrap();
+        assert_eq!(value, 0x1234);
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_alt_rollback_between_attempts() {
+        let data = vec![0x01, 0x02, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+
+        // The first alternative consumes two bytes before failing
+        let value = cursor
+            .alt(&mut [
+                &mut |c| {
+                    c.parse_u16_le()?;
+                    c.parse_u16_le()
+                },
+                &mut |c| c.parse_u16_le(),
+            ])
+            .unwrap();
+        assert_eq!(value, 0x0201);
+
+        let error = cursor
+            .alt(&mut [&mut |c| c.expect_tag(&[0xAA]).map(|_| 1), &mut |c| {
+                c.expect_tag(&[0xBB]).map(|_| 2)
+            }])
+            .unwrap_err();
+        assert!(error.to_string().contains("[BB]"));
+        assert_eq!(cursor.position(), 2);
+
+        let parsers: &mut [Alternative<'_, Vec<u8>, u8>] = &mut [];
+        assert!(cursor.alt(parsers).is_err());
+    }
+
+    #[test]
+    fn test_many0() {
+        let data = vec![0x01, 0x00, 0x02, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.many0(|c| c.parse_u16_le()).unwrap(), vec![1, 2]);
+        assert!(cursor.at_end());
+
+        // Nothing matches: no items and no movement
+        assert_eq!(cursor.many0(|c| c.parse_u8()).unwrap(), Vec::<u8>::new());
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_many0_partial_item_restored() {
+        // Two complete (u8, u16) records followed by one with a truncated u16
+        let data = vec![0x01, 0x10, 0x00, 0x02, 0x20, 0x00, 0x03, 0x30];
+        let mut cursor = BinaryCursor::new(data);
+
+        let records = cursor
+            .many0(|c| Ok((c.parse_u8()?, c.parse_u16_le()?)))
+            .unwrap();
+        assert_eq!(records, vec![(1, 0x10), (2, 0x20)]);
+        assert_eq!(cursor.position(), 6);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x03);
+    }
+
+    #[test]
+    fn test_many0_no_progress() {
+        let data = vec![0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.parse_u8().unwrap();
+
+        let error = cursor.many0(|c| c.parse_bytes(0)).unwrap_err();
+        assert_eq!(error.fields().operation, Some("many0"));
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_many_till() {
+        let data = vec![b'a', b'b', 0x00, 0x00, 0x05, b'c'];
+        let mut cursor = BinaryCursor::new(data);
+
+        let (items, terminator) = cursor
+            .many_till(
+                |c| c.parse_u8(),
+                |c| {
+                    c.expect_tag(&[0x00, 0x00])?;
+                    c.parse_u8()
+                },
+            )
+            .unwrap();
+        assert_eq!(items, b"ab");
+        assert_eq!(terminator, 0x05);
+        assert_eq!(cursor.position(), 5);
+
+        // The sentinel may match immediately
+        let (items, _) = cursor
+            .many_till(|c| c.parse_u16_le(), |c| c.expect_tag(b"c"))
+            .unwrap();
+        assert!(items.is_empty());
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_many_till_errors() {
+        // The item parser fails partway through the last record before a sentinel is found
+        let data = vec![0x01, 0x10, 0x00, 0x02, 0x20];
+        let mut cursor = BinaryCursor::new(data);
+        let result = cursor.many_till(
+            |c| Ok((c.parse_u8()?, c.parse_u16_le()?)),
+            |c| c.expect_tag(&[0xFF]),
+        );
+        assert!(result.is_err());
+        assert_eq!(cursor.position(), 0);
+
+        let error = cursor
+            .many_till(|c| c.parse_bytes(0), |c| c.expect_tag(&[0xFF]))
+            .unwrap_err();
+        assert_eq!(error.fields().operation, Some("many_till"));
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_iter_with_exact_multiple() {
+        let data = vec![0x01, 0x00, 0x02, 0x00, 0x03, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+
+        let values: Vec<u16> = cursor
+            .iter_with(|c| c.parse_u16_le())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(values, vec![1, 2, 3]);
+        assert!(cursor.at_end());
+
+        // Early exit leaves the position after the last record parsed
+        cursor.set_position(0);
+        let found = cursor
+            .iter_with(|c| c.parse_u16_le())
+            .find(|value| matches!(value, Ok(2)));
+        assert!(found.is_some());
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_iter_with_trailing_partial_record() {
+        let data = vec![0x01, 0x00, 0x02, 0x00, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+
+        let mut iter = cursor.iter_with(|c| c.parse_u16_le());
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+        assert_eq!(iter.next().unwrap().unwrap(), 2);
+        let error = iter.next().unwrap().unwrap_err();
+        assert_eq!(error.position(), Some(4));
+        assert!(iter.next().is_none());
+        assert_eq!(cursor.position(), 4);
+
+        // Records that consume nothing are an error rather than an endless iterator
+        let mut iter = cursor.iter_with(|c| c.parse_bytes(0));
+        let error = iter.next().unwrap().unwrap_err();
+        assert_eq!(error.fields().operation, Some("iter_with"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_with_empty() {
+        let mut cursor = BinaryCursor::new(vec![]);
+        assert!(cursor.iter_with(|c| c.parse_u32_le()).next().is_none());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_length_data() {
+        let data = vec![0x03, b'a', b'b', b'c', 0x01, 0x00, b'd', 0x00, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.length_data(|c| c.parse_u8()).unwrap(), b"abc");
+        assert_eq!(cursor.length_data(|c| c.parse_u16_le()).unwrap(), b"d");
+        assert_eq!(
+            cursor.length_data(|c| c.parse_u16_le()).unwrap(),
+            Vec::<u8>::new()
+        );
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_length_data_truncated() {
+        let data = vec![0x05, b'a', b'b'];
+        let mut cursor = BinaryCursor::new(data);
+        let error = cursor.length_data(|c| c.parse_u8()).unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.fields().requested, Some(5));
+        assert_eq!(cursor.position(), 0);
+
+        // A huge prefix fails before anything is allocated
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.length_data(|c| c.parse_uleb128()).is_err());
+        assert!(cursor.length_data(|c| c.parse_u64_le()).is_err());
+        assert_eq!(cursor.position(), 0);
+
+        let mut cursor = BinaryCursor::new(vec![0x01]);
+        assert!(cursor.length_data(|c| c.parse_u16_le()).is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_len_prefixed_bytes() {
+        let data = vec![
+            0x00, // empty u8-prefixed
+            0x00, 0x02, 0xAA, 0xBB, // u16 BE
+            0x01, 0x00, 0x00, 0x00, 0xCC, // u32 LE
+            0x00, 0x00, 0x00, 0x01, 0xDD, // u32 BE
+            0x02, 0x00, 0xEE, 0xFF, // u16 LE, exactly filling the data
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(
+            cursor.parse_len_prefixed_bytes_u8().unwrap(),
+            Vec::<u8>::new()
+        );
+        assert_eq!(
+            cursor.parse_len_prefixed_bytes_u16_be().unwrap(),
+            [0xAA, 0xBB]
+        );
+        assert_eq!(cursor.parse_len_prefixed_bytes_u32_le().unwrap(), [0xCC]);
+        assert_eq!(cursor.parse_len_prefixed_bytes_u32_be().unwrap(), [0xDD]);
+        assert_eq!(
+            cursor.parse_len_prefixed_bytes_u16_le().unwrap(),
+            [0xEE, 0xFF]
+        );
+        assert!(cursor.at_end());
+        assert!(cursor.parse_len_prefixed_bytes_u8().is_err());
+    }
+
+    #[test]
+    fn test_len_prefixed_bytes_hostile_length() {
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+
+        let error = cursor.parse_len_prefixed_bytes_u32_le().unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(
+            error.fields().operation,
+            Some("parse_len_prefixed_bytes_u32_le")
+        );
+        assert_eq!(error.fields().requested, Some(u32::MAX as usize));
+        assert_eq!(error.fields().available, Some(2));
+        assert_eq!(error.position(), Some(4));
+        assert_eq!(cursor.position(), 0);
+        assert!(cursor.parse_len_prefixed_str_u32_be().is_err());
+        assert_eq!(cursor.position(), 0);
+
+        // The allocation limit applies even when the data is long enough
+        let data = vec![0x04, 0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursorBuilder::new()
+            .max_alloc(3)
+            .build(&data)
+            .unwrap();
+        let error = cursor.parse_len_prefixed_bytes_u8().unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_len_prefixed_str() {
+        let data = vec![
+            0x05, b'h', b'e', b'l', b'l', b'o', // u8
+            0x00, 0x00, // empty u16 LE
+            0x00, 0x02, 0xC3, 0xA9, // u16 BE "é"
+            0x02, 0x00, 0x00, 0x00, 0xC3, 0x28, // u32 LE, invalid UTF-8
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.parse_len_prefixed_str_u8().unwrap(), "hello");
+        assert_eq!(cursor.parse_len_prefixed_str_u16_le().unwrap(), "");
+        assert_eq!(cursor.parse_len_prefixed_str_u16_be().unwrap(), "é");
+
+        let error = cursor.parse_len_prefixed_str_u32_le().unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.position(), Some(16));
+        assert_eq!(cursor.position(), 12);
+        assert_eq!(
+            cursor.parse_len_prefixed_bytes_u32_le().unwrap(),
+            [0xC3, 0x28]
+        );
+    }
+
+    #[test]
+    fn test_parse_pstr() {
+        let mut data = vec![0x00, 0x03, b'a', 0xFF, b'c', 0x03, b'a', 0xFF, b'c', 0xFF];
+        data.extend(std::iter::repeat_n(b'x', 255));
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.parse_pstr().unwrap(), "");
+        assert!(cursor.parse_pstr().is_err());
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.parse_pstr_lossy().unwrap(), "a\u{FFFD}c");
+        assert_eq!(cursor.parse_pstr_raw().unwrap(), [b'a', 0xFF, b'c']);
+
+        // The longest possible Pascal string
+        let long = cursor.parse_pstr().unwrap();
+        assert_eq!(long.len(), 255);
+        assert!(long.bytes().all(|b| b == b'x'));
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_parse_pstr_even_padded() {
+        // Odd total length with a pad byte, then even total length without one
+        let data = vec![0x02, b'h', b'i', 0x00, 0x03, b'a', b'b', b'c', 0x00, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.parse_pstr_even_padded().unwrap(), "hi");
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.parse_pstr_even_padded().unwrap(), "abc");
+        assert_eq!(cursor.position(), 8);
+        // An empty string still takes two bytes
+        assert_eq!(cursor.parse_pstr_even_padded().unwrap(), "");
+        assert!(cursor.at_end());
+
+        // A missing pad byte is an error
+        let data = vec![0x02, b'h', b'i'];
+        let mut cursor = BinaryCursor::new(data);
+        let error = cursor.parse_pstr_even_padded().unwrap_err();
+        assert_eq!(error.position(), Some(3));
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_pstr_truncated() {
+        let data = vec![0x0A, b'a', b'b', b'c'];
+        let mut cursor = BinaryCursor::new(data);
+
+        let error = cursor.parse_pstr().unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.fields().operation, Some("parse_pstr"));
+        assert_eq!(error.fields().requested, Some(10));
+        assert_eq!(error.fields().available, Some(3));
+        assert!(cursor.parse_pstr_raw().is_err());
+        assert!(cursor.parse_pstr_lossy().is_err());
+        assert!(cursor.parse_pstr_even_padded().is_err());
+        assert_eq!(cursor.position(), 0);
+
+        let mut cursor = BinaryCursor::new(vec![]);
+        assert!(cursor.parse_pstr().is_err());
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        const EFI_SYSTEM_PARTITION: &str = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b";
+        let mut data = vec![
+            0x28, 0x73, 0x2A, 0xC1, 0x1F, 0xF8, 0xD2, 0x11, 0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E,
+            0xC9, 0x3B,
+        ];
+        data.extend_from_slice(&[
+            0xC1, 0x2A, 0x73, 0x28, 0xF8, 0x1F, 0x11, 0xD2, 0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E,
+            0xC9, 0x3B,
+        ]);
+        let mut cursor = BinaryCursor::new(data);
+
+        let mixed = cursor.parse_uuid_le_mixed().unwrap();
+        assert_eq!(mixed.to_string(), EFI_SYSTEM_PARTITION);
+        let big = cursor.parse_uuid_be().unwrap();
+        assert_eq!(big.to_string(), EFI_SYSTEM_PARTITION);
+        assert_eq!(mixed, big);
+        assert_eq!(big.as_bytes()[..4], [0xC1, 0x2A, 0x73, 0x28]);
+        assert!(cursor.at_end());
+
+        // Reading the mixed layout as big-endian gives a different UUID
+        cursor.set_position(0);
+        assert_eq!(
+            cursor.parse_uuid_be().unwrap().to_string(),
+            "28732ac1-1ff8-d211-ba4b-00a0c93ec93b"
+        );
+
+        let mut cursor = BinaryCursor::new(vec![0u8; 15]);
+        let error = cursor.parse_uuid_le_mixed().unwrap_err();
+        assert_eq!(error.fields().requested, Some(16));
+        assert!(cursor.parse_uuid_be().is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_ip_addresses() {
+        let mut data = vec![10, 0, 0, 1];
+        // 2001:db8::8a2e:370:7334
+        data.extend_from_slice(&[
+            0x20, 0x01, 0x0D, 0xB8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8A, 0x2E, 0x03, 0x70,
+            0x73, 0x34,
+        ]);
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.parse_ipv4().unwrap(), Ipv4Addr::new(10, 0, 0, 1));
+        let v6 = cursor.parse_ipv6().unwrap();
+        assert_eq!(v6, "2001:db8::8a2e:370:7334".parse::<Ipv6Addr>().unwrap());
+        assert_eq!(v6.to_string(), "2001:db8::8a2e:370:7334");
+        assert!(cursor.at_end());
+
+        let mut cursor = BinaryCursor::new(vec![127, 0, 0]);
+        let error = cursor.parse_ipv4().unwrap_err();
+        assert_eq!(error.fields().operation, Some("parse_ipv4"));
+        assert!(cursor.parse_ipv6().is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_socket_addresses() {
+        let mut data = vec![127, 0, 0, 1, 0x00, 0x50, 127, 0, 0, 1, 0x00, 0x50];
+        data.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+        data.extend_from_slice(&[0x01, 0xBB]);
+        data.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+        data.extend_from_slice(&[0xBB, 0x01]);
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(
+            cursor.parse_socketaddr_v4_be().unwrap(),
+            "127.0.0.1:80".parse().unwrap()
+        );
+        assert_eq!(
+            cursor.parse_socketaddr_v4_le().unwrap(),
+            "127.0.0.1:20480".parse().unwrap()
+        );
+        assert_eq!(
+            cursor.parse_socketaddr_v6_be().unwrap(),
+            "[::1]:443".parse().unwrap()
+        );
+        assert_eq!(
+            cursor.parse_socketaddr_v6_le().unwrap(),
+            "[::1]:443".parse().unwrap()
+        );
+        assert!(cursor.at_end());
+
+        // A missing port leaves the address unread too
+        let mut cursor = BinaryCursor::new(vec![127, 0, 0, 1, 0x00]);
+        let error = cursor.parse_socketaddr_v4_be().unwrap_err();
+        assert_eq!(error.fields().requested, Some(6));
+        assert_eq!(error.fields().available, Some(5));
+        assert_eq!(cursor.position(), 0);
+        assert!(cursor.parse_socketaddr_v6_le().is_err());
+    }
+
+    #[test]
+    fn test_parse_unix_timestamps() {
+        let mut data = 1_700_000_000u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&4_102_444_800u64.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(
+            cursor.parse_unix_timestamp_u32_le().unwrap(),
+            UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        );
+        assert_eq!(
+            cursor.parse_unix_timestamp_u64_le().unwrap(),
+            UNIX_EPOCH + Duration::from_secs(4_102_444_800)
+        );
+
+        let error = cursor.parse_unix_timestamp_u64_le().unwrap_err();
+        assert_eq!(error.position(), Some(12));
+        assert!(error.to_string().contains(&u64::MAX.to_string()));
+        assert_eq!(cursor.position(), 12);
+    }
+
+    #[test]
+    fn test_parse_filetime() {
+        let mut data = 125_911_584_000_000_000u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&116_444_736_000_000_001u64.to_le_bytes());
+        data.extend_from_slice(&116_444_735_999_999_999u64.to_le_bytes());
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(
+            cursor.parse_filetime_le().unwrap(),
+            UNIX_EPOCH + Duration::from_secs(946_684_800)
+        );
+        assert_eq!(
+            cursor.parse_filetime_le().unwrap(),
+            UNIX_EPOCH + Duration::from_nanos(100)
+        );
+
+        let error = cursor.parse_filetime_le().unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert!(error.to_string().contains("116444735999999999"));
+        assert_eq!(cursor.position(), 16);
+    }
+
+    #[test]
+    fn test_parse_dos_datetime() {
+        // From a ZIP local file header, followed by 2000-02-29 23:59:58 and 1980-01-01
+        let data = vec![
+            0x09, 0x74, 0x97, 0x4F, 0x7D, 0xBF, 0x5D, 0x28, 0x00, 0x00, 0x21, 0x00,
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(
+            cursor.parse_dos_datetime_le().unwrap(),
+            DosDateTime {
+                year: 2019,
+                month: 12,
+                day: 23,
+                hour: 14,
+                minute: 32,
+                second: 18,
+            }
+        );
+        let leap_day = cursor.parse_dos_datetime_le().unwrap();
+        assert_eq!((leap_day.year, leap_day.month, leap_day.day), (2000, 2, 29));
+        assert_eq!(
+            (leap_day.hour, leap_day.minute, leap_day.second),
+            (23, 59, 58)
+        );
+        let earliest = cursor.parse_dos_datetime_le().unwrap();
+        assert_eq!((earliest.year, earliest.month, earliest.day), (1980, 1, 1));
+    }
+
+    #[test]
+    fn test_parse_dos_datetime_invalid() {
+        let cases: [([u8; 4], &str); 4] = [
+            // Month 13
+            (
+                [0x00, 0x00, 0xA1, 0x01],
+                "DOS date 0x01A1 has invalid month 13",
+            ),
+            // 2001-02-29
+            (
+                [0x00, 0x00, 0x5D, 0x2A],
+                "DOS date 0x2A5D has invalid day 29",
+            ),
+            // Day 0
+            (
+                [0x00, 0x00, 0x20, 0x00],
+                "DOS date 0x0020 has invalid day 0",
+            ),
+            // 24:00:00
+            (
+                [0x00, 0xC0, 0x21, 0x00],
+                "DOS time 0xC000 is not a valid time of day",
+            ),
+        ];
+        for (bytes, message) in cases {
+            let mut cursor = BinaryCursor::new(bytes);
+            let error = cursor.parse_dos_datetime_le().unwrap_err();
+            assert!(error.to_string().contains(message), "{}", error);
+            assert_eq!(error.position(), Some(0));
+            assert_eq!(cursor.position(), 0);
+        }
+    }
+
+    #[test]
+    fn test_io_read_mid_parse() {
+        let data = vec![
+            0x05, 0x00, b'h', b'e', b'l', b'l', b'o', 0x2A, 0x00, b'x', b'y', b'\n', 0xFF,
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        let len = cursor.parse_u16_le().unwrap();
+        let mut payload = vec![];
+        (&mut cursor)
+            .take(len.into())
+            .read_to_end(&mut payload)
+            .unwrap();
+        assert_eq!(payload, b"hello");
+        assert_eq!(cursor.position(), 7);
+        assert_eq!(cursor.parse_u16_le().unwrap(), 42);
+
+        let mut line = vec![];
+        cursor.read_until(b'\n', &mut line).unwrap();
+        assert_eq!(line, b"xy\n");
+        assert_eq!(cursor.fill_buf().unwrap(), [0xFF]);
+        cursor.consume(1);
+        assert!(cursor.at_end());
+
+        let mut buf = [0u8; 1];
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        assert!(cursor.read_exact(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_io_seek_matches_set_position() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.push_location();
+
+        assert_eq!(cursor.seek(SeekFrom::End(-1)).unwrap(), 3);
+        assert_eq!(cursor.parse_u8().unwrap(), 4);
+        assert_eq!(cursor.seek(SeekFrom::Current(-3)).unwrap(), 1);
+        assert_eq!(cursor.stream_position().unwrap(), 1);
+        assert_eq!(cursor.seek(SeekFrom::Start(10)).unwrap(), 10);
+        assert!(cursor.parse_u8().is_err());
+        assert!(cursor.seek(SeekFrom::Current(-11)).is_err());
+        assert_eq!(cursor.position(), 10);
+
+        // The location stack is left alone
+        assert_eq!(cursor.location_depth(), 1);
+        cursor.restore_location();
+        assert_eq!(cursor.position(), 0);
+    }
+
+    /// A header whose version decides whether the length field is one or two bytes wide
+    fn parse_versioned_len(cursor: &mut BinaryCursor<&[u8]>, wide: bool) -> Option<Vec<u8>> {
+        let len = if wide {
+            cursor.parse_u16_le().ok()? as usize
+        } else {
+            cursor.parse_u8().ok()? as usize
+        };
+        cursor.parse_bytes(len).ok()
+    }
+
+    #[test]
+    fn test_fork_speculative_parse() {
+        let data = vec![0xFF, 0x03, b'a', b'b', b'c', 0x01];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.parse_u8().unwrap();
+        cursor.push_location();
+        cursor.set_bookmark("body");
+
+        // The wide interpretation runs off the end, so its fork is dropped
+        let mut wide = cursor.fork();
+        assert_eq!(wide.location_depth(), 0);
+        assert_eq!(wide.position(), 1);
+        assert!(parse_versioned_len(&mut wide, true).is_none());
+        wide.push_location();
+        drop(wide);
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.location_depth(), 1);
+
+        // The narrow interpretation succeeds and its progress is adopted
+        let end = {
+            let mut narrow = cursor.fork();
+            assert_eq!(parse_versioned_len(&mut narrow, false).unwrap(), b"abc");
+            assert_eq!(narrow.bookmark("body"), Some(1));
+            assert_eq!(cursor.position(), 1);
+            narrow.position()
+        };
+        cursor.set_position(end);
+        assert_eq!(cursor.parse_u8().unwrap(), 1);
+        assert_eq!(cursor.location_depth(), 1);
+    }
+
+    #[test]
+    fn test_clone_owned_cursor() {
+        let mut cursor = BinaryCursor::new(vec![0x01, 0x02, 0x03]);
+        cursor.parse_u8().unwrap();
+        cursor.push_location();
+
+        let mut copy = cursor.clone();
+        assert_eq!(copy.parse_u16_le().unwrap(), 0x0302);
+        assert!(copy.restore_location());
+        assert_eq!(copy.position(), 1);
+
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.location_depth(), 1);
+    }
+
+    #[test]
+    fn test_tracing_records_fields() {
+        let data = vec![0x01, 0x34, 0x12, b'h', b'i', 0x00, 0x02, 0xAA];
+        let mut cursor = BinaryCursor::with_tracing(data);
+
+        cursor.parse_u8().unwrap();
+        cursor.parse_u16_le().unwrap();
+        cursor.parse_cstr().unwrap();
+        assert!(cursor.parse_len_prefixed_bytes_u8().is_err());
+        assert!(!cursor.matches_tag(&[0x03]).unwrap());
+        assert!(cursor.matches_tag(&[0x02]).unwrap());
+
+        let summary: Vec<_> = cursor
+            .trace_events()
+            .iter()
+            .map(|e| (e.operation, e.offset, e.len, e.depth, e.success))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("parse_u8", 0, 1, 0, true),
+                ("parse_u16_le", 1, 2, 0, true),
+                ("parse_cstr", 3, 3, 0, true),
+                ("parse_len_prefixed_bytes_u8", 6, 0, 0, false),
+                ("matches_tag", 6, 0, 0, true),
+                ("matches_tag", 6, 1, 0, true),
+            ]
+        );
+
+        assert_eq!(cursor.take_trace_events().len(), 6);
+        assert!(cursor.trace_events().is_empty());
+        cursor.set_position(7);
+        cursor.parse_u8().unwrap();
+        assert_eq!(cursor.trace_events().len(), 1);
+
+        cursor.clear_tracing();
+        cursor.set_position(0);
+        cursor.parse_u8().unwrap();
+        assert!(cursor.trace_events().is_empty());
+    }
+
+    #[test]
+    fn test_trace_hook() {
+        use std::cell::Cell;
+        use std::sync::mpsc;
+
+        let (sender, receiver) = mpsc::channel();
+        let data = vec![0x02, 0x00, 0xAA, 0xBB, 0xCC];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.trace_events().is_empty());
+        // The hook only needs to be `Send`, so it may own state that is not `Sync`
+        let calls = Cell::new(0);
+        cursor.set_trace_hook(move |event| {
+            calls.set(calls.get() + 1);
+            sender.send((calls.get(), event)).unwrap();
+        });
+
+        let items = cursor
+            .length_count(|c| c.parse_u16_le(), |c| c.parse_u8())
+            .unwrap();
+        assert_eq!(items, vec![0xAA, 0xBB]);
+        // Plain cursor moves and combinators are not traced
+        cursor.skip(1).unwrap();
+        assert!(cursor.parse_u8().is_err());
+
+        let (calls, seen): (Vec<_>, Vec<_>) = receiver.try_iter().unzip();
+        assert_eq!(calls, vec![1, 2, 3, 4, 5]);
+        let summary: Vec<_> = seen
+            .iter()
+            .map(|e| (e.operation, e.offset, e.len, e.depth))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("parse_u16_le", 0, 2, 1),
+                ("parse_u8", 2, 1, 1),
+                ("parse_u8", 3, 1, 1),
+                ("length_count", 0, 4, 0),
+                ("parse_u8", 5, 0, 0),
+            ]
+        );
+        assert!(!seen[4].success);
+        // Hooks are not carried over to clones or collected by trace_events
+        assert!(cursor.trace_events().is_empty());
+        let mut copy = cursor.clone();
+        copy.set_position(0);
+        copy.parse_u8().unwrap();
+        assert_eq!(receiver.try_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_context_hexdump() {
+        let data: Vec<u8> = (0..40).map(|i| b'a' + i % 26).collect();
+        let mut cursor = BinaryCursor::new(data);
+
+        // A window within one line
+        cursor.set_position(20);
+        assert_eq!(
+            cursor.context(4),
+            [
+                "00000010  71 72 73 74 75 76 77 78                           |qrstuvwx        |",
+                "                      ^^",
+            ]
+            .join("\n")
+        );
+
+        // A window spanning two lines, with the marker past the gap in the middle
+        cursor.set_position(10);
+        assert_eq!(
+            cursor.context(8),
+            [
+                "00000000        63 64 65 66 67 68  69 6A 6B 6C 6D 6E 6F 70  |  cdefghijklmnop|",
+                "                                         ^^",
+                "00000010  71 72                                             |qr              |",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_context_hexdump_clamped() {
+        let data: Vec<u8> = (0..40).map(|i| b'a' + i % 26).collect();
+        let mut cursor = BinaryCursor::new(data);
+
+        // Clamped at the start
+        cursor.set_position(1);
+        assert_eq!(
+            cursor.context(3),
+            [
+                "00000000  61 62 63 64                                       |abcd            |",
+                "             ^^",
+            ]
+            .join("\n")
+        );
+
+        // At the end, the marker points just past the last byte
+        cursor.set_position(40);
+        assert_eq!(
+            cursor.context(2),
+            [
+                "00000020                    6D 6E                           |      mn        |",
+                "                                   ^^",
+            ]
+            .join("\n")
+        );
+
+        // Positions past the end are clamped to it, and unprintable bytes are shown as dots
+        let data = vec![
+            0x00, b'A', 0xFF, 0x0A, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+            0x1A, 0x1B,
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(100);
+        assert_eq!(
+            cursor.context(16),
+            [
+                "00000000  00 41 FF 0A 10 11 12 13  14 15 16 17 18 19 1A 1B  |.A..............|",
+                "00000010                                                    |                |",
+                "          ^^",
+            ]
+            .join("\n")
+        );
+
+        let cursor = BinaryCursor::new(vec![]);
+        assert_eq!(
+            cursor.context(8),
+            [
+                "00000000                                                    |                |",
+                "          ^^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_error_with_context() {
+        let data = b"HDR\x00\x10\x00\x00\x00".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        cursor.skip(4).unwrap();
+
+        let error = cursor
+            .parse_u64_le()
+            .map_err(|e| e.with_context(&cursor).context("header"))
+            .unwrap_err();
+        assert_eq!(error.position(), Some(4));
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.fields().context, vec!["header"]);
+        assert_eq!(
+            error.to_string(),
+            [
+                "header: Unexpected EOF at offset 4 in parse_u64_le: requested 8 bytes, 4 available",
+                "00000000  48 44 52 00 10 00 00 00                           |HDR.....        |",
+                "                      ^^",
+            ]
+            .join("\n")
+        );
+
+        // Errors without an offset use the cursor's position
+        let error =
+            BinaryCursorError::ParseError(std::io::Error::other("bad")).with_context(&cursor);
+        assert_eq!(error.position(), None);
+        assert!(error.to_string().ends_with("\n                      ^^"));
+    }
+
+    #[test]
+    fn test_ensure_remaining() {
+        let data = vec![0u8; 10];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(2);
+
+        assert!(cursor.ensure_remaining(0).is_ok());
+        assert!(cursor.ensure_remaining(8).is_ok());
+        assert!(cursor.has_remaining(8));
+        assert!(!cursor.has_remaining(9));
+
+        let error = cursor.ensure_remaining(9).unwrap_err();
+        assert_eq!(error.fields().operation, Some("ensure_remaining"));
+        assert_eq!(error.fields().requested, Some(9));
+        assert_eq!(error.fields().available, Some(8));
+        assert_eq!(error.position(), Some(2));
+        assert_eq!(cursor.position(), 2);
+
+        let error = cursor.ensure_remaining(u64::MAX).unwrap_err();
+        assert_eq!(error.fields().requested, Some(usize::MAX));
+    }
+
+    #[test]
+    fn test_ensure_remaining_past_end() {
+        let data = vec![0u8; 4];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(4);
+        assert!(cursor.ensure_remaining(0).is_ok());
+        assert!(cursor.ensure_remaining(1).is_err());
+
+        cursor.set_position(0x1F0);
+        assert!(cursor.ensure_remaining(0).is_ok());
+        assert!(!cursor.has_remaining(1));
+        let error = cursor.ensure_remaining(24).unwrap_err();
+        assert_eq!(error.position(), Some(0x1F0));
+        assert_eq!(error.fields().available, Some(0));
+        assert_eq!(cursor.position(), 0x1F0);
+    }
+
+    #[test]
+    fn test_separated_list() {
+        let comma = |c: &mut BinaryCursor<&[u8]>| c.expect_tag(b",");
+        let digit = |c: &mut BinaryCursor<&[u8]>| {
+            let byte = c.parse_u8()?;
+            if byte.is_ascii_digit() {
+                Ok(byte - b'0')
+            } else {
+                Err(BinaryCursorError::InvalidData {
+                    operation: "digit",
+                    position: c.position() - 1,
+                    message: "not a digit".to_string(),
+                })
+            }
+        };
+
+        // Empty list: the failed first item is rolled back
+        let mut cursor = BinaryCursor::new(&b"x,1"[..]);
+        assert!(cursor.separated_list(digit, comma).unwrap().is_empty());
+        assert_eq!(cursor.position(), 0);
+        let mut cursor = BinaryCursor::new(&b""[..]);
+        assert!(cursor.separated_list(digit, comma).unwrap().is_empty());
+
+        let mut cursor = BinaryCursor::new(&b"7;"[..]);
+        assert_eq!(cursor.separated_list(digit, comma).unwrap(), vec![7]);
+        assert_eq!(cursor.position(), 1);
+
+        let mut cursor = BinaryCursor::new(&b"1,2,3"[..]);
+        assert_eq!(cursor.separated_list(digit, comma).unwrap(), vec![1, 2, 3]);
+        assert!(cursor.at_end());
+
+        // A trailing separator is left unconsumed
+        let mut cursor = BinaryCursor::new(&b"1,2,"[..]);
+        assert_eq!(cursor.separated_list(digit, comma).unwrap(), vec![1, 2]);
+        assert_eq!(cursor.position(), 3);
+        let mut cursor = BinaryCursor::new(&b"1,2,x"[..]);
+        assert_eq!(cursor.separated_list(digit, comma).unwrap(), vec![1, 2]);
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_separated_list_no_progress() {
+        let mut cursor = BinaryCursor::new(vec![0x01, 0x02]);
+        let error = cursor
+            .separated_list(|c| c.parse_bytes(0), |_| Ok(()))
+            .unwrap_err();
+        assert_eq!(error.fields().operation, Some("separated_list"));
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_delimited() {
+        let open = |c: &mut BinaryCursor<&[u8]>| c.expect_tag(b"<b>");
+        let close = |c: &mut BinaryCursor<&[u8]>| c.expect_tag(b"</b>");
+        let text = |c: &mut BinaryCursor<&[u8]>| c.take_until(b"<");
+
+        let mut cursor = BinaryCursor::new(&b"<b>bold</b>!"[..]);
+        assert_eq!(cursor.delimited(open, text, close).unwrap(), b"bold");
+        assert_eq!(cursor.position(), 11);
+
+        // Each failing stage is named, and nothing is consumed
+        let mut cursor = BinaryCursor::new(&b"<i>bold</b>"[..]);
+        let error = cursor.delimited(open, text, close).unwrap_err();
+        assert_eq!(error.fields().context, vec!["open delimiter"]);
+        assert_eq!(cursor.position(), 0);
+
+        let mut cursor = BinaryCursor::new(&b"<b>bold"[..]);
+        let error = cursor.delimited(open, text, close).unwrap_err();
+        assert_eq!(error.fields().context, vec!["delimited value"]);
+        assert_eq!(cursor.position(), 0);
+
+        // The close tag never appears
+        let mut cursor = BinaryCursor::new(&b"<b>bold<i>"[..]);
+        let error = cursor.delimited(open, text, close).unwrap_err();
+        assert_eq!(error.fields().context, vec!["close delimiter"]);
+        assert_eq!(error.position(), Some(7));
+        assert!(error.to_string().starts_with("close delimiter: "));
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_verify_mid_record() {
+        // A record of a u8 kind, a u16 length capped at 1024, and a payload
+        let data = vec![0x01, 0x00, 0x10, 0xAA];
+        let mut cursor = BinaryCursor::new(data);
+
+        let kind = cursor.verify(|c| c.parse_u8(), |kind| *kind == 1).unwrap();
+        assert_eq!(kind, 1);
+        let error = cursor
+            .verify(|c| c.parse_u16_le(), |len| *len <= 1024)
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.fields().operation, Some("verify"));
+        assert_eq!(error.position(), Some(1));
+        assert!(error.to_string().contains("value 4096 was rejected"));
+        assert_eq!(cursor.position(), 1);
+
+        // Parser errors pass through unchanged, and compose with opt and alt
+        assert_eq!(
+            cursor
+                .verify(|c| c.parse_u32_le(), |_| true)
+                .unwrap_err()
+                .fields()
+                .operation,
+            Some("parse_u32_le")
+        );
+        assert_eq!(
+            cursor
+                .opt(|c| c.verify(|c| c.parse_u16_le(), |len| *len <= 1024))
+                .unwrap(),
+            None
+        );
+        // An older record layout with a one-byte length
+        let len = cursor
+            .alt(&mut [
+                &mut |c| c.verify(|c| c.parse_u16_le(), |len| *len <= 1024),
+                &mut |c| c.verify(|c| c.parse_u8().map(u16::from), |len| *len <= 1024),
+            ])
+            .unwrap();
+        assert_eq!(len, 0);
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_map_res() {
+        let data = vec![b'7', b'x', 0xFF];
+        let mut cursor = BinaryCursor::new(data);
+
+        let digit = |c: &mut BinaryCursor<Vec<u8>>| {
+            c.map_res(
+                |c| c.parse_u8(),
+                |byte| {
+                    char::from(byte)
+                        .to_digit(10)
+                        .ok_or(format!("{:?} is not a digit", char::from(byte)))
+                },
+            )
+        };
+        assert_eq!(digit(&mut cursor).unwrap(), 7);
+
+        let error = digit(&mut cursor).unwrap_err();
+        assert_eq!(error.fields().operation, Some("map_res"));
+        assert_eq!(error.position(), Some(1));
+        assert_eq!(error.fields().message, "'x' is not a digit");
+        assert_eq!(cursor.position(), 1);
+
+        cursor.set_position(2);
+        let error = cursor.map_res(|c| c.parse_u8(), i8::try_from).unwrap_err();
+        assert_eq!(error.position(), Some(2));
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_try_seek() {
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.try_seek(SeekFrom::End(-3)).unwrap(), 7);
+        assert_eq!(cursor.parse_u8().unwrap(), 7);
+        assert_eq!(cursor.try_seek(SeekFrom::Current(-6)).unwrap(), 2);
+        assert_eq!(cursor.try_seek(SeekFrom::Current(3)).unwrap(), 5);
+        assert_eq!(cursor.try_seek(SeekFrom::End(0)).unwrap(), 10);
+        assert_eq!(cursor.try_seek(SeekFrom::Start(4)).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_try_seek_errors() {
+        let data = vec![0u8; 10];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(4);
+
+        let error = cursor.try_seek(SeekFrom::Current(-5)).unwrap_err();
+        assert_eq!(error.fields().operation, Some("try_seek"));
+        assert_eq!(error.position(), Some(4));
+        assert!(
+            error
+                .to_string()
+                .contains("cannot seek 5 bytes back from position 4")
+        );
+        assert!(cursor.try_seek(SeekFrom::End(-11)).is_err());
+        assert!(cursor.try_seek(SeekFrom::Current(i64::MIN)).is_err());
+
+        let error = cursor.try_seek(SeekFrom::End(1)).unwrap_err();
+        assert!(error.to_string().contains("position 11 is past the end"));
+        assert!(cursor.try_seek(SeekFrom::Start(11)).is_err());
+        assert!(cursor.try_seek(SeekFrom::Current(i64::MAX)).is_err());
+        cursor.set_position(u64::MAX);
+        let error = cursor.try_seek(SeekFrom::Current(1)).unwrap_err();
+        assert!(error.to_string().contains("overflows"));
+        cursor.set_position(4);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_jump_seek() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump_seek(SeekFrom::End(-1)).unwrap();
+            assert_eq!(jump.cursor.parse_u8().unwrap(), 0x05);
+            assert!(jump.jump_seek(SeekFrom::Current(-6)).is_err());
+            assert_eq!(jump.cursor.position(), 5);
+        }
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.location_depth(), 0);
+
+        // A guard whose only jump failed restores nothing
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            assert!(jump.jump_seek(SeekFrom::End(1)).is_err());
+            assert_eq!(jump.cursor.location_depth(), 0);
+            jump.cursor.set_position(3);
+        }
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_consumed_and_remaining_slices() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        // Fresh
+        assert!(cursor.consumed().is_empty());
+        assert_eq!(cursor.remaining_slice(), &[0x01, 0x02, 0x03, 0x04]);
+
+        // Mid-stream
+        cursor.parse_u8().unwrap();
+        assert_eq!(cursor.consumed(), &[0x01]);
+        assert_eq!(cursor.remaining_slice(), &[0x02, 0x03, 0x04]);
+        assert_eq!(cursor.position(), 1);
+
+        // At the end
+        cursor.set_position(4);
+        assert_eq!(cursor.consumed(), &[0x01, 0x02, 0x03, 0x04]);
+        assert!(cursor.remaining_slice().is_empty());
+
+        // Past the end
+        cursor.set_position(10);
+        assert_eq!(cursor.consumed().len(), 4);
+        assert!(cursor.remaining_slice().is_empty());
+        cursor.set_position(u64::MAX);
+        assert_eq!(cursor.consumed().len(), 4);
+        assert!(cursor.remaining_slice().is_empty());
+    }
+
+    #[test]
+    fn test_take_remaining() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.take_remaining(), &[0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(cursor.position(), 4);
+
+        cursor.set_position(2);
+        assert_eq!(cursor.take_remaining(), &[0x03, 0x04]);
+        assert!(cursor.at_end());
+        assert_eq!(cursor.consumed().len(), 4);
+
+        // Already at the end
+        assert!(cursor.take_remaining().is_empty());
+        assert_eq!(cursor.position(), 4);
+
+        // Past the end, the position is not moved back
+        cursor.set_position(9);
+        assert!(cursor.take_remaining().is_empty());
+        assert_eq!(cursor.position(), 9);
+    }
+
+    #[test]
+    fn test_multi_field_helpers_are_atomic() {
+        use crate::from_cursor::FromCursor;
+
+        /// Runs `parse` from position 1 of `data` and checks that it fails without moving
+        fn check<U: std::fmt::Debug>(
+            name: &str,
+            data: &[u8],
+            parse: impl FnOnce(&mut BinaryCursor<Vec<u8>>) -> Result<U, BinaryCursorError>,
+        ) {
+            let mut bytes = vec![0xEE];
+            bytes.extend_from_slice(data);
+            let mut cursor = BinaryCursor::new(bytes);
+            cursor.set_position(1);
+            let result = parse(&mut cursor);
+            assert!(result.is_err(), "{} succeeded: {:?}", name, result);
+            assert_eq!(cursor.position(), 1, "{} moved the cursor", name);
+            assert_eq!(cursor.location_depth(), 0, "{} left a location", name);
+        }
+
+        check("count", &[1, 0, 2], |c| c.count(|c| c.parse_u16_le(), 2));
+        check("count_with_limit", &[1, 0, 2], |c| {
+            c.count_with_limit(|c| c.parse_u16_le(), 2, 8)
+        });
+        check("count_array", &[1, 0, 2], |c| {
+            c.count_array::<_, _, 2>(|c| c.parse_u16_le())
+        });
+        check("many_till", &[1, 2], |c| {
+            c.many_till(|c| c.parse_u8(), |c| c.expect_tag(b"\xFF"))
+        });
+        check("delimited", b"<a", |c| {
+            c.delimited(
+                |c| c.expect_tag(b"<"),
+                |c| c.parse_u8(),
+                |c| c.expect_tag(b">"),
+            )
+        });
+        check("verify", &[1, 0], |c| {
+            c.verify(|c| c.parse_u16_le(), |v| *v > 1)
+        });
+        check("map_res", &[1, 0], |c| {
+            c.map_res(|c| c.parse_u16_le(), |v| u8::try_from(v + 255))
+        });
+        check("length_data", &[3, 1, 2], |c| {
+            c.length_data(|c| c.parse_u8())
+        });
+        check("length_count", &[2, 1, 0, 2], |c| {
+            c.length_count(|c| c.parse_u8(), |c| c.parse_u16_le())
+        });
+        check("parse_len_prefixed_bytes_u8", &[3, 1, 2], |c| {
+            c.parse_len_prefixed_bytes_u8()
+        });
+        check("parse_len_prefixed_str_u16_le", &[3, 0, b'a'], |c| {
+            c.parse_len_prefixed_str_u16_le()
+        });
+        check("parse_pstr", &[3, b'a'], |c| c.parse_pstr());
+        check("parse_pstr_even_padded", &[2, b'a', b'b'], |c| {
+            c.parse_pstr_even_padded()
+        });
+        check("parse_cstr", b"ab", |c| c.parse_cstr());
+        check("parse_utf16_cstr_le", &[b'a', 0], |c| {
+            c.parse_utf16_cstr_le()
+        });
+        check("take_until_and_consume", b"ab\0", |c| {
+            c.take_until_and_consume(b"\0\0")
+        });
+        check("parse_uleb128", &[0x80, 0x80], |c| c.parse_uleb128());
+        check("parse_socketaddr_v4_be", &[1, 2, 3, 4, 0], |c| {
+            c.parse_socketaddr_v4_be()
+        });
+        check("parse_socketaddr_v6_le", &[0; 17], |c| {
+            c.parse_socketaddr_v6_le()
+        });
+        check("parse_dos_datetime_le", &[0, 0, 0], |c| {
+            c.parse_dos_datetime_le()
+        });
+        check("deref_offset_u32_le", &[5, 0, 0, 0], |c| {
+            c.deref_offset_u32_le(OffsetBase::Start, |c| c.parse_u32_le())
+        });
+        check("parse", &[1, 0, 2], |c| c.parse::<(u16, u16)>());
+        check("tuple from_cursor", &[1, 0, 2], |c| {
+            <(u16, u16)>::from_cursor(c)
+        });
+        check("array from_cursor", &[1, 0, 2], |c| {
+            <[u16; 2]>::from_cursor(c)
+        });
+    }
+
+    #[test]
+    fn test_short_reads_leave_position() {
+        let data = vec![0x01, 0x02, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        assert!(cursor.parse_u32_le().is_err());
+        assert!(cursor.parse_u64_le().is_err());
+        assert!(cursor.parse_f32_le().is_err());
+        assert!(cursor.parse_f64_le().is_err());
+        assert!(cursor.parse_i32_le().is_err());
+        assert!(cursor.parse_bytes(3).is_err());
+        let error = cursor.parse_array::<4>().unwrap_err();
+        assert_eq!(error.fields().requested, Some(4));
+        assert_eq!(error.fields().available, Some(2));
+        assert_eq!(cursor.position(), 1);
+
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0302);
+        assert_eq!(cursor.position(), 3);
+        assert!(cursor.parse_u8().is_err());
+
+        // Past the end, nothing is available
+        cursor.set_position(10);
+        let error = cursor.parse_u8().unwrap_err();
+        assert_eq!(error.position(), Some(10));
+        assert_eq!(error.fields().available, Some(0));
+        assert_eq!(cursor.position(), 10);
+    }
+
+    #[test]
+    fn test_count_hostile_length() {
+        let data = vec![0xAB; 16];
+        let mut cursor = BinaryCursor::new(data);
+
+        let start = std::time::Instant::now();
+        let error = cursor
+            .count(|c| c.parse_u8(), u32::MAX as usize)
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.position(), Some(16));
+        let error = cursor.count(|c| c.parse_u64_le(), usize::MAX).unwrap_err();
+        assert_eq!(error.position(), Some(16));
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+        assert_eq!(cursor.position(), 0);
+
+        // A count that fits is unaffected
+        assert_eq!(cursor.count(|c| c.parse_u64_le(), 2).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_count_with_limit() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(
+            cursor.count_with_limit(|c| c.parse_u8(), 2, 2).unwrap(),
+            vec![1, 2]
+        );
+        let error = cursor
+            .count_with_limit(|c| c.parse_u8(), u32::MAX as usize, 64)
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.position(), Some(2));
+        assert!(error.to_string().contains("count 4294967295"));
+        assert_eq!(cursor.position(), 2);
+
+        // Within the limit, running out of data is still an EOF error
+        let error = cursor
+            .count_with_limit(|c| c.parse_u8(), 3, 64)
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_length_count() {
+        let data = vec![0x02, 0x00, 0x01, 0x00, 0x02, 0x00, 0x01, 0xAA];
+        let mut cursor = BinaryCursor::new(data);
+
+        let items = cursor
+            .length_count(|c| c.parse_u16_le(), |c| c.parse_u16_le())
+            .unwrap();
+        assert_eq!(items, vec![1, 2]);
+        let items = cursor
+            .length_count(|c| c.parse_u8(), |c| c.parse_u8())
+            .unwrap();
+        assert_eq!(items, vec![0xAA]);
+    }
+
+    #[test]
+    fn test_length_count_truncated() {
+        let data = vec![0x03, 0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(
+            cursor
+                .length_count(|c| c.parse_u8(), |c| c.parse_u8())
+                .is_err()
+        );
+        assert_eq!(cursor.position(), 0);
+
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x01];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(
+            cursor
+                .length_count(|c| c.parse_u32_le(), |c| c.parse_u8())
+                .is_err()
+        );
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_atomic() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
+        let mut cursor = BinaryCursor::new(data);
+
+        let result = cursor.atomic(|c| Ok((c.parse_u8()?, c.parse_u16_le()?)));
+        assert_eq!(result.unwrap(), (0x01, 0x0302));
+        assert_eq!(cursor.position(), 3);
+
+        let result = cursor.atomic(|c| Ok((c.parse_u8()?, c.parse_u16_le()?)));
+        assert!(result.is_err());
+        assert_eq!(cursor.position(), 3);
+
+        let result = cursor.atomic(|c| {
+            c.parse_u8()?;
+            c.atomic(|c| c.parse_u32_le())
+        });
+        assert!(result.is_err());
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_at() {
+        let data = vec![0x04, 0x00, 0x00, 0x00, 0x34, 0x12];
+        let mut cursor = BinaryCursor::new(data);
+
+        let offset = cursor.parse_u32_le().unwrap();
+        let value = cursor.at(offset as u64, |c| c.parse_u16_le()).unwrap();
+        assert_eq!(value, 0x1234);
+        assert_eq!(cursor.position(), 4);
+
+        let value = cursor.at_offset(-4, |c| c.parse_u8()).unwrap();
+        assert_eq!(value, 0x04);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_at_error_restores_position() {
+        let data = vec![0x01, 0x02, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        assert!(cursor.at(2, |c| c.parse_u32_le()).is_err());
+        assert_eq!(cursor.position(), 1);
+        assert!(cursor.at_offset(-2, |c| c.parse_u8()).is_err());
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.pop_location(), None);
+    }
+
+    #[test]
+    fn test_offset_readers() {
+        let mut data = vec![0u8; 0x1000];
+        data[0x0F00..0x0F08].copy_from_slice(&0x0102030405060708u64.to_le_bytes());
+        data[0x0FFE] = 0xAA;
+        data[0x0FFF] = 0xBB;
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(3);
+        cursor.push_location();
+
+        assert_eq!(cursor.u64_le_at(0x0F00).unwrap(), 0x0102030405060708);
+        assert_eq!(cursor.u32_le_at(0x0F00).unwrap(), 0x05060708);
+        assert_eq!(cursor.u16_le_at(0x0F06).unwrap(), 0x0102);
+        assert_eq!(cursor.u8_at(0x0FFE).unwrap(), 0xAA);
+        assert_eq!(cursor.bytes_at(0x0FFE, 2).unwrap(), [0xAA, 0xBB]);
+        assert_eq!(cursor.position(), 3);
+
+        // Failures restore the position and leave the location stack as it was
+        assert!(cursor.u32_le_at(0x0FFE).is_err());
+        assert!(cursor.bytes_at(0x0FFF, 2).is_err());
+        assert!(cursor.u8_at(0x2000).is_err());
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.location_depth(), 1);
+        assert_eq!(cursor.peek_location(), Some(3));
+    }
+
+    #[test]
+    fn test_parse_offset_bases() {
+        let data = vec![
+            0x08, 0x00, 0x00, 0x00, // absolute 8
+            0x06, 0x00, 0x00, 0x00, // 6 from this field, at 4
+            0x02, 0x00, 0x00, 0x00, // 2 from a structure starting at 8
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert_eq!(cursor.parse_offset_u32_le(OffsetBase::Start).unwrap(), 8);
+        assert_eq!(
+            cursor
+                .parse_offset_u32_le(OffsetBase::CurrentFieldStart)
+                .unwrap(),
+            10
+        );
+        assert_eq!(
+            cursor.parse_offset_u32_le(OffsetBase::Absolute(8)).unwrap(),
+            10
+        );
+        assert_eq!(cursor.position(), 12);
+        assert!(cursor.parse_offset_u32_le(OffsetBase::Start).is_err());
+
+        // Overflowing the base is an error and consumes nothing
+        cursor.set_position(0);
+        let error = cursor
+            .parse_offset_u32_le(OffsetBase::Absolute(u64::MAX))
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_deref_offset() {
+        let data = vec![
+            0x0C, 0x00, 0x00, 0x00, // absolute 12
+            0x0A, 0x00, 0x00, 0x00, // 10 from this field, at 4
+            0x04, 0x00, 0x00, 0x00, // 4 from a structure starting at 10
+            0x2A, 0x00, 0x11, 0x22,
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        let value = cursor
+            .deref_offset_u32_le(OffsetBase::Start, |c| c.parse_u8())
+            .unwrap();
+        assert_eq!(value, 0x2A);
+        assert_eq!(cursor.position(), 4);
+        let value = cursor
+            .deref_offset_u32_le(OffsetBase::CurrentFieldStart, |c| c.parse_u16_le())
+            .unwrap();
+        assert_eq!(value, 0x2211);
+        assert_eq!(cursor.position(), 8);
+        let value = cursor
+            .deref_offset_u32_le(OffsetBase::Absolute(10), |c| c.parse_u8())
+            .unwrap();
+        assert_eq!(value, 0x11);
+        assert_eq!(cursor.position(), 12);
+        assert_eq!(cursor.location_depth(), 0);
+
+        // A failing parser leaves the offset field unread
+        cursor.set_position(0);
+        assert!(
+            cursor
+                .deref_offset_u32_le(OffsetBase::Start, |c| c.parse_u64_le())
+                .is_err()
+        );
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.location_depth(), 0);
+    }
+
+    #[test]
+    fn test_deref_offset_past_end() {
+        let data = vec![0x00, 0x00, 0xF0, 0x00, 0x00, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(2);
+
+        let error = cursor
+            .deref_offset_u32_le(OffsetBase::CurrentFieldStart, |c| c.parse_u8())
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.position(), Some(2));
+        assert!(error.to_string().contains("offset resolves to 242"));
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_deref_optional_offset() {
+        let data = vec![
+            0x00, 0x00, 0x00, 0x00, // null
+            0x08, 0x00, 0x00, 0x00, // absolute 8
+            0x07, 0x00, 0x00, 0x00, // past the end
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        let value = cursor
+            .deref_optional_offset_u32_le(OffsetBase::Start, |c| c.parse_u8())
+            .unwrap();
+        assert_eq!(value, None);
+        assert_eq!(cursor.position(), 4);
+        let value = cursor
+            .deref_optional_offset_u32_le(OffsetBase::Start, |c| c.parse_u8())
+            .unwrap();
+        assert_eq!(value, Some(0x07));
+        assert_eq!(cursor.position(), 8);
+        let error = cursor
+            .deref_optional_offset_u32_le(OffsetBase::CurrentFieldStart, |c| c.parse_u8())
+            .unwrap_err();
+        assert!(error.to_string().contains("offset resolves to 15"));
+        assert_eq!(cursor.position(), 8);
+    }
+
+    #[test]
+    fn test_deref_offset_traced() {
+        let data = vec![0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2A];
+        let mut cursor = BinaryCursor::with_tracing(data);
+
+        cursor
+            .deref_offset_u32_le(OffsetBase::Start, |c| c.parse_u8())
+            .unwrap();
+        cursor
+            .deref_optional_offset_u32_le(OffsetBase::Start, |c| c.parse_u8())
+            .unwrap();
+
+        let summary: Vec<_> = cursor
+            .trace_events()
+            .iter()
+            .map(|e| (e.operation, e.offset, e.len, e.depth, e.success))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("parse_u8", 8, 1, 1, true),
+                ("deref_offset_u32_le", 0, 4, 0, true),
+                ("deref_optional_offset_u32_le", 4, 4, 0, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_at_nested() {
+        // A pointer at 0 to a pointer at 2 to a value at 4
+        let data = vec![0x02, 0x00, 0x04, 0x00, 0xAB];
+        let mut cursor = BinaryCursor::new(data);
+
+        let value = cursor
+            .at(0, |c| {
+                let first = c.parse_u8()? as u64;
+                c.at(first, |c| {
+                    let second = c.parse_u8()? as u64;
+                    c.at(second, |c| c.parse_u8())
+                })
+            })
+            .unwrap();
+        assert_eq!(value, 0xAB);
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_transaction() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        let value = cursor
+            .transaction(|c| Ok((c.parse_u8()?, c.parse_u8()?)))
+            .unwrap();
+        assert_eq!(value, (0x01, 0x02));
+        assert_eq!(cursor.position(), 2);
+
+        assert!(cursor.transaction(|c| c.parse_u32_le()).is_err());
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_transaction_nested() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        let value = cursor
+            .transaction(|c| {
+                let first = c.parse_u8()?;
+                // The inner failure is recovered from without disturbing the outer parse
+                let inner = c.transaction(|c| {
+                    c.parse_u16_le()?;
+                    c.parse_u16_le()
+                });
+                assert!(inner.is_err());
+                assert_eq!(c.position(), 1);
+                Ok((first, c.parse_u16_le()?))
+            })
+            .unwrap();
+        assert_eq!(value, (0x01, 0x0302));
+        assert_eq!(cursor.position(), 3);
+
+        let result = cursor.transaction(|c| {
+            c.parse_u8()?;
+            c.transaction(|c| c.parse_u8())
+        });
+        assert!(result.is_err());
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_transaction_location_stack() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+        cursor.push_location();
+
+        let result = cursor.transaction(|c| {
+            c.push_location();
+            c.set_position(3);
+            c.push_location();
+            c.parse_u16_le()
+        });
+        assert!(result.is_err());
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.pop_location(), Some(1));
+        assert_eq!(cursor.pop_location(), None);
+
+        // On success pushed locations are kept
+        cursor
+            .transaction(|c| {
+                c.push_location();
+                c.parse_u8()
+            })
+            .unwrap();
+        assert_eq!(cursor.pop_location(), Some(1));
+    }
+
+    #[test]
+    fn test_bookmarks() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        cursor.set_bookmark("header");
+        cursor.set_position(2);
+        cursor.set_bookmark("body");
+        assert_eq!(cursor.bookmark("header"), Some(0));
+        assert_eq!(cursor.bookmark("body"), Some(2));
+
+        cursor.goto_bookmark("header").unwrap();
+        assert_eq!(cursor.parse_u8().unwrap(), 0x01);
+        cursor.goto_bookmark("body").unwrap();
+        assert_eq!(cursor.parse_u8().unwrap(), 0x03);
+
+        // Overwriting replaces the saved position
+        cursor.set_bookmark("header");
+        assert_eq!(cursor.bookmark("header"), Some(3));
+
+        assert_eq!(cursor.remove_bookmark("body"), Some(2));
+        assert_eq!(cursor.bookmark("body"), None);
+        assert_eq!(cursor.remove_bookmark("body"), None);
+    }
+
+    #[test]
+    fn test_goto_missing_bookmark() {
+        let data = vec![0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        let error = cursor.goto_bookmark("missing").unwrap_err();
+        assert!(error.to_string().contains("missing"));
+        assert_eq!(cursor.position(), 1);
+
+        let mut jump = BinaryCursorJump::new(&mut cursor);
+        assert!(jump.jump_to_bookmark("missing").is_err());
+        drop(jump);
+        assert_eq!(cursor.pop_location(), None);
+    }
+
+    #[test]
+    fn test_bookmarks_with_location_stack() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(3);
+        cursor.set_bookmark("tail");
+        cursor.set_position(1);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump_to_bookmark("tail").unwrap();
+            assert_eq!(jump.cursor.parse_u8().unwrap(), 0x04);
+            jump.cursor.set_bookmark("end");
+        }
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.bookmark("end"), Some(4));
+
+        cursor.push_location();
+        cursor.goto_bookmark("tail").unwrap();
+        assert!(cursor.restore_location());
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.bookmark("tail"), Some(3));
+    }
+
+    #[test]
+    fn test_pop_location() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.pop_location(), None);
+
+        cursor.push_location();
+        cursor.set_position(2);
+        assert_eq!(cursor.pop_location(), Some(0));
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_error_handling() {
+        let data = vec![0x42];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert!(cursor.parse_u16_le().is_err());
+        assert!(cursor.parse_u32_le().is_err());
+        assert!(cursor.parse_f32_le().is_err());
+        assert!(cursor.parse_bytes(2).is_err());
+    }
+
+    #[test]
+    fn test_primitive_eof_errors() {
+        let data = vec![0x01, 0x02, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.parse_u8().unwrap();
+
+        let error = cursor.parse_u32_le().unwrap_err();
+        match error {
+            BinaryCursorError::UnexpectedEof {
+                operation,
+                position,
+                requested,
+                available,
+            } => {
+                assert_eq!(operation, "parse_u32_le");
+                assert_eq!(position, 1);
+                assert_eq!(requested, 4);
+                assert_eq!(available, 2);
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+        // A failed read consumes nothing
+        assert_eq!(cursor.position(), 1);
+
+        for error in [
+            cursor.parse_u64().unwrap_err(),
+            cursor.parse_f32_le().unwrap_err(),
+            cursor.parse_bytes(3).unwrap_err(),
+            cursor.parse_uint(3, Endianness::Big).unwrap_err(),
+        ] {
+            assert_eq!(error.fields().kind, "unexpected_eof");
+            assert_eq!(error.position(), Some(1));
+        }
+
+        cursor.set_position(10);
+        let error = cursor.parse_u8().unwrap_err();
+        assert_eq!(error.position(), Some(10));
+        assert_eq!(error.fields().available, Some(0));
+    }
+
+    #[test]
+    fn test_error_position_at_depth() {
+        // A header, then an offset to a record whose second field is truncated
+        let data = vec![0xAA, 0x03, 0x00, 0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+
+        let error = cursor
+            .transaction(|c| {
+                c.expect_tag(&[0xAA])?;
+                let offset = c.parse_u16_le()? as u64;
+                c.at(offset, |c| {
+                    c.parse_u8()?;
+                    c.parse_u32_le().map_err(|e| e.context("record size"))
+                })
+            })
+            .map_err(|e| e.context("header"))
+            .unwrap_err();
+        assert_eq!(error.position(), Some(4));
+        assert_eq!(error.fields().context, vec!["header", "record size"]);
+        assert_eq!(cursor.position(), 0);
+
+        cursor.set_position(5);
+        assert_eq!(cursor.parse_u16_le_rev().unwrap(), 0x0201);
+        let error = cursor.rev_mode().parse_u32_le().unwrap_err();
+        assert_eq!(error.position(), Some(3));
+        assert_eq!(error.fields().available, Some(3));
+
+        let argument_error = cursor.parse_uint(9, Endianness::Little).unwrap_err();
+        assert_eq!(argument_error.position(), None);
+    }
+
+    #[test]
+    fn test_restore_location() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+
+        cursor.push_location();
+        cursor.set_position(2);
+        assert_eq!(cursor.position(), 2);
+
+        assert!(cursor.restore_location());
+        assert_eq!(cursor.position(), 0);
+
+        assert!(!cursor.restore_location());
+    }
+
+    #[test]
+    fn test_error_conversion() {
+        use std::io::{Error, ErrorKind};
+        let io_error = Error::new(ErrorKind::UnexpectedEof, "test error");
+        let cursor_error = BinaryCursorError::from_io_error(io_error);
+        assert!(matches!(cursor_error, BinaryCursorError::ParseError(_)));
+    }
+
+    #[test]
+    fn test_error_display_snapshots() {
+        let parse_error = BinaryCursorError::from_io_error(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ));
+        assert_eq!(
+            parse_error.to_string(),
+            "Parse error: failed to fill whole buffer"
+        );
+
+        let eof = BinaryCursorError::UnexpectedEof {
+            operation: "parse_u32_le",
+            position: 12,
+            requested: 4,
+            available: 3,
+        };
+        assert_eq!(
+            eof.to_string(),
+            "Unexpected EOF at offset 12 in parse_u32_le: requested 4 bytes, 3 available"
+        );
+
+        let invalid = BinaryCursorError::InvalidData {
+            operation: "parse_cstr",
+            position: 7,
+            message: "invalid utf-8 sequence".to_string(),
+        };
+        assert_eq!(
+            invalid.to_string(),
+            "Invalid data at offset 7 in parse_cstr: invalid utf-8 sequence"
+        );
+
+        let context = invalid.context("file name").context("header");
+        assert_eq!(
+            context.to_string(),
+            "header: file name: Invalid data at offset 7 in parse_cstr: invalid utf-8 sequence"
+        );
+    }
+
+    #[test]
+    fn test_error_fields() {
+        let eof = BinaryCursorError::UnexpectedEof {
+            operation: "parse_u32_le",
+            position: 12,
+            requested: 4,
+            available: 3,
+        }
+        .context("record");
+        assert_eq!(
+            eof.fields(),
+            ErrorFields {
+                kind: "unexpected_eof",
+                offset: Some(12),
+                requested: Some(4),
+                available: Some(3),
+                operation: Some("parse_u32_le"),
+                message: "requested 4 bytes, 3 available".to_string(),
+                context: vec!["record"],
+            }
+        );
+
+        let parse_error = BinaryCursorError::from_io_error(std::io::Error::other("boom"));
+        assert_eq!(
+            parse_error.fields(),
+            ErrorFields {
+                kind: "parse_error",
+                offset: None,
+                requested: None,
+                available: None,
+                operation: None,
+                message: "boom".to_string(),
+                context: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_to_json() {
+        let invalid = BinaryCursorError::InvalidData {
+            operation: "parse_cstr",
+            position: 7,
+            message: "bad \"quote\"\n".to_string(),
+        }
+        .context("outer")
+        .context("inner");
+        assert_eq!(
+            invalid.to_json(),
+            r#"{"kind":"invalid_data","offset":7,"requested":null,"available":null,"operation":"parse_cstr","message":"bad \"quote\"\n","context":["inner","outer"]}"#
+        );
+
+        let parse_error = BinaryCursorError::from_io_error(std::io::Error::other("boom"));
+        assert_eq!(
+            parse_error.to_json(),
+            r#"{"kind":"parse_error","offset":null,"requested":null,"available":null,"operation":null,"message":"boom","context":[]}"#
+        );
+    }
+
+    #[test]
+    fn test_helper_error_fields() {
+        let data = vec![b'a', 0xFF, 0x00, b'b'];
+        let mut cursor = BinaryCursor::new(data);
+
+        let fields = cursor.parse_cstr().unwrap_err().fields();
+        assert_eq!(fields.kind, "invalid_data");
+        assert_eq!(fields.offset, Some(0));
+        assert_eq!(fields.operation, Some("parse_cstr"));
+
+        cursor.set_position(3);
+        let fields = cursor.parse_cstr().unwrap_err().fields();
+        assert_eq!(fields.kind, "unexpected_eof");
+        assert_eq!(fields.offset, Some(3));
+        assert_eq!(fields.requested, Some(2));
+        assert_eq!(fields.available, Some(1));
+
+        // The string helpers report the method that was called
+        cursor.set_position(0);
+        let fields = cursor.parse_cstr_max(8).unwrap_err().fields();
+        assert_eq!(fields.operation, Some("parse_cstr_max"));
+        let fields = cursor.parse_cstr_raw_max(0).unwrap_err().fields();
+        assert_eq!(fields.operation, Some("parse_cstr_raw_max"));
+        cursor.set_position(3);
+        let fields = cursor.parse_cstr_raw().unwrap_err().fields();
+        assert_eq!(fields.operation, Some("parse_cstr_raw"));
+        let fields = cursor.parse_cstr_lossy().unwrap_err().fields();
+        assert_eq!(fields.operation, Some("parse_cstr_lossy"));
+
+        let fields = cursor.parse_u32_le_rev().unwrap_err().fields();
+        assert_eq!(fields.kind, "unexpected_eof");
+        assert_eq!(fields.operation, Some("parse_u32_le_rev"));
+        assert_eq!(fields.requested, Some(4));
+        assert_eq!(fields.available, Some(3));
+    }
+
+    #[test]
+    fn test_jump_relative() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(2);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump_relative(2).unwrap();
+            assert_eq!(jump.cursor.position(), 4);
+        }
+        assert_eq!(cursor.position(), 2);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump_relative(-1).unwrap();
+            assert_eq!(jump.cursor.position(), 1);
+        }
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_jump_relative_overflow() {
+        let data = vec![0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            assert!(jump.jump_relative(-2).is_err());
+        }
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_parse_i8() {
+        let data = vec![0xFE, 0x7F, 0x80];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_i8().unwrap(), -2);
+        assert_eq!(cursor.parse_i8().unwrap(), 127);
+        assert_eq!(cursor.parse_i8().unwrap(), -128);
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_parse_i16_le() {
+        let data = vec![0xFE, 0xFF, 0xFF, 0x7F, 0x00, 0x80];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_i16_le().unwrap(), -2);
+        assert_eq!(cursor.parse_i16_le().unwrap(), 32767);
+        assert_eq!(cursor.parse_i16_le().unwrap(), -32768);
+        assert_eq!(cursor.position(), 6);
+    }
+
+    #[test]
+    fn test_parse_i32_le() {
+        let data = vec![
+            0xFE, 0xFF, 0xFF, 0xFF, // -2
+            0xFF, 0xFF, 0xFF, 0x7F, // 2147483647
+            0x00, 0x00, 0x00, 0x80, // -2147483648
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_i32_le().unwrap(), -2);
+        assert_eq!(cursor.parse_i32_le().unwrap(), 2147483647);
+        assert_eq!(cursor.parse_i32_le().unwrap(), -2147483648);
+        assert_eq!(cursor.position(), 12);
+    }
+
+    #[test]
+    fn test_parse_i64_le() {
+        let data = vec![
+            0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // -2
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, // 9223372036854775807
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, // -9223372036854775808
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_i64_le().unwrap(), -2);
+        assert_eq!(cursor.parse_i64_le().unwrap(), 9223372036854775807);
+        assert_eq!(cursor.parse_i64_le().unwrap(), -9223372036854775808);
+        assert_eq!(cursor.position(), 24);
+    }
+
+    #[test]
+    fn test_signed_integer_error_handling() {
+        let data = vec![0x42];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert!(cursor.parse_i16_le().is_err());
+        assert!(cursor.parse_i32_le().is_err());
+        assert!(cursor.parse_u64_le().is_err());
+        assert!(cursor.parse_i64_le().is_err());
+    }
+
+    #[test]
+    fn test_parse_uint() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let expected_le = [
+            0x01,
+            0x0201,
+            0x030201,
+            0x04030201,
+            0x0504030201,
+            0x060504030201,
+            0x07060504030201,
+            0x0807060504030201,
+        ];
+        let expected_be = [
+            0x01,
+            0x0102,
+            0x010203,
+            0x01020304,
+            0x0102030405,
+            0x010203040506,
+            0x01020304050607,
+            0x0102030405060708,
+        ];
+
+        for num_bytes in 1..=8 {
+            let mut cursor = BinaryCursor::new(&data);
+            assert_eq!(
+                cursor.parse_uint(num_bytes, Endianness::Little).unwrap(),
+                expected_le[num_bytes - 1]
+            );
+            assert_eq!(cursor.position(), num_bytes as u64);
+
+            let mut cursor = BinaryCursor::new(&data);
+            assert_eq!(
+                cursor.parse_uint(num_bytes, Endianness::Big).unwrap(),
+                expected_be[num_bytes - 1]
+            );
+            assert_eq!(cursor.position(), num_bytes as u64);
+        }
+    }
+
+    #[test]
+    fn test_parse_int() {
+        let data = vec![0xFF; 8];
+        for num_bytes in 1..=8 {
+            let mut cursor = BinaryCursor::new(&data);
+            assert_eq!(cursor.parse_int(num_bytes, Endianness::Little).unwrap(), -1);
+            let mut cursor = BinaryCursor::new(&data);
+            assert_eq!(cursor.parse_int(num_bytes, Endianness::Big).unwrap(), -1);
+        }
+
+        // 5-byte field with the sign bit set
+        let data = vec![0x00, 0x00, 0x00, 0x00, 0x80];
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(
+            cursor.parse_int(5, Endianness::Little).unwrap(),
+            -549755813888
+        );
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(cursor.parse_int(5, Endianness::Big).unwrap(), 128);
+
+        // 7-byte field with the sign bit set
+        let data = vec![0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(cursor.parse_int(7, Endianness::Little).unwrap(), -2);
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(
+            cursor.parse_int(7, Endianness::Big).unwrap(),
+            -281474976710657
+        );
+
+        // 7-byte field with the sign bit clear
+        let data = vec![0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(
+            cursor.parse_int(7, Endianness::Big).unwrap(),
+            36028797018963967
+        );
+    }
+
+    #[test]
+    fn test_parse_uint_invalid_width() {
+        let data = vec![0x00; 16];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert!(cursor.parse_uint(0, Endianness::Little).is_err());
+        assert!(cursor.parse_uint(9, Endianness::Big).is_err());
+        assert!(cursor.parse_int(0, Endianness::Little).is_err());
+        assert!(cursor.parse_int(9, Endianness::Big).is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_uint_error_handling() {
+        let data = vec![0x01, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_uint(3, Endianness::Little).is_err());
+    }
+
+    #[test]
+    fn test_parse_cstr() {
+        let data = b"abc\0\0de\0".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_cstr().unwrap(), "abc");
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.parse_cstr().unwrap(), "");
+        assert_eq!(cursor.position(), 5);
+        assert_eq!(cursor.parse_cstr().unwrap(), "de");
+        assert_eq!(cursor.position(), 8);
+    }
+
+    #[test]
+    fn test_parse_cstr_missing_terminator() {
+        let data = b"abc\0def".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_cstr().unwrap(), "abc");
+        assert!(cursor.parse_cstr().is_err());
+        assert_eq!(cursor.position(), 4);
+
+        cursor.set_position(20);
+        assert!(cursor.parse_cstr_raw().is_err());
+        assert_eq!(cursor.position(), 20);
+    }
+
+    #[test]
+    fn test_parse_cstr_invalid_utf8() {
+        let data = vec![b'a', 0xFF, b'b', 0x00];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_cstr().is_err());
+        assert_eq!(cursor.position(), 0);
+
+        assert_eq!(cursor.parse_cstr_raw().unwrap(), vec![b'a', 0xFF, b'b']);
+        assert_eq!(cursor.position(), 4);
+
+        cursor.set_position(0);
+        assert_eq!(cursor.parse_cstr_lossy().unwrap(), "a\u{FFFD}b");
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_parse_cstr_max() {
+        let data = b"abcd\0".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+
+        assert!(cursor.parse_cstr_max(3).is_err());
+        assert_eq!(cursor.position(), 0);
+
+        assert_eq!(cursor.parse_cstr_max(4).unwrap(), "abcd");
+        assert_eq!(cursor.position(), 5);
+
+        cursor.set_position(0);
+        assert_eq!(cursor.parse_cstr_raw_max(10).unwrap(), b"abcd");
+        assert_eq!(cursor.position(), 5);
+
+        let data = b"\0".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_cstr_max(0).unwrap(), "");
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_parse_packed_le_fat_datetime() {
+        // FAT directory entry time then date for 2021-06-15 13:45:30
+        let data = vec![0xAF, 0x6D, 0xCF, 0x52];
+        let mut cursor = BinaryCursor::new(data);
+
+        let time = cursor
+            .parse_packed_le(2, &[("seconds", 5), ("minutes", 6), ("hours", 5)])
+            .unwrap();
+        assert_eq!(time, vec![15, 45, 13]);
+
+        let date = cursor
+            .parse_packed_le(2, &[("day", 5), ("month", 4), ("year", 7)])
+            .unwrap();
+        assert_eq!(date, vec![15, 6, 41]);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_parse_packed_le_partial_and_full_width() {
+        let data = vec![0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80];
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(
+            cursor
+                .parse_packed_le(8, &[("low", 4), ("zero", 0), ("next", 8)])
+                .unwrap(),
+            vec![0x0F, 0, 0x0F]
+        );
+        assert_eq!(cursor.position(), 8);
+
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(
+            cursor.parse_packed_le(8, &[("all", 64)]).unwrap(),
+            vec![0x80000000000000FF]
+        );
+    }
+
+    #[test]
+    fn test_parse_packed_le_width_overflow() {
+        let data = vec![0xFF, 0xFF];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_packed_le(2, &[("a", 8), ("b", 9)]).is_err());
+        assert_eq!(cursor.position(), 0);
+        assert!(cursor.parse_packed_le(4, &[("a", 8)]).is_err());
+
+        // A hostile size is an error rather than an overflow
+        let error = cursor.parse_packed_le(usize::MAX, &[("a", 8)]).unwrap_err();
+        assert!(matches!(error, BinaryCursorError::ParseError(_)));
+        assert!(
+            cursor
+                .parse_packed_le(usize::MAX / 8, &[("a", 200)])
+                .is_err()
+        );
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_bit_field_tcp_flags() {
+        // CWR ECE URG ACK PSH RST SYN FIN, with ACK, PSH and FIN set
+        let data = vec![0b0001_1001];
+        let mut cursor = BinaryCursor::new(data);
+
+        let flags = cursor.parse_u8_bits().unwrap();
+        assert_eq!(flags.value(), 0x19);
+        assert_eq!(flags.width(), 8);
+        let set: Vec<u8> = (0..8).filter(|&n| flags.bit(n).unwrap()).collect();
+        assert_eq!(set, vec![0, 3, 4]);
+        assert!(flags.bit(8).is_err());
+        let error = flags.bit(255).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Bit 255 is outside a 8-bit field")
+        );
+        assert!(BitField::new(u64::MAX).bit(255).is_err());
+        assert!(BitField::new(u64::MAX).bit(63).unwrap());
+        assert_eq!(flags.bits(3..3).unwrap(), 0);
+        assert_eq!(flags.bits(0..8).unwrap(), 0x19);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = flags.bits(5..2);
+        assert!(reversed.is_err());
+    }
+
+    #[test]
+    fn test_bit_field_packed_u16() {
+        // IPv4 flags and fragment offset: 3-bit flags (DF set) over a 13-bit offset of 185
+        let data = vec![0x40, 0xB9, 0xB9, 0x40, 0x78, 0x56, 0x34, 0x12];
+        let mut cursor = BinaryCursor::new(data);
+
+        let word = cursor.parse_u16_bits_be().unwrap();
+        assert_eq!(word.bits(13..16).unwrap(), 0b010);
+        assert_eq!(word.bits(0..13).unwrap(), 185);
+        assert!(word.bits(10..17).is_err());
+        assert_eq!(cursor.parse_u16_bits_le().unwrap(), word);
+
+        let dword = cursor.parse_u32_bits_le().unwrap();
+        assert_eq!(dword.bits(24..32).unwrap(), 0x12);
+        assert_eq!(dword.bits(0..32).unwrap(), 0x12345678);
+        assert!(cursor.parse_u32_bits_be().is_err());
+    }
+
+    #[test]
+    fn test_parse_bcd_iso8583() {
+        let data = vec![
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, // field 4, amount "000000010000"
+            0x12, 0x34, 0x56, // field 11, STAN "123456"
+            0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, // 20 nines
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_bcd(6).unwrap(), 10000);
+        assert_eq!(cursor.parse_bcd(3).unwrap(), 123456);
+        assert_eq!(cursor.parse_bcd(0).unwrap(), 0);
+
+        let error = cursor.parse_bcd(10).unwrap_err();
+        assert!(error.to_string().contains("does not fit in u64"));
+        // The 20th digit, in the last byte, is the one that overflows
+        assert_eq!(error.position(), Some(18));
+        assert_eq!(cursor.position(), 9);
+        assert!(cursor.parse_bcd(11).is_err());
+    }
+
+    #[test]
+    fn test_parse_bcd_invalid_digit() {
+        let data = vec![0x12, 0x3A, 0x45];
+        let mut cursor = BinaryCursor::new(data);
+        let error = cursor.parse_bcd(3).unwrap_err();
+        assert_eq!(error.position(), Some(1));
+        assert!(error.to_string().contains("0xA is not a decimal digit"));
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.parse_bcd(1).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_parse_semi_octets() {
+        // SMS PDU destination address "31641600986", padded with a filler nibble
+        let data = vec![0x13, 0x46, 0x61, 0x00, 0x89, 0xF6, 0x21, 0x43];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_semi_octets(6).unwrap(), "31641600986");
+        assert_eq!(cursor.parse_semi_octets(2).unwrap(), "1234");
+        assert!(cursor.at_end());
+
+        let data = vec![0xF1, 0x32];
+        let mut cursor = BinaryCursor::new(data);
+        let error = cursor.parse_semi_octets(2).unwrap_err();
+        assert!(error.to_string().contains("follows a 0xF filler"));
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.parse_semi_octets(1).unwrap(), "1");
+
+        let mut cursor = BinaryCursor::new(vec![0xB1]);
+        assert!(cursor.parse_semi_octets(1).is_err());
+    }
+
+    #[test]
+    fn test_expect_tag() {
+        let data = vec![0x89, b'P', b'N', b'G', 0x01];
+        let mut cursor = BinaryCursor::new(data);
+
+        cursor.expect_tag(b"\x89PNG").unwrap();
+        assert_eq!(cursor.position(), 4);
+        cursor.expect_tag(b"").unwrap();
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn test_expect_tag_mismatch() {
+        let data = vec![0x00, b'G', b'I', b'F', b'8'];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        let error = cursor.expect_tag(b"GIF9").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid data at offset 1 in expect_tag: expected tag [47 49 46 39], found [47 49 46 38]"
+        );
+        assert_eq!(cursor.position(), 1);
+        cursor.expect_tag(b"GIF8").unwrap();
+    }
+
+    #[test]
+    fn test_expect_tag_truncated() {
+        let data = vec![b'R', b'I', b'F'];
+        let mut cursor = BinaryCursor::new(data);
+
+        let error = cursor.expect_tag(b"RIFF").unwrap_err();
+        let fields = error.fields();
+        assert_eq!(fields.kind, "unexpected_eof");
+        assert_eq!(fields.requested, Some(4));
+        assert_eq!(fields.available, Some(3));
+        assert_eq!(cursor.position(), 0);
+
+        assert!(!cursor.matches_tag(b"RIFF").unwrap());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_matches_tag() {
+        let data = b"PK\x03\x04rest";
+        let mut cursor = BinaryCursor::new(&data[..]);
+
+        assert!(!cursor.matches_tag(b"\x1F\x8B").unwrap());
+        assert_eq!(cursor.position(), 0);
+        assert!(cursor.matches_tag(b"PK\x03\x04").unwrap());
+        assert_eq!(cursor.position(), 4);
+
+        cursor.set_position(100);
+        assert!(!cursor.matches_tag(b"r").unwrap());
+        assert!(cursor.matches_tag(b"").unwrap());
+    }
+
+    #[test]
+    fn test_find() {
+        let data = b"abaabab";
+        let mut cursor = BinaryCursor::new(&data[..]);
+
+        // Match at the current position, and an empty needle
+        assert_eq!(cursor.find(b"ab"), Some(0));
+        assert_eq!(cursor.find(b""), Some(0));
+
+        // The partial match "aba" at offset 0 must not hide the match at offset 3
+        assert_eq!(cursor.find(b"abab"), Some(3));
+        cursor.set_position(1);
+        assert_eq!(cursor.find(b"ab"), Some(3));
+
+        // A match ending exactly at the end of the data
+        cursor.set_position(4);
+        assert_eq!(cursor.find(b"bab"), Some(4));
+        assert_eq!(cursor.find(b"babx"), None);
+        assert_eq!(cursor.position(), 4);
+
+        cursor.set_position(100);
+        assert_eq!(cursor.find(b"a"), None);
+    }
+
+    #[test]
+    fn test_seek_to() {
+        let data = b"\x00\x01garbage\x47\x40\x00\x10";
+        let mut cursor = BinaryCursor::new(&data[..]);
+
+        assert_eq!(cursor.seek_to(b"\x47\x40").unwrap(), 9);
+        assert_eq!(cursor.position(), 9);
+        assert_eq!(cursor.seek_to(b"\x47").unwrap(), 9);
+
+        cursor.set_position(10);
+        let error = cursor.seek_to(b"\x47\x40").unwrap_err();
+        assert_eq!(error.position(), Some(10));
+        assert!(error.to_string().contains("pattern [47 40] not found"));
+        assert_eq!(cursor.position(), 10);
+    }
+
+    #[test]
+    fn test_take_until() {
+        let data = b"\r\nGET / HTTP/1.1\r\nHost: a\rb\r\n\r\n";
+        let mut cursor = BinaryCursor::new(&data[..]);
+
+        // Delimiter at the current position
+        assert_eq!(cursor.take_until(b"\r\n").unwrap(), b"");
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.take_until_and_consume(b"\r\n").unwrap(), b"");
+        assert_eq!(cursor.position(), 2);
+
+        assert_eq!(
+            cursor.take_until_and_consume(b"\r\n").unwrap(),
+            b"GET / HTTP/1.1"
+        );
+        // A lone \r does not end the line
+        assert_eq!(
+            cursor.take_until_and_consume(b"\r\n").unwrap(),
+            b"Host: a\rb"
+        );
+        assert_eq!(cursor.take_until(b"\r\n").unwrap(), b"");
+        assert_eq!(cursor.take_until_and_consume(b"\r\n").unwrap(), b"");
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_take_until_byte_and_missing_delimiter() {
+        let data = b"key=value;rest";
+        let mut cursor = BinaryCursor::new(&data[..]);
+
+        assert_eq!(cursor.take_until_byte(b'=').unwrap(), b"key");
+        assert_eq!(cursor.parse_u8().unwrap(), b'=');
+        assert_eq!(cursor.take_until_byte(b';').unwrap(), b"value");
+        cursor.skip(1).unwrap();
+
+        let error = cursor.take_until(b"\r\n").unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.position(), Some(10));
+        assert_eq!(error.fields().available, Some(4));
+        assert!(cursor.take_until_and_consume(b";").is_err());
+        assert!(cursor.take_until_byte(b'x').is_err());
+        assert_eq!(cursor.position(), 10);
+    }
+
+    #[test]
+    fn test_parse_uleb128() {
+        let cases: &[(&[u8], u64)] = &[
+            (&[0x00], 0),
+            (&[0x01], 1),
+            (&[0x7F], 127),
+            (&[0x80, 0x01], 128),
+            (&[0xFF, 0x7F], 16383),
+            (&[0x80, 0x80, 0x01], 16384),
+            (
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+                u64::MAX,
+            ),
+        ];
+        for (bytes, expected) in cases {
+            let mut cursor = BinaryCursor::new(*bytes);
+            assert_eq!(cursor.parse_uleb128().unwrap(), *expected);
+            assert_eq!(cursor.position(), bytes.len() as u64);
+        }
+
+        // Only the bytes of the encoding are consumed
+        let data = vec![0x80, 0x01, 0xFF];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_uleb128().unwrap(), 128);
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_parse_uleb128_errors() {
+        // 11 bytes is always too long
+        let data = vec![0x80; 11];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(
+            cursor.parse_uleb128().unwrap_err().fields().kind,
+            "invalid_data"
+        );
+        assert_eq!(cursor.position(), 0);
+
+        // 10 bytes, but the final byte carries bits beyond 64
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(
+            cursor.parse_uleb128().unwrap_err().fields().kind,
+            "invalid_data"
+        );
+        assert_eq!(cursor.position(), 0);
+
+        let data = vec![0x01, 0x80, 0x80];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_uleb128().unwrap(), 1);
+        let fields = cursor.parse_uleb128().unwrap_err().fields();
+        assert_eq!(fields.kind, "unexpected_eof");
+        assert_eq!(fields.offset, Some(1));
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_parse_sleb128() {
+        let cases: &[(&[u8], i64)] = &[
+            (&[0x00], 0),
+            (&[0x02], 2),
+            (&[0x7E], -2),
+            (&[0x7F], -1),
+            (&[0x3F], 63),
+            (&[0x40], -64),
+            (&[0xFF, 0x00], 127),
+            (&[0x80, 0x01], 128),
+            (&[0x81, 0x7F], -127),
+            (&[0x80, 0x7F], -128),
+            (&[0xC0, 0xBB, 0x78], -123456),
+            (
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00],
+                i64::MAX,
+            ),
+            (
+                &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F],
+                i64::MIN,
+            ),
+        ];
+        for (bytes, expected) in cases {
+            let mut cursor = BinaryCursor::new(*bytes);
+            assert_eq!(cursor.parse_sleb128().unwrap(), *expected);
+            assert_eq!(cursor.position(), bytes.len() as u64);
+        }
+    }
+
+    #[test]
+    fn test_parse_sleb128_errors() {
+        let data = vec![0x80; 11];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_sleb128().is_err());
+        assert_eq!(cursor.position(), 0);
+
+        let data = vec![0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_sleb128().is_err());
+
+        let data = vec![0xFF];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(
+            cursor.parse_sleb128().unwrap_err().fields().kind,
+            "unexpected_eof"
+        );
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_varint_zigzag() {
+        let data = vec![0x00, 0x01, 0x02, 0x03, 0xFE, 0xFF, 0x03, 0xFF, 0xFF, 0x03];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_varint_zigzag().unwrap(), 0);
+        assert_eq!(cursor.parse_varint_zigzag().unwrap(), -1);
+        assert_eq!(cursor.parse_varint_zigzag().unwrap(), 1);
+        assert_eq!(cursor.parse_varint_zigzag().unwrap(), -2);
+        assert_eq!(cursor.parse_varint_zigzag().unwrap(), 32767);
+        assert_eq!(cursor.parse_varint_zigzag().unwrap(), -32768);
+    }
+
+    #[test]
+    fn test_parse_vlq() {
+        // Examples from the Standard MIDI File specification
+        let cases: &[(&[u8], u32)] = &[
+            (&[0x00], 0x00000000),
+            (&[0x40], 0x00000040),
+            (&[0x7F], 0x0000007F),
+            (&[0x81, 0x00], 0x00000080),
+            (&[0xC0, 0x00], 0x00002000),
+            (&[0xFF, 0x7F], 0x00003FFF),
+            (&[0x81, 0x80, 0x00], 0x00004000),
+            (&[0xC0, 0x80, 0x00], 0x00100000),
+            (&[0xFF, 0xFF, 0x7F], 0x001FFFFF),
+            (&[0x81, 0x80, 0x80, 0x00], 0x00200000),
+            (&[0xC0, 0x80, 0x80, 0x00], 0x08000000),
+            (&[0xFF, 0xFF, 0xFF, 0x7F], 0x0FFFFFFF),
+            (&[0x80, 0x00], 0x00000000),
+        ];
+        for (bytes, expected) in cases {
+            let mut data = bytes.to_vec();
+            data.push(0xAA);
+            let mut cursor = BinaryCursor::new(data);
+            assert_eq!(cursor.parse_vlq().unwrap(), *expected);
+            assert_eq!(cursor.position(), bytes.len() as u64);
+        }
+    }
+
+    #[test]
+    fn test_parse_vlq_errors() {
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x7F];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(
+            cursor.parse_vlq().unwrap_err().fields().kind,
+            "invalid_data"
+        );
+        assert_eq!(cursor.position(), 0);
+
+        let data = vec![0x81, 0x80];
+        let mut cursor = BinaryCursor::new(data);
+        let fields = cursor.parse_vlq().unwrap_err().fields();
+        assert_eq!(fields.kind, "unexpected_eof");
+        assert_eq!(fields.available, Some(2));
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_fixed_str() {
+        let data = b"abc\0\0def  ghijk".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_fixed_str(5, Padding::Null).unwrap(), "abc");
+        assert_eq!(cursor.position(), 5);
+        assert_eq!(cursor.parse_fixed_str(5, Padding::Space).unwrap(), "def");
+        assert_eq!(cursor.position(), 10);
+        assert_eq!(cursor.parse_fixed_str(5, Padding::None).unwrap(), "ghijk");
+        assert_eq!(cursor.position(), 15);
+    }
+
+    #[test]
+    fn test_parse_fixed_str_all_padding() {
+        let data = b"\0\0\0\0    ".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_fixed_str(4, Padding::Null).unwrap(), "");
+        assert_eq!(cursor.parse_fixed_str(4, Padding::Space).unwrap(), "");
+        assert_eq!(cursor.position(), 8);
+
+        cursor.set_position(0);
+        assert_eq!(cursor.parse_fixed_str(0, Padding::Null).unwrap(), "");
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_fixed_str_embedded_nul() {
+        let data = b"ab\0cd\0\0\0".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_fixed_str(8, Padding::Null).unwrap(), "ab");
+        assert_eq!(cursor.position(), 8);
+
+        cursor.set_position(0);
+        assert_eq!(
+            cursor.parse_fixed_str(8, Padding::None).unwrap(),
+            "ab\0cd\0\0\0"
+        );
+
+        let data = b" a b  ".to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_fixed_str(6, Padding::Space).unwrap(), " a b");
+    }
+
+    #[test]
+    fn test_parse_fixed_str_errors() {
+        let data = vec![b'a', 0xFF, 0x00, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+
+        assert!(cursor.parse_fixed_str(4, Padding::Null).is_err());
+        assert_eq!(cursor.position(), 0);
+
+        assert_eq!(
+            cursor.parse_fixed_str_lossy(4, Padding::Null).unwrap(),
+            "a\u{FFFD}"
+        );
+        assert_eq!(cursor.position(), 4);
+
+        cursor.set_position(2);
+        assert!(cursor.parse_fixed_str(4, Padding::Null).is_err());
+        assert_eq!(cursor.position(), 2);
+        assert!(cursor.parse_fixed_str_lossy(4, Padding::Null).is_err());
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_parse_utf16() {
+        let data = vec![b'h', 0x00, 0xE9, 0x00, 0x00, b'h', 0x00, 0xE9];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_utf16_le(2).unwrap(), "hé");
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.parse_utf16_be(2).unwrap(), "hé");
+        assert_eq!(cursor.position(), 8);
+    }
+
+    #[test]
+    fn test_parse_utf16_surrogates() {
+        // U+1F600 as a surrogate pair
+        let data = vec![0x3D, 0xD8, 0x00, 0xDE];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_utf16_le(2).unwrap(), "😀");
+        assert_eq!(cursor.position(), 4);
+
+        let data = vec![0xD8, 0x3D, 0x00, b'a'];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_utf16_be(2).is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_utf16_odd_length() {
+        let data = vec![b'a', 0x00, b'b'];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_utf16_le(2).is_err());
+        assert_eq!(cursor.position(), 0);
+        assert!(cursor.parse_utf16_cstr_le().is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_utf16_cstr() {
+        let data = vec![
+            b'h', 0x00, b'i', 0x00, 0x00, 0x00, // "hi" LE
+            0x00, 0x00, // empty
+            0xD8, 0x3D, 0xDE, 0x00, 0x00, 0x00, // U+1F600 BE
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_utf16_cstr_le().unwrap(), "hi");
+        assert_eq!(cursor.position(), 6);
+        assert_eq!(cursor.parse_utf16_cstr_le().unwrap(), "");
+        assert_eq!(cursor.position(), 8);
+        assert_eq!(cursor.parse_utf16_cstr_be().unwrap(), "😀");
+        assert_eq!(cursor.position(), 14);
+        assert!(cursor.parse_utf16_cstr_be().is_err());
+        assert_eq!(cursor.position(), 14);
+    }
+
+    #[test]
+    fn test_parse_utf16_cstr_unpaired_surrogate() {
+        let data = vec![0x3D, 0xD8, 0x00, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(cursor.parse_utf16_cstr_le().is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_extract_region() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(1);
+
+        assert_eq!(
+            cursor.extract_region(0, 4).unwrap(),
+            vec![0x01, 0x02, 0x03, 0x04]
+        );
+        assert_eq!(
+            cursor.extract_region(2, 4).unwrap(),
+            vec![0x03, 0x04, 0x05, 0x06]
+        );
+        assert_eq!(cursor.extract_region(6, 0).unwrap(), Vec::<u8>::new());
+        assert_eq!(cursor.position(), 1);
+    }
+
+    #[test]
+    fn test_extract_region_to() {
+        let data: Vec<u8> = (0..=255).cycle().take(200_000).collect();
+        let cursor = BinaryCursor::new(&data);
+
+        let mut out = vec![];
+        assert_eq!(
+            cursor.extract_region_to(10, 150_000, &mut out).unwrap(),
+            150_000
+        );
+        assert_eq!(out, &data[10..150_010]);
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_member_cursor() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let cursor = BinaryCursor::new(data);
+
+        let mut first = cursor.member_cursor(0, 4).unwrap();
+        let mut second = cursor.member_cursor(2, 4).unwrap();
+        assert_eq!(first.parse_u32_le().unwrap(), 0x04030201);
+        assert_eq!(second.parse_u32_le().unwrap(), 0x06050403);
+        assert!(first.parse_u8().is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_region_out_of_range() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let cursor = BinaryCursor::new(data);
+
+        let error = cursor.extract_region(2, 3).unwrap_err();
+        assert_eq!(error.fields().offset, Some(2));
+        assert_eq!(error.fields().requested, Some(3));
+        assert_eq!(error.fields().available, Some(2));
+
+        let mut out = vec![];
+        assert!(cursor.extract_region_to(5, 0, &mut out).is_err());
+        assert!(out.is_empty());
+        assert!(cursor.member_cursor(u64::MAX, 2).is_err());
     }
-}
 
-impl<'a, T> Drop for BinaryCursorJump<'a, T>
-where
-    T: AsRef<[u8]>,
-{
-    fn drop(&mut self) {
-        self.cursor.restore_location();
+    #[test]
+    fn test_checksums() {
+        let data = b"xx123456789yy";
+        let mut cursor = BinaryCursor::new(&data[..]);
+        cursor.set_position(11);
+
+        assert_eq!(cursor.crc32_of_range(2, 9).unwrap(), 0xCBF43926);
+        assert_eq!(cursor.crc32_of_range(0, 0).unwrap(), 0);
+        assert_eq!(cursor.sum_u8_of_range(2, 9).unwrap(), 0xDD);
+        assert_eq!(
+            cursor
+                .fold_range(2, 9, 0u32, |sum, byte| sum + byte as u32)
+                .unwrap(),
+            0x1DD
+        );
+        cursor.verify_crc32(2, 9, 0xCBF43926).unwrap();
+        assert_eq!(cursor.position(), 11);
     }
-}
-// endregion: CursorJump implementation
 
-// region: Tests
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_checksum_errors() {
+        let data = b"123456789";
+        let cursor = BinaryCursor::new(&data[..]);
+
+        let error = cursor.verify_crc32(0, 9, 0x12345678).unwrap_err();
+        assert_eq!(error.position(), Some(0));
+        assert_eq!(
+            error.fields().message,
+            "CRC-32 mismatch over bytes 0..9: expected 0x12345678, computed 0xCBF43926"
+        );
+
+        let error = cursor.crc32_of_range(5, 5).unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.fields().operation, Some("crc32_of_range"));
+        assert!(cursor.sum_u8_of_range(10, 0).is_err());
+        assert!(cursor.fold_range(u64::MAX, 2, 0, |a, _| a).is_err());
+        assert!(cursor.verify_crc32(8, 2, 0).is_err());
+    }
 
     #[test]
-    fn test_parse_u8() {
-        let data = vec![0x42, 0x43, 0x44];
+    fn test_read_bits_msb_first() {
+        let data = vec![0b1010_1100, 0b0101_0011, 0b1111_0000];
         let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_u8().unwrap(), 0x42);
-        assert_eq!(cursor.position(), 1);
+        let mut bits = cursor.bits(BitOrder::MsbFirst);
+
+        assert!(bits.read_bit().unwrap());
+        assert!(!bits.read_bit().unwrap());
+        assert_eq!(bits.read_bits(3).unwrap(), 0b101);
+        assert_eq!(bits.bit_position(), 5);
+
+        // Spans the first and second bytes
+        assert_eq!(bits.read_bits(5).unwrap(), 0b10001);
+        assert_eq!(bits.bit_position(), 10);
+
+        // Spans the second and third bytes
+        assert_eq!(bits.read_bits(13).unwrap(), 0b0100111111000);
+        assert_eq!(bits.read_bits(0).unwrap(), 0);
+        assert!(!bits.is_aligned());
     }
 
     #[test]
-    fn test_parse_u16_le() {
-        let data = vec![0x42, 0x24, 0x43, 0x25];
+    fn test_read_bits_lsb_first() {
+        let data = vec![0b1010_1100, 0b0101_0011];
         let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_u16_le().unwrap(), 0x2442);
-        assert_eq!(cursor.position(), 2);
+        let mut bits = cursor.bits(BitOrder::LsbFirst);
+
+        assert_eq!(bits.read_bits(3).unwrap(), 0b100);
+        assert_eq!(bits.read_bits(7).unwrap(), 0b1110101);
+        assert_eq!(bits.read_bits(6).unwrap(), 0b010100);
+        assert!(bits.is_aligned());
     }
 
     #[test]
-    fn test_parse_u32_le() {
-        let data = vec![0x42, 0x24, 0x00, 0x01, 0x43, 0x25, 0x01, 0x02];
-        let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_u32_le().unwrap(), 0x01002442);
-        assert_eq!(cursor.position(), 4);
+    fn test_read_bits_64() {
+        let data = vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xF0];
+        let mut cursor = BinaryCursor::new(&data);
+        let mut bits = cursor.bits(BitOrder::MsbFirst);
+        assert_eq!(bits.read_bits(64).unwrap(), 0x0123456789ABCDEF);
+        assert!(bits.read_bits(65).is_err());
+
+        let mut cursor = BinaryCursor::new(&data);
+        let mut bits = cursor.bits(BitOrder::MsbFirst);
+        assert_eq!(bits.read_bits(4).unwrap(), 0x0);
+        assert_eq!(bits.read_bits(64).unwrap(), 0x123456789ABCDEFF);
     }
 
     #[test]
-    fn test_parse_u64_le() {
-        let data = vec![
-            0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // 18446744073709551614
-            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // 18446744073709551615
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0
-        ];
+    fn test_bits_align() {
+        let data = vec![0xFF, 0x12, 0x34];
         let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_u64_le().unwrap(), 18446744073709551614);
-        assert_eq!(cursor.parse_u64_le().unwrap(), 18446744073709551615);
-        assert_eq!(cursor.parse_u64_le().unwrap(), 0);
-        assert_eq!(cursor.position(), 24);
+
+        {
+            let mut bits = cursor.bits(BitOrder::MsbFirst);
+            bits.align_to_byte();
+            assert_eq!(bits.bit_position(), 0);
+            assert_eq!(bits.read_bits(3).unwrap(), 0b111);
+            bits.align_to_byte();
+            assert!(bits.is_aligned());
+            assert_eq!(bits.bit_position(), 8);
+            assert_eq!(bits.read_bits(4).unwrap(), 0x1);
+        }
+
+        // Dropping the reader discards the rest of the partial byte
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x34);
     }
 
     #[test]
-    fn test_parse_f32_le() {
-        let data = vec![0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x40];
+    fn test_read_bits_eof() {
+        let data = vec![0xAB, 0xCD];
         let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_f32_le().unwrap(), 1.0);
-        assert_eq!(cursor.position(), 4);
+        let mut bits = cursor.bits(BitOrder::MsbFirst);
+
+        assert_eq!(bits.read_bits(4).unwrap(), 0xA);
+        let error = bits.read_bits(13).unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.fields().requested, Some(2));
+        assert_eq!(error.fields().available, Some(1));
+
+        // Nothing was consumed by the failed read
+        assert_eq!(bits.bit_position(), 4);
+        assert_eq!(bits.read_bits(12).unwrap(), 0xBCD);
+        assert!(bits.read_bit().is_err());
     }
 
     #[test]
-    fn test_parse_f64_le() {
+    fn test_parse_rev_trailer() {
         let data = vec![
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // 1.0
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, // 2.0
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xBF, // -1.0
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0.0
+            0xDE, 0xAD, // payload
+            0x07, 0x00, // u16 version = 7
+            0x02, 0x00, 0x00, 0x00, // u32 count = 2
+            0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // u64 index offset = 16
         ];
+        let len = data.len() as u64;
         let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_f64_le().unwrap(), 1.0);
-        assert_eq!(cursor.parse_f64_le().unwrap(), 2.0);
-        assert_eq!(cursor.parse_f64_le().unwrap(), -1.0);
-        assert_eq!(cursor.parse_f64_le().unwrap(), 0.0);
-        assert_eq!(cursor.position(), 32);
+        cursor.set_position(len);
+
+        assert_eq!(cursor.parse_u64_le_rev().unwrap(), 16);
+        assert_eq!(cursor.position(), 8);
+        assert_eq!(cursor.parse_u32_le_rev().unwrap(), 2);
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.parse_u16_le_rev().unwrap(), 7);
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.parse_bytes_rev(2).unwrap(), vec![0xDE, 0xAD]);
+        assert_eq!(cursor.position(), 0);
     }
 
     #[test]
-    fn test_parse_f64_le_error_handling() {
-        let data = vec![0x42];
+    fn test_parse_bytes_rev_hostile_count() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
         let mut cursor = BinaryCursor::new(data);
-        assert!(cursor.parse_f64_le().is_err());
+        cursor.set_position(3);
+
+        let error = cursor.parse_bytes_rev(usize::MAX).unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.fields().available, Some(3));
+        assert!(cursor.parse_bytes_rev(u32::MAX as usize).is_err());
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.parse_bytes_rev(3).unwrap(), vec![0x01, 0x02, 0x03]);
     }
 
     #[test]
-    fn test_parse_bytes() {
-        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+    fn test_parse_rev_mixed_directions() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
         let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_bytes(4).unwrap(), vec![0x01, 0x02, 0x03, 0x04]);
-        assert_eq!(cursor.position(), 4);
+        cursor.set_position(6);
+
+        let mut rev = cursor.rev_mode();
+        assert_eq!(rev.parse_u16_le().unwrap(), 0x0605);
+        assert_eq!(rev.parse_u8().unwrap(), 0x04);
+        assert_eq!(rev.cursor.parse_u8().unwrap(), 0x04);
+        assert_eq!(rev.cursor.position(), 4);
+        assert_eq!(rev.parse_u32_le().unwrap(), 0x04030201);
+        assert_eq!(rev.parse_bytes(0).unwrap(), Vec::<u8>::new());
+
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0201);
     }
 
     #[test]
-    fn test_location_stack() {
-        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+    fn test_parse_rev_underflow() {
+        let data = vec![0x01, 0x02, 0x03];
         let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(3);
 
-        cursor.push_location();
-        cursor.set_position(4);
-        assert_eq!(cursor.position(), 4);
+        assert!(cursor.parse_u32_le_rev().is_err());
+        assert_eq!(cursor.position(), 3);
+        assert!(cursor.rev_mode().parse_u64_le().is_err());
+        assert_eq!(cursor.position(), 3);
 
-        assert!(cursor.restore_location());
-        assert_eq!(cursor.position(), 0);
+        cursor.set_position(10);
+        assert!(cursor.parse_u16_le_rev().is_err());
+        assert_eq!(cursor.position(), 10);
     }
 
     #[test]
-    fn test_binary_cursor_jump() {
-        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+    fn test_sub_cursor() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
         let mut cursor = BinaryCursor::new(data);
+        cursor.parse_u8().unwrap();
 
         {
-            let mut jump = BinaryCursorJump::new(&mut cursor);
-            jump.jump(4).unwrap();
-            assert_eq!(jump.cursor.position(), 4);
+            let mut window = cursor.sub_cursor(3).unwrap();
+            assert_eq!(window.position(), 0);
+            assert_eq!(window.len(), 3);
+            assert_eq!(window.parse_u16_le().unwrap(), 0x0302);
+            assert!(window.parse_u16_le().is_err());
         }
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x05);
 
-        assert_eq!(cursor.position(), 0u64);
+        assert!(cursor.sub_cursor(1).is_err());
+        assert_eq!(cursor.position(), 5);
+        assert!(cursor.sub_cursor(0).unwrap().at_end());
     }
 
     #[test]
-    fn test_sequential_parsing() {
-        let data = vec![0x42, 0x24, 0x00, 0x01, 0x43, 0x25, 0x01, 0x02];
+    fn test_sub_cursor_nested() {
+        // An outer chunk of 6 bytes containing a 2-byte header and a 3-byte inner chunk
+        let data = vec![0x06, 0xAA, 0x03, 0x01, 0x02, 0x03, 0xBB, 0xCC];
         let mut cursor = BinaryCursor::new(data);
 
-        assert_eq!(cursor.parse_u8().unwrap(), 0x42);
-        assert_eq!(cursor.position(), 1);
+        let len = cursor.parse_u8().unwrap() as u64;
+        let mut outer = cursor.sub_cursor(len).unwrap();
+        assert_eq!(outer.parse_u8().unwrap(), 0xAA);
 
-        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0024);
-        assert_eq!(cursor.position(), 3);
+        let inner_len = outer.parse_u8().unwrap() as u64;
+        let mut inner = outer.sub_cursor(inner_len).unwrap();
+        assert_eq!(inner.parse_bytes(3).unwrap(), vec![0x01, 0x02, 0x03]);
+        assert!(inner.parse_u8().is_err());
+
+        assert_eq!(outer.position(), 5);
+        assert_eq!(outer.parse_u8().unwrap(), 0xBB);
+        assert!(outer.parse_u8().is_err());
+        let error = outer.sub_cursor(1).unwrap_err();
+        assert_eq!(error.fields().operation, Some("sub_cursor"));
 
-        assert_eq!(cursor.parse_u32_le().unwrap(), 0x01254301);
         assert_eq!(cursor.position(), 7);
+        assert_eq!(cursor.parse_u8().unwrap(), 0xCC);
+    }
 
-        assert!(cursor.parse_u8().is_ok());
-        assert_eq!(cursor.position(), 8);
+    #[test]
+    fn test_remaining_and_len() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.len(), 4);
+        assert_eq!(cursor.remaining(), 4);
+        assert!(!cursor.is_empty());
+        assert!(!cursor.at_end());
+
+        cursor.parse_u8().unwrap();
+        assert_eq!(cursor.remaining(), 3);
+        assert!(!cursor.at_end());
+
+        cursor.parse_bytes(3).unwrap();
+        assert_eq!(cursor.remaining(), 0);
+        assert!(cursor.at_end());
+        assert!(!cursor.is_empty());
+
+        cursor.set_position(100);
+        assert_eq!(cursor.len(), 4);
+        assert_eq!(cursor.remaining(), 0);
+        assert!(cursor.at_end());
     }
 
     #[test]
-    fn test_count() {
+    fn test_remaining_empty_data() {
+        let cursor = BinaryCursor::new(Vec::<u8>::new());
+        assert_eq!(cursor.len(), 0);
+        assert_eq!(cursor.remaining(), 0);
+        assert!(cursor.is_empty());
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_skip() {
         let data = vec![0x01, 0x02, 0x03, 0x04];
         let mut cursor = BinaryCursor::new(data);
-        let result = cursor.count(|c| c.parse_u8(), 4).unwrap();
-        assert_eq!(result, vec![0x01, 0x02, 0x03, 0x04]);
+
+        cursor.skip(0).unwrap();
+        assert_eq!(cursor.position(), 0);
+        cursor.skip(1).unwrap();
+        assert_eq!(cursor.parse_u8().unwrap(), 0x02);
+        cursor.skip(2).unwrap();
         assert_eq!(cursor.position(), 4);
+        cursor.skip(0).unwrap();
     }
 
     #[test]
-    fn test_pop_location() {
+    fn test_skip_past_end() {
         let data = vec![0x01, 0x02, 0x03, 0x04];
         let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.pop_location(), None);
+        cursor.set_position(1);
 
-        cursor.push_location();
-        cursor.set_position(2);
-        assert_eq!(cursor.pop_location(), Some(0));
-        assert_eq!(cursor.position(), 2);
+        let error = cursor.skip(4).unwrap_err();
+        let fields = error.fields();
+        assert_eq!(fields.kind, "unexpected_eof");
+        assert_eq!(fields.operation, Some("skip"));
+        assert_eq!(fields.requested, Some(4));
+        assert_eq!(fields.available, Some(3));
+        assert_eq!(cursor.position(), 1);
+
+        assert!(cursor.skip(u64::MAX).is_err());
+        cursor.set_position(10);
+        assert!(cursor.skip(0).is_err());
+        assert_eq!(cursor.position(), 10);
     }
 
     #[test]
-    fn test_error_handling() {
-        let data = vec![0x42];
+    fn test_align() {
+        let data = vec![0u8; 16];
         let mut cursor = BinaryCursor::new(data);
 
-        assert!(cursor.parse_u16_le().is_err());
-        assert!(cursor.parse_u32_le().is_err());
-        assert!(cursor.parse_f32_le().is_err());
-        assert!(cursor.parse_bytes(2).is_err());
+        cursor.align(4).unwrap();
+        assert_eq!(cursor.position(), 0);
+        for (start, alignment, expected) in [
+            (1, 4, 4),
+            (3, 4, 4),
+            (4, 4, 4),
+            (5, 8, 8),
+            (9, 8, 16),
+            (7, 1, 7),
+        ] {
+            cursor.set_position(start);
+            cursor.align(alignment).unwrap();
+            assert_eq!(cursor.position(), expected);
+        }
     }
 
     #[test]
-    fn test_restore_location() {
-        let data = vec![0x01, 0x02, 0x03, 0x04];
+    fn test_align_errors() {
+        let data = vec![0u8; 6];
         let mut cursor = BinaryCursor::new(data);
+        cursor.set_position(5);
 
-        cursor.push_location();
-        cursor.set_position(2);
-        assert_eq!(cursor.position(), 2);
-
-        assert!(cursor.restore_location());
-        assert_eq!(cursor.position(), 0);
+        assert!(matches!(
+            cursor.align(0).unwrap_err(),
+            BinaryCursorError::ParseError(_)
+        ));
+        let error = cursor.align(4).unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.fields().operation, Some("align"));
+        assert_eq!(cursor.position(), 5);
+    }
 
-        assert!(!cursor.restore_location());
+    #[test]
+    fn test_builder_defaults() {
+        let cursor = BinaryCursorBuilder::new().build(vec![0x01]).unwrap();
+        assert_eq!(*cursor.options(), CursorOptions::default());
+        assert_eq!(
+            *BinaryCursor::new(vec![0x01]).options(),
+            CursorOptions::default()
+        );
     }
 
     #[test]
-    fn test_error_conversion() {
-        use std::io::{Error, ErrorKind};
-        let io_error = Error::new(ErrorKind::UnexpectedEof, "test error");
-        let cursor_error = BinaryCursorError::from_io_error(io_error);
-        match cursor_error {
-            BinaryCursorError::ParseError(_) => (),
-        }
+    fn test_builder_endianness() {
+        let data = vec![0x01, 0x02, 0x00, 0x00, 0x00, 0x03, 0xFF, 0xFE];
+        let mut cursor = BinaryCursor::new(&data);
+        assert_eq!(cursor.parse_u16().unwrap(), 0x0201);
+
+        let mut cursor = BinaryCursorBuilder::new()
+            .endianness(Endianness::Big)
+            .build(&data)
+            .unwrap();
+        assert_eq!(cursor.parse_u16().unwrap(), 0x0102);
+        assert_eq!(cursor.parse_u32().unwrap(), 3);
+        assert_eq!(cursor.parse_i16().unwrap(), -2);
+        // Explicitly little-endian methods are unaffected
+        cursor.set_position(0);
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0201);
     }
 
     #[test]
-    fn test_jump_relative() {
-        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
-        let mut cursor = BinaryCursor::new(data);
-        cursor.set_position(2);
+    fn test_with_endianness() {
+        let data = vec![0x00, 0x02, 0x03, 0x00, 0x00];
+        let mut cursor = BinaryCursorBuilder::new()
+            .endianness(Endianness::Big)
+            .build(&data)
+            .unwrap();
 
-        {
-            let mut jump = BinaryCursorJump::new(&mut cursor);
-            jump.jump_relative(2).unwrap();
-            assert_eq!(jump.cursor.position(), 4);
-        }
-        assert_eq!(cursor.position(), 2);
+        let value = cursor
+            .with_endianness(Endianness::Little, |c| {
+                c.set_position(2);
+                c.parse_u16()
+            })
+            .unwrap();
+        assert_eq!(value, 3);
+        assert_eq!(cursor.options().endianness, Endianness::Big);
 
-        {
-            let mut jump = BinaryCursorJump::new(&mut cursor);
-            jump.jump_relative(-1).unwrap();
-            assert_eq!(jump.cursor.position(), 1);
-        }
-        assert_eq!(cursor.position(), 2);
+        assert!(
+            cursor
+                .with_endianness(Endianness::Little, |c| c.parse_u32())
+                .is_err()
+        );
+        assert_eq!(cursor.options().endianness, Endianness::Big);
     }
 
     #[test]
-    fn test_jump_relative_overflow() {
-        let data = vec![0x01, 0x02];
-        let mut cursor = BinaryCursor::new(data);
-        cursor.set_position(1);
+    fn test_builder_strict_varints() {
+        let data = vec![0x80, 0x00];
+        let mut lenient = BinaryCursor::new(&data);
+        assert_eq!(lenient.parse_uleb128().unwrap(), 0);
+        lenient.set_position(0);
+        assert_eq!(lenient.parse_vlq().unwrap(), 0);
 
-        {
-            let mut jump = BinaryCursorJump::new(&mut cursor);
-            assert!(jump.jump_relative(-2).is_err());
+        let mut strict = BinaryCursorBuilder::new()
+            .strict(true)
+            .build(&data)
+            .unwrap();
+        let error = strict.parse_uleb128().unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(strict.position(), 0);
+        assert!(strict.parse_vlq().is_err());
+        assert!(strict.parse_sleb128().is_err());
+
+        // Minimal encodings are still accepted, including ones whose last byte is 0x00 or
+        // 0x7F because it carries the sign
+        for (bytes, expected) in [
+            (&[0xFF, 0x00][..], 127),
+            (&[0x80, 0x7F], -128),
+            (&[0x7F], -1),
+        ] {
+            let mut strict = BinaryCursorBuilder::new()
+                .strict(true)
+                .build(bytes)
+                .unwrap();
+            assert_eq!(strict.parse_sleb128().unwrap(), expected);
         }
-        assert_eq!(cursor.position(), 1);
+        let mut strict = BinaryCursorBuilder::new()
+            .strict(true)
+            .build(&[0xFF, 0x7F, 0x7F][..])
+            .unwrap();
+        assert!(strict.parse_sleb128().is_err());
+        let mut strict = BinaryCursorBuilder::new()
+            .strict(true)
+            .build(&[0x80, 0x01][..])
+            .unwrap();
+        assert_eq!(strict.parse_uleb128().unwrap(), 128);
     }
 
     #[test]
-    fn test_parse_i8() {
-        let data = vec![0xFE, 0x7F, 0x80];
-        let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_i8().unwrap(), -2);
-        assert_eq!(cursor.parse_i8().unwrap(), 127);
-        assert_eq!(cursor.parse_i8().unwrap(), -128);
-        assert_eq!(cursor.position(), 3);
+    fn test_builder_max_alloc() {
+        let data = vec![0u8; 16];
+        let mut cursor = BinaryCursorBuilder::new()
+            .max_alloc(4)
+            .build(&data)
+            .unwrap();
+
+        assert_eq!(cursor.parse_bytes(4).unwrap().len(), 4);
+        let error = cursor.parse_bytes(5).unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.fields().offset, Some(4));
+        assert_eq!(cursor.position(), 4);
+
+        assert!(cursor.parse_bytes_rev(5).is_err());
+        assert!(cursor.extract_region(0, 8).is_err());
+        // Member cursors inherit the limit
+        let mut member = cursor.member_cursor(0, 8).unwrap();
+        assert!(member.parse_bytes(8).is_err());
     }
 
     #[test]
-    fn test_parse_i16_le() {
-        let data = vec![0xFE, 0xFF, 0xFF, 0x7F, 0x00, 0x80];
-        let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_i16_le().unwrap(), -2);
-        assert_eq!(cursor.parse_i16_le().unwrap(), 32767);
-        assert_eq!(cursor.parse_i16_le().unwrap(), -32768);
-        assert_eq!(cursor.position(), 6);
+    fn test_builder_depth_limit() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let mut cursor = BinaryCursorBuilder::new()
+            .depth_limit(1)
+            .build(&data)
+            .unwrap();
+
+        {
+            let mut jump = BinaryCursorJump::new(&mut cursor);
+            jump.jump(2).unwrap();
+            let error = jump.jump(3).unwrap_err();
+            assert_eq!(error.fields().kind, "invalid_data");
+            assert!(jump.jump_relative(1).is_err());
+            assert_eq!(jump.cursor.parse_u8().unwrap(), 0x03);
+        }
+        assert_eq!(cursor.position(), 0);
+
+        // The limit only applies to jumps
+        cursor.push_location();
+        cursor.push_location();
+        assert_eq!(cursor.pop_location(), Some(0));
     }
 
     #[test]
-    fn test_parse_i32_le() {
-        let data = vec![
-            0xFE, 0xFF, 0xFF, 0xFF, // -2
-            0xFF, 0xFF, 0xFF, 0x7F, // 2147483647
-            0x00, 0x00, 0x00, 0x80, // -2147483648
-        ];
-        let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_i32_le().unwrap(), -2);
-        assert_eq!(cursor.parse_i32_le().unwrap(), 2147483647);
-        assert_eq!(cursor.parse_i32_le().unwrap(), -2147483648);
-        assert_eq!(cursor.position(), 12);
+    fn test_builder_enforce_alignment() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let mut cursor = BinaryCursorBuilder::new()
+            .enforce_alignment(true)
+            .build(&data)
+            .unwrap();
+
+        assert_eq!(cursor.parse_u8().unwrap(), 0x01);
+        let error = cursor.parse_u16_le().unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x02);
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0403);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x05);
+        assert!(cursor.parse_u32().is_err());
+        assert_eq!(cursor.parse_bytes(1).unwrap(), vec![0x06]);
     }
 
     #[test]
-    fn test_parse_i64_le() {
-        let data = vec![
-            0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // -2
-            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, // 9223372036854775807
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, // -9223372036854775808
-        ];
-        let mut cursor = BinaryCursor::new(data);
-        assert_eq!(cursor.parse_i64_le().unwrap(), -2);
-        assert_eq!(cursor.parse_i64_le().unwrap(), 9223372036854775807);
-        assert_eq!(cursor.parse_i64_le().unwrap(), -9223372036854775808);
-        assert_eq!(cursor.position(), 24);
+    fn test_timestamps_enforce_alignment() {
+        let mut data = vec![0u8; 2];
+        data.extend_from_slice(&[0x09, 0x74, 0x97, 0x4F]);
+        data.extend_from_slice(&[0u8; 2]);
+        data.extend_from_slice(&125_911_584_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        let mut cursor = BinaryCursorBuilder::new()
+            .enforce_alignment(true)
+            .build(&data)
+            .unwrap();
+
+        for offset in [1, 3] {
+            cursor.set_position(offset);
+            let error = cursor.parse_dos_datetime_le().unwrap_err();
+            assert_eq!(error.fields().operation, Some("parse_dos_datetime_le"));
+            assert_eq!(cursor.position(), offset);
+        }
+        cursor.set_position(2);
+        assert_eq!(cursor.parse_dos_datetime_le().unwrap().year, 2019);
+
+        cursor.set_position(2);
+        assert!(cursor.parse_unix_timestamp_u32_le().is_err());
+        cursor.set_position(4);
+        assert!(cursor.parse_unix_timestamp_u32_le().is_ok());
+
+        cursor.set_position(4);
+        assert!(cursor.parse_filetime_le().is_err());
+        assert!(cursor.parse_unix_timestamp_u64_le().is_err());
+        assert_eq!(cursor.position(), 4);
+        cursor.set_position(8);
+        assert_eq!(
+            cursor.parse_filetime_le().unwrap(),
+            UNIX_EPOCH + Duration::from_secs(946_684_800)
+        );
+        assert_eq!(
+            cursor.parse_unix_timestamp_u64_le().unwrap(),
+            UNIX_EPOCH + Duration::from_secs(7)
+        );
     }
 
     #[test]
-    fn test_signed_integer_error_handling() {
-        let data = vec![0x42];
-        let mut cursor = BinaryCursor::new(data);
+    fn test_builder_combined_and_invalid() {
+        let data = vec![0x00, 0x00, 0x00, 0x02, 0x80, 0x00];
+        let mut cursor = BinaryCursorBuilder::new()
+            .endianness(Endianness::Big)
+            .strict(true)
+            .enforce_alignment(true)
+            .max_alloc(2)
+            .build(&data)
+            .unwrap();
+        assert_eq!(cursor.parse_u32().unwrap(), 2);
+        assert!(cursor.parse_uleb128().is_err());
+        assert!(cursor.parse_bytes(3).is_err());
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0080);
 
-        assert!(cursor.parse_i16_le().is_err());
-        assert!(cursor.parse_i32_le().is_err());
-        assert!(cursor.parse_u64_le().is_err());
-        assert!(cursor.parse_i64_le().is_err());
+        assert!(
+            BinaryCursorBuilder::new()
+                .max_alloc(0)
+                .build(&data)
+                .is_err()
+        );
+        assert!(
+            BinaryCursorBuilder::new()
+                .depth_limit(0)
+                .build(&data)
+                .is_err()
+        );
     }
 }
 // endregion: Tests
@@ -0,0 +1,445 @@
+//! A binary writer that mirrors the `BinaryCursor` parsing interface.
+//!
+//! `BinaryWriter` wraps a `std::io::Cursor<T>` and provides `write_*` methods matching the
+//! cursor's `parse_*` methods, together with the same location stack and an RAII jump guard,
+//! `BinaryWriterJump`. This makes it straightforward to re-serialize data parsed with a
+//! `BinaryCursor`, including patching a length field once the body it describes has been
+//! written.
+//!
+//! Writers over a `Vec<u8>` grow as needed; writing past the current end, for example after
+//! a jump, fills the gap with zeros. Writers over a `&mut [u8]` cannot grow, and a write that
+//! does not fit is an error.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursor_binary_parser::binary_writer::{BinaryWriter, BinaryWriterJump};
+//!
+//! let mut writer = BinaryWriter::new(vec![]);
+//! writer.write_u16_le(0).unwrap(); // length placeholder
+//! writer.write_bytes(b"body").unwrap();
+//!
+//! let length = writer.position() as u16 - 2;
+//! {
+//!     let mut jump = BinaryWriterJump::new(&mut writer);
+//!     jump.jump(0).unwrap();
+//!     jump.writer.write_u16_le(length).unwrap();
+//! }
+//! assert_eq!(writer.into_inner(), b"\x04\x00body");
+//! ```
+
+use crate::binary_cursor::BinaryCursorError;
+use std::io::{Cursor, Write};
+
+// region: Writer implementation
+/// A cursor-like interface for writing binary data
+///
+/// See the [module documentation](self) for how the supported buffer types behave.
+#[derive(Debug)]
+pub struct BinaryWriter<T>
+where
+    Cursor<T>: Write,
+{
+    /// The underlying cursor containing the binary data
+    pub data: Cursor<T>,
+    /// Stack of saved positions for temporary jumps
+    location_stack: Vec<u64>,
+}
+
+impl<T> BinaryWriter<T>
+where
+    Cursor<T>: Write,
+{
+    /// Creates a new `BinaryWriter` that writes into `data` from position 0
+    pub fn new(data: T) -> Self {
+        Self {
+            data: Cursor::new(data),
+            location_stack: vec![],
+        }
+    }
+
+    /// Consumes the writer, returning the underlying buffer
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+
+    /// Saves the current position to the location stack
+    pub fn push_location(&mut self) {
+        self.location_stack.push(self.data.position());
+    }
+
+    /// Removes and returns the most recently saved position from the location stack
+    pub fn pop_location(&mut self) -> Option<u64> {
+        self.location_stack.pop()
+    }
+
+    /// Restores the most recently saved position from the location stack
+    ///
+    /// Returns `true` if a position was restored, `false` if the stack was empty
+    pub fn restore_location(&mut self) -> bool {
+        if let Some(pos) = self.location_stack.pop() {
+            self.data.set_position(pos);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the current position in the data stream
+    pub fn position(&self) -> u64 {
+        self.data.position()
+    }
+
+    /// Sets the current position in the data stream
+    pub fn set_position(&mut self, pos: u64) {
+        self.data.set_position(pos);
+    }
+
+    /// Writes a u8 at the current position
+    pub fn write_u8(&mut self, value: u8) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&[value])
+    }
+
+    /// Writes an i8 at the current position
+    pub fn write_i8(&mut self, value: i8) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes a u16 in little-endian format at the current position
+    pub fn write_u16_le(&mut self, value: u16) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes a u16 in big-endian format at the current position
+    pub fn write_u16_be(&mut self, value: u16) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes a u32 in little-endian format at the current position
+    pub fn write_u32_le(&mut self, value: u32) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes a u32 in big-endian format at the current position
+    pub fn write_u32_be(&mut self, value: u32) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes a u64 in little-endian format at the current position
+    pub fn write_u64_le(&mut self, value: u64) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes a u64 in big-endian format at the current position
+    pub fn write_u64_be(&mut self, value: u64) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes an i16 in little-endian format at the current position
+    pub fn write_i16_le(&mut self, value: i16) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes an i16 in big-endian format at the current position
+    pub fn write_i16_be(&mut self, value: i16) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes an i32 in little-endian format at the current position
+    pub fn write_i32_le(&mut self, value: i32) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes an i32 in big-endian format at the current position
+    pub fn write_i32_be(&mut self, value: i32) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes an i64 in little-endian format at the current position
+    pub fn write_i64_le(&mut self, value: i64) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes an i64 in big-endian format at the current position
+    pub fn write_i64_be(&mut self, value: i64) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes an f32 in little-endian format at the current position
+    pub fn write_f32_le(&mut self, value: f32) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes an f32 in big-endian format at the current position
+    pub fn write_f32_be(&mut self, value: f32) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes an f64 (double precision) in little-endian format at the current position
+    pub fn write_f64_le(&mut self, value: f64) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes an f64 (double precision) in big-endian format at the current position
+    pub fn write_f64_be(&mut self, value: f64) -> Result<(), BinaryCursorError> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes `bytes` at the current position
+    ///
+    /// If the buffer cannot hold all of them, an error is returned and the position is left
+    /// unchanged, although the bytes that did fit may have been overwritten.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), BinaryCursorError> {
+        let start = self.data.position();
+        if let Err(e) = self.data.write_all(bytes) {
+            self.data.set_position(start);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+}
+// endregion: Writer implementation
+
+// region: WriterJump implementation
+/// A RAII guard for temporary position changes in a BinaryWriter
+///
+/// This type automatically restores the writer's position when dropped, making it safe
+/// to temporarily change position without worrying about restoring it manually.
+///
+/// As with [`BinaryCursorJump`](crate::binary_cursor::BinaryCursorJump), a guard can jump
+/// any number of times, and on drop restores the position from before its first jump,
+/// leaving the location stack as deep as when the guard was created.
+pub struct BinaryWriterJump<'a, T>
+where
+    Cursor<T>: Write,
+{
+    /// The writer being operated on
+    pub writer: &'a mut BinaryWriter<T>,
+    /// The depth of the location stack when the guard was created
+    depth: usize,
+}
+
+impl<'a, T> BinaryWriterJump<'a, T>
+where
+    Cursor<T>: Write,
+{
+    /// Creates a new `BinaryWriterJump` for the given writer
+    pub fn new(writer: &'a mut BinaryWriter<T>) -> Self {
+        let depth = writer.location_stack.len();
+        Self { writer, depth }
+    }
+
+    /// Temporarily jumps to the specified position
+    ///
+    /// The position will be automatically restored when the `BinaryWriterJump` is dropped.
+    pub fn jump(&mut self, location: u64) -> Result<(), BinaryCursorError> {
+        self.writer.push_location();
+        self.writer.set_position(location);
+        Ok(())
+    }
+
+    /// Temporarily jumps to a position relative to the current writer location
+    ///
+    /// The position will be automatically restored when the `BinaryWriterJump` is dropped.
+    /// A positive offset moves forward, while a negative offset moves backward. If the
+    /// target would overflow or underflow, an error is returned and nothing is saved.
+    pub fn jump_relative(&mut self, offset: i64) -> Result<(), BinaryCursorError> {
+        let current_pos = self.writer.position();
+        let new_pos = if offset >= 0 {
+            current_pos.checked_add(offset as u64)
+        } else {
+            current_pos.checked_sub(offset.unsigned_abs())
+        }
+        .ok_or_else(|| {
+            BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Position would overflow/underflow",
+            ))
+        })?;
+        self.writer.push_location();
+        self.writer.set_position(new_pos);
+        Ok(())
+    }
+}
+
+impl<'a, T> Drop for BinaryWriterJump<'a, T>
+where
+    Cursor<T>: Write,
+{
+    fn drop(&mut self) {
+        // Only restore if this guard saved a position, and then to the position from before
+        // its first jump, as for `BinaryCursorJump`
+        if self.writer.location_stack.len() > self.depth {
+            self.writer.location_stack.truncate(self.depth + 1);
+            self.writer.restore_location();
+        }
+    }
+}
+// endregion: WriterJump implementation
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_cursor::BinaryCursor;
+
+    #[test]
+    fn test_write_primitives() {
+        let mut writer = BinaryWriter::new(vec![]);
+        writer.write_u8(0x01).unwrap();
+        writer.write_i8(-1).unwrap();
+        writer.write_u16_le(0x0302).unwrap();
+        writer.write_u16_be(0x0405).unwrap();
+        writer.write_u32_le(0x09080706).unwrap();
+        writer.write_i32_be(-2).unwrap();
+        assert_eq!(writer.position(), 14);
+        assert_eq!(
+            writer.into_inner(),
+            vec![
+                0x01, 0xFF, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0xFF, 0xFF, 0xFF, 0xFE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip_with_cursor() {
+        let mut writer = BinaryWriter::new(vec![]);
+        writer.write_u64_le(u64::MAX - 1).unwrap();
+        writer.write_i64_le(i64::MIN).unwrap();
+        writer.write_i16_le(-300).unwrap();
+        writer.write_f32_le(1.5).unwrap();
+        writer.write_f64_le(-0.25).unwrap();
+        writer.write_bytes(b"tail").unwrap();
+        let bytes = writer.into_inner();
+
+        let mut cursor = BinaryCursor::new(&bytes);
+        assert_eq!(cursor.parse_u64_le().unwrap(), u64::MAX - 1);
+        assert_eq!(cursor.parse_i64_le().unwrap(), i64::MIN);
+        assert_eq!(cursor.parse_i16_le().unwrap(), -300);
+        assert_eq!(cursor.parse_f32_le().unwrap(), 1.5);
+        assert_eq!(cursor.parse_f64_le().unwrap(), -0.25);
+        assert_eq!(cursor.parse_bytes(4).unwrap(), b"tail");
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_patch_length_with_jump() {
+        let mut writer = BinaryWriter::new(vec![]);
+        writer.write_bytes(b"CHNK").unwrap();
+        let length_at = writer.position();
+        writer.write_u32_be(0).unwrap();
+        writer.write_bytes(&[0xAA; 5]).unwrap();
+
+        let length = (writer.position() - length_at - 4) as u32;
+        {
+            let mut jump = BinaryWriterJump::new(&mut writer);
+            jump.jump(length_at).unwrap();
+            jump.writer.write_u32_be(length).unwrap();
+        }
+        assert_eq!(writer.position(), 13);
+        writer.write_u8(0xBB).unwrap();
+
+        let bytes = writer.into_inner();
+        let mut cursor = BinaryCursor::new(&bytes);
+        cursor.expect_tag(b"CHNK").unwrap();
+        assert_eq!(
+            cursor
+                .parse_uint(4, crate::binary_cursor::Endianness::Big)
+                .unwrap(),
+            5
+        );
+        assert_eq!(bytes.len(), 14);
+    }
+
+    #[test]
+    fn test_vec_grows_past_end() {
+        let mut writer = BinaryWriter::new(vec![0x01]);
+        {
+            let mut jump = BinaryWriterJump::new(&mut writer);
+            jump.jump_relative(3).unwrap();
+            jump.writer.write_u8(0x02).unwrap();
+        }
+        assert_eq!(writer.position(), 0);
+        assert!(
+            BinaryWriterJump::new(&mut writer)
+                .jump_relative(-1)
+                .is_err()
+        );
+        assert_eq!(writer.into_inner(), vec![0x01, 0x00, 0x00, 0x02]);
+    }
+
+    #[test]
+    fn test_fixed_slice_overflow() {
+        let mut buffer = [0u8; 6];
+        let mut writer = BinaryWriter::new(&mut buffer[..]);
+        writer.write_u32_le(0x04030201).unwrap();
+
+        let error = writer.write_u32_le(0x08070605).unwrap_err();
+        assert!(matches!(error, BinaryCursorError::ParseError(_)));
+        assert_eq!(writer.position(), 4);
+        writer.write_u16_le(0x0605).unwrap();
+        assert!(writer.write_u8(0x07).is_err());
+
+        drop(writer);
+        assert_eq!(buffer, [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+    }
+
+    #[test]
+    fn test_jump_multi_hop() {
+        let mut writer = BinaryWriter::new(vec![0u8; 6]);
+        writer.set_position(1);
+
+        {
+            let mut jump = BinaryWriterJump::new(&mut writer);
+            jump.jump(4).unwrap();
+            jump.writer.write_u8(0xAA).unwrap();
+            jump.jump(2).unwrap();
+            jump.writer.write_u8(0xBB).unwrap();
+        }
+        assert_eq!(writer.position(), 1);
+        assert_eq!(writer.pop_location(), None);
+        assert_eq!(writer.into_inner(), [0x00, 0x00, 0xBB, 0x00, 0xAA, 0x00]);
+    }
+
+    #[test]
+    fn test_failed_jump_keeps_outer_location() {
+        let mut writer = BinaryWriter::new(vec![0u8; 4]);
+        writer.set_position(2);
+        writer.push_location();
+        writer.set_position(3);
+
+        {
+            let mut jump = BinaryWriterJump::new(&mut writer);
+            assert!(jump.jump_relative(-10).is_err());
+        }
+        assert_eq!(writer.position(), 3);
+        assert_eq!(writer.pop_location(), Some(2));
+
+        // A failed hop after a successful one still unwinds to the start
+        {
+            let mut jump = BinaryWriterJump::new(&mut writer);
+            jump.jump(1).unwrap();
+            jump.jump_relative(1).unwrap();
+            assert!(jump.jump_relative(-10).is_err());
+        }
+        assert_eq!(writer.position(), 3);
+        assert_eq!(writer.pop_location(), None);
+    }
+
+    #[test]
+    fn test_location_stack() {
+        let mut writer = BinaryWriter::new(vec![]);
+        writer.write_u16_le(0).unwrap();
+        writer.push_location();
+        writer.write_u16_le(0).unwrap();
+        assert_eq!(writer.pop_location(), Some(2));
+        assert!(!writer.restore_location());
+
+        writer.push_location();
+        writer.set_position(0);
+        writer.write_u8(0xFF).unwrap();
+        assert!(writer.restore_location());
+        assert_eq!(writer.position(), 4);
+    }
+}
+// endregion: Tests
@@ -0,0 +1,244 @@
+//! Decompression of embedded compressed regions into new cursors.
+//!
+//! Each codec is gated behind its own cargo feature (`zstd`, `lz4`). The helpers read a
+//! declared number of compressed bytes from the current position, decompress them into a
+//! new owned `BinaryCursor<Vec<u8>>` with the same options, and advance the outer cursor past
+//! the compressed bytes.
+//!
+//! Errors are split into two groups so callers can tell them apart:
+//! - Truncation: the compressed region extends past the end of the data. This is reported
+//!   as `BinaryCursorError::UnexpectedEof`.
+//! - Corruption: the region is present but the codec rejects its contents (including a
+//!   stream that ends early), the output does not match the declared size, or the output
+//!   would exceed the caller's limit. These are reported as `BinaryCursorError::InvalidData`.
+//!
+//! On any error the outer cursor's position is left unchanged.
+
+use crate::binary_cursor::{BinaryCursor, BinaryCursorError};
+
+/// The largest expansion ratio an LZ4 block can achieve, used to reject impossible sizes
+/// before allocating the output buffer
+#[cfg(feature = "lz4")]
+const LZ4_MAX_RATIO: u64 = 255;
+
+impl<T> BinaryCursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Decompresses a Zstandard frame of `compressed_len` bytes into a new cursor
+    ///
+    /// Decompression stops with an error as soon as the output would exceed `limit` bytes,
+    /// so a small malicious frame cannot expand into an arbitrarily large allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let compressed = zstd::encode_all(&[0x01, 0x02, 0x03][..], 0).unwrap();
+    /// let mut cursor = BinaryCursor::new(&compressed);
+    ///
+    /// let mut inner = cursor
+    ///     .parse_zstd_block(compressed.len() as u64, 1024)
+    ///     .unwrap();
+    /// assert_eq!(inner.parse_u8().unwrap(), 0x01);
+    /// assert_eq!(cursor.position(), compressed.len() as u64);
+    /// ```
+    #[cfg(feature = "zstd")]
+    pub fn parse_zstd_block(
+        &mut self,
+        compressed_len: u64,
+        limit: u64,
+    ) -> Result<BinaryCursor<Vec<u8>>, BinaryCursorError> {
+        self.traced("parse_zstd_block", |cursor| {
+            use std::io::Read;
+
+            let start = cursor.data.position();
+            let region = cursor.region(start, compressed_len, "parse_zstd_block")?;
+            let corrupt = |message: String| BinaryCursorError::InvalidData {
+                operation: "parse_zstd_block",
+                position: start,
+                message,
+            };
+
+            let decoder = zstd::stream::read::Decoder::with_buffer(region)
+                .map_err(|e| corrupt(e.to_string()))?;
+            let mut output = vec![];
+            decoder
+                .take(limit.saturating_add(1))
+                .read_to_end(&mut output)
+                .map_err(|e| corrupt(e.to_string()))?;
+
+            if output.len() as u64 > limit {
+                return Err(corrupt(format!(
+                    "decompressed output exceeds limit of {} bytes",
+                    limit
+                )));
+            }
+
+            cursor
+                .data
+                .set_position(cursor.data.position() + compressed_len);
+            Ok(BinaryCursor::with_options(output, *cursor.options()))
+        })
+    }
+
+    /// Decompresses a raw LZ4 block of `compressed_len` bytes into a new cursor
+    ///
+    /// LZ4 blocks do not record their own decompressed size, so it must be supplied by the
+    /// caller, typically from a header field. A `decompressed_len` larger than the best
+    /// possible LZ4 compression ratio allows is rejected before any allocation, and output
+    /// that does not match `decompressed_len` exactly is treated as corruption.
+    #[cfg(feature = "lz4")]
+    pub fn parse_lz4_block(
+        &mut self,
+        compressed_len: u64,
+        decompressed_len: u64,
+    ) -> Result<BinaryCursor<Vec<u8>>, BinaryCursorError> {
+        self.traced("parse_lz4_block", |cursor| {
+            let start = cursor.data.position();
+            let region = cursor.region(start, compressed_len, "parse_lz4_block")?;
+            let corrupt = |message: String| BinaryCursorError::InvalidData {
+                operation: "parse_lz4_block",
+                position: start,
+                message,
+            };
+
+            if decompressed_len > compressed_len.saturating_mul(LZ4_MAX_RATIO) {
+                return Err(corrupt(format!(
+                    "{} compressed bytes cannot decompress to {} bytes",
+                    compressed_len, decompressed_len
+                )));
+            }
+
+            let output = lz4_flex::block::decompress(region, decompressed_len as usize)
+                .map_err(|e| corrupt(e.to_string()))?;
+
+            if output.len() as u64 != decompressed_len {
+                return Err(corrupt(format!(
+                    "decompressed to {} bytes, expected {}",
+                    output.len(),
+                    decompressed_len
+                )));
+            }
+
+            cursor
+                .data
+                .set_position(cursor.data.position() + compressed_len);
+            Ok(BinaryCursor::with_options(output, *cursor.options()))
+        })
+    }
+}
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_kind(error: BinaryCursorError) -> &'static str {
+        error.fields().kind
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_parse_zstd_block() {
+        let payload = b"hello hello hello hello";
+        let mut data = vec![0xAA];
+        let compressed = zstd::encode_all(&payload[..], 3).unwrap();
+        data.extend_from_slice(&compressed);
+        data.push(0xBB);
+
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_u8().unwrap(), 0xAA);
+
+        let mut inner = cursor
+            .parse_zstd_block(compressed.len() as u64, payload.len() as u64)
+            .unwrap();
+        assert_eq!(inner.parse_bytes(payload.len()).unwrap(), payload);
+        assert_eq!(cursor.parse_u8().unwrap(), 0xBB);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_parse_zstd_block_limit() {
+        let compressed = zstd::encode_all(&[0u8; 4096][..], 3).unwrap();
+        let mut cursor = BinaryCursor::new(&compressed);
+
+        let result = cursor.parse_zstd_block(compressed.len() as u64, 4095);
+        assert_eq!(error_kind(result.unwrap_err()), "invalid_data");
+        assert_eq!(cursor.position(), 0);
+        assert!(
+            cursor
+                .parse_zstd_block(compressed.len() as u64, 4096)
+                .is_ok()
+        );
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_parse_zstd_block_corrupt_and_truncated() {
+        let mut compressed = zstd::encode_all(&b"hello world"[..], 3).unwrap();
+        let len = compressed.len() as u64;
+
+        let mut cursor = BinaryCursor::new(&compressed);
+        let result = cursor.parse_zstd_block(len + 1, 1024);
+        assert_eq!(error_kind(result.unwrap_err()), "unexpected_eof");
+
+        let result = cursor.parse_zstd_block(len - 2, 1024);
+        assert_eq!(error_kind(result.unwrap_err()), "invalid_data");
+        assert_eq!(cursor.position(), 0);
+
+        compressed[6] ^= 0xFF;
+        let mut cursor = BinaryCursor::new(&compressed);
+        let result = cursor.parse_zstd_block(len, 1024);
+        assert_eq!(error_kind(result.unwrap_err()), "invalid_data");
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn test_parse_lz4_block() {
+        let payload = b"hello hello hello hello";
+        let compressed = lz4_flex::block::compress(payload);
+        let mut data = compressed.clone();
+        data.push(0xBB);
+
+        let mut cursor = BinaryCursor::new(data);
+        let mut inner = cursor
+            .parse_lz4_block(compressed.len() as u64, payload.len() as u64)
+            .unwrap();
+        assert_eq!(inner.parse_bytes(payload.len()).unwrap(), payload);
+        assert_eq!(cursor.parse_u8().unwrap(), 0xBB);
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn test_parse_lz4_block_limit() {
+        let compressed = lz4_flex::block::compress(b"abc");
+        let mut cursor = BinaryCursor::new(&compressed);
+
+        let result = cursor.parse_lz4_block(compressed.len() as u64, u64::MAX);
+        assert_eq!(error_kind(result.unwrap_err()), "invalid_data");
+
+        let result = cursor.parse_lz4_block(compressed.len() as u64, 2);
+        assert_eq!(error_kind(result.unwrap_err()), "invalid_data");
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn test_parse_lz4_block_corrupt_and_truncated() {
+        let payload = b"hello hello hello hello";
+        let compressed = lz4_flex::block::compress(payload);
+        let len = compressed.len() as u64;
+
+        let mut cursor = BinaryCursor::new(&compressed);
+        let result = cursor.parse_lz4_block(len + 1, payload.len() as u64);
+        assert_eq!(error_kind(result.unwrap_err()), "unexpected_eof");
+
+        let result = cursor.parse_lz4_block(len - 2, payload.len() as u64);
+        assert_eq!(error_kind(result.unwrap_err()), "invalid_data");
+        assert_eq!(cursor.position(), 0);
+    }
+}
+// endregion: Tests
@@ -0,0 +1,709 @@
+//! Deserialization of serde types from a [`BinaryCursor`].
+//!
+//! This module is only available with the `serde` cargo feature. It lets types deriving
+//! `serde::Deserialize` be read directly from binary data, using these conventions:
+//!
+//! * Integers and floats are fixed width, in the configured byte order (little-endian by
+//!   default). `bool` is a u8 that must be 0 or 1, and `char` is a u32 code point.
+//! * Strings and byte buffers are a length prefix followed by that many bytes, which must be
+//!   valid UTF-8 for strings.
+//! * Sequences and maps are a length prefix followed by the elements, or the key-value
+//!   pairs, in order.
+//! * `Option` is a u8 presence flag, 0 for `None` or 1 for `Some` followed by the value.
+//! * Structs, tuples and arrays are their fields in order, with no prefix. Unit types take
+//!   no space.
+//! * Enums are a u32 variant index followed by the variant's fields.
+//!
+//! The length prefix is a u32 by default. Both it and the byte order can be changed with
+//! [`DeserializerConfig`]. The format is not self-describing, so types that rely on
+//! `deserialize_any`, such as `serde_json::Value` or untagged enums, are not supported.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursor_binary_parser::binary_cursor::BinaryCursor;
+//! use cursor_binary_parser::de::from_cursor;
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, PartialEq, Deserialize)]
+//! struct Entry {
+//!     id: u16,
+//!     name: String,
+//! }
+//!
+//! let data = vec![0x07, 0x00, 0x02, 0x00, 0x00, 0x00, b'o', b'k'];
+//! let mut cursor = BinaryCursor::new(data);
+//!
+//! let entry: Entry = from_cursor(&mut cursor).unwrap();
+//! assert_eq!(entry, Entry { id: 7, name: "ok".to_string() });
+//! ```
+
+use crate::binary_cursor::{BinaryCursor, BinaryCursorError, Endianness};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use std::fmt::Display;
+use std::io;
+
+// region: DeserializerConfig
+/// The width of the length prefix before strings, byte buffers, sequences and maps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthPrefix {
+    /// A single byte
+    U8,
+    /// A two byte integer
+    U16,
+    /// A four byte integer
+    U32,
+    /// An eight byte integer
+    U64,
+}
+
+/// Options controlling how a [`CursorDeserializer`] lays out values
+///
+/// The defaults are little-endian values with a u32 length prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursor_binary_parser::binary_cursor::{BinaryCursor, Endianness};
+/// use cursor_binary_parser::de::{DeserializerConfig, LengthPrefix, from_cursor_with_config};
+///
+/// let data = vec![0x02, 0x00, 0x01, 0x00, 0x02];
+/// let mut cursor = BinaryCursor::new(data);
+/// let config = DeserializerConfig::new()
+///     .endianness(Endianness::Big)
+///     .length_prefix(LengthPrefix::U8);
+///
+/// let values: Vec<u16> = from_cursor_with_config(&mut cursor, config).unwrap();
+/// assert_eq!(values, vec![1, 2]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DeserializerConfig {
+    /// The byte order of integers, floats and length prefixes
+    pub endianness: Endianness,
+    /// The width of length prefixes
+    pub length_prefix: LengthPrefix,
+}
+
+impl Default for DeserializerConfig {
+    fn default() -> Self {
+        Self {
+            endianness: Endianness::Little,
+            length_prefix: LengthPrefix::U32,
+        }
+    }
+}
+
+impl DeserializerConfig {
+    /// Creates a config with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the byte order of integers, floats and length prefixes
+    pub fn endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    /// Sets the width of length prefixes
+    pub fn length_prefix(mut self, length_prefix: LengthPrefix) -> Self {
+        self.length_prefix = length_prefix;
+        self
+    }
+}
+// endregion: DeserializerConfig
+
+// region: Entry points
+/// Deserializes a `D` from the current position of `cursor` with the default config
+///
+/// On error the position is left unchanged.
+pub fn from_cursor<D, T>(cursor: &mut BinaryCursor<T>) -> Result<D, BinaryCursorError>
+where
+    D: DeserializeOwned,
+    T: AsRef<[u8]>,
+{
+    from_cursor_with_config(cursor, DeserializerConfig::default())
+}
+
+/// Deserializes a `D` from the current position of `cursor` with the given config
+///
+/// On error the position is left unchanged.
+pub fn from_cursor_with_config<D, T>(
+    cursor: &mut BinaryCursor<T>,
+    config: DeserializerConfig,
+) -> Result<D, BinaryCursorError>
+where
+    D: DeserializeOwned,
+    T: AsRef<[u8]>,
+{
+    cursor.atomic(|cursor| D::deserialize(&mut CursorDeserializer::with_config(cursor, config)))
+}
+// endregion: Entry points
+
+// region: CursorDeserializer implementation
+/// A `serde::Deserializer` reading from a borrowed [`BinaryCursor`]
+///
+/// See the [module documentation](self) for the layout of each type. Values are read from
+/// the cursor's current position, which is left just after the last value read.
+pub struct CursorDeserializer<'a, T: AsRef<[u8]>> {
+    cursor: &'a mut BinaryCursor<T>,
+    config: DeserializerConfig,
+}
+
+impl<'a, T> CursorDeserializer<'a, T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Creates a deserializer over `cursor` with the default config
+    pub fn new(cursor: &'a mut BinaryCursor<T>) -> Self {
+        Self::with_config(cursor, DeserializerConfig::default())
+    }
+
+    /// Creates a deserializer over `cursor` with the given config
+    pub fn with_config(cursor: &'a mut BinaryCursor<T>, config: DeserializerConfig) -> Self {
+        Self { cursor, config }
+    }
+
+    /// Returns the config in use
+    pub fn config(&self) -> DeserializerConfig {
+        self.config
+    }
+
+    /// Returns the underlying cursor
+    pub fn cursor(&mut self) -> &mut BinaryCursor<T> {
+        self.cursor
+    }
+
+    /// Reads a length prefix of the configured width
+    fn parse_length(&mut self, operation: &'static str) -> Result<usize, BinaryCursorError> {
+        let position = self.cursor.position();
+        let len = match self.config.length_prefix {
+            LengthPrefix::U8 => u64::from(self.read::<1>(operation)?[0]),
+            LengthPrefix::U16 => u64::from(u16_from(self.read(operation)?, self.config)),
+            LengthPrefix::U32 => u64::from(u32_from(self.read(operation)?, self.config)),
+            LengthPrefix::U64 => u64_from(self.read(operation)?, self.config),
+        };
+        usize::try_from(len).map_err(|_| BinaryCursorError::InvalidData {
+            operation,
+            position,
+            message: format!("length {} does not fit in usize", len),
+        })
+    }
+
+    /// Reads a length prefix followed by that many bytes
+    fn parse_prefixed_bytes(
+        &mut self,
+        operation: &'static str,
+    ) -> Result<Vec<u8>, BinaryCursorError> {
+        let len = self.parse_length(operation)?;
+        self.cursor.parse_bytes(len)
+    }
+
+    /// Reads `N` bytes for `operation`
+    fn read<const N: usize>(
+        &mut self,
+        operation: &'static str,
+    ) -> Result<[u8; N], BinaryCursorError> {
+        self.cursor.read_array_unaligned(operation)
+    }
+}
+
+macro_rules! decode_fn {
+    ($name:ident, $type:ty) => {
+        fn $name(bytes: [u8; size_of::<$type>()], config: DeserializerConfig) -> $type {
+            match config.endianness {
+                Endianness::Little => <$type>::from_le_bytes(bytes),
+                Endianness::Big => <$type>::from_be_bytes(bytes),
+            }
+        }
+    };
+}
+
+decode_fn!(u16_from, u16);
+decode_fn!(u32_from, u32);
+decode_fn!(u64_from, u64);
+
+/// Gives position-less errors raised by a visitor, such as an invalid enum variant, the
+/// offset of the value being visited
+fn locate<R>(position: u64, result: Result<R, BinaryCursorError>) -> Result<R, BinaryCursorError> {
+    result.map_err(|error| match error {
+        BinaryCursorError::ParseError(source) if source.kind() == io::ErrorKind::InvalidData => {
+            BinaryCursorError::InvalidData {
+                operation: "deserialize",
+                position,
+                message: source.to_string(),
+            }
+        }
+        error => error,
+    })
+}
+
+macro_rules! deserialize_number {
+    ($method:ident, $visit:ident, $type:ty, $operation:literal) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            let position = self.cursor.position();
+            let bytes = self.read($operation)?;
+            let value = match self.config.endianness {
+                Endianness::Little => <$type>::from_le_bytes(bytes),
+                Endianness::Big => <$type>::from_be_bytes(bytes),
+            };
+            locate(position, visitor.$visit(value))
+        }
+    };
+}
+
+impl<'de, T> de::Deserializer<'de> for &mut CursorDeserializer<'_, T>
+where
+    T: AsRef<[u8]>,
+{
+    type Error = BinaryCursorError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(BinaryCursorError::ParseError(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "CursorDeserializer does not support deserialize_any, as the format is not self-describing",
+        )))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        let value = match self.read::<1>("deserialize_bool")?[0] {
+            0 => false,
+            1 => true,
+            other => {
+                return Err(BinaryCursorError::InvalidData {
+                    operation: "deserialize_bool",
+                    position,
+                    message: format!("{} is not a valid bool", other),
+                });
+            }
+        };
+        locate(position, visitor.visit_bool(value))
+    }
+
+    deserialize_number!(deserialize_i8, visit_i8, i8, "deserialize_i8");
+    deserialize_number!(deserialize_i16, visit_i16, i16, "deserialize_i16");
+    deserialize_number!(deserialize_i32, visit_i32, i32, "deserialize_i32");
+    deserialize_number!(deserialize_i64, visit_i64, i64, "deserialize_i64");
+    deserialize_number!(deserialize_i128, visit_i128, i128, "deserialize_i128");
+    deserialize_number!(deserialize_u8, visit_u8, u8, "deserialize_u8");
+    deserialize_number!(deserialize_u16, visit_u16, u16, "deserialize_u16");
+    deserialize_number!(deserialize_u32, visit_u32, u32, "deserialize_u32");
+    deserialize_number!(deserialize_u64, visit_u64, u64, "deserialize_u64");
+    deserialize_number!(deserialize_u128, visit_u128, u128, "deserialize_u128");
+    deserialize_number!(deserialize_f32, visit_f32, f32, "deserialize_f32");
+    deserialize_number!(deserialize_f64, visit_f64, f64, "deserialize_f64");
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        let code = u32_from(self.read("deserialize_char")?, self.config);
+        let value = char::from_u32(code).ok_or_else(|| BinaryCursorError::InvalidData {
+            operation: "deserialize_char",
+            position,
+            message: format!("{:#X} is not a valid char", code),
+        })?;
+        locate(position, visitor.visit_char(value))
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        let bytes = self.parse_prefixed_bytes("deserialize_string")?;
+        let value = String::from_utf8(bytes).map_err(|error| BinaryCursorError::InvalidData {
+            operation: "deserialize_string",
+            position,
+            message: error.to_string(),
+        })?;
+        locate(position, visitor.visit_string(value))
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        let bytes = self.parse_prefixed_bytes("deserialize_byte_buf")?;
+        locate(position, visitor.visit_byte_buf(bytes))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        match self.read::<1>("deserialize_option")?[0] {
+            0 => locate(position, visitor.visit_none()),
+            1 => locate(position, visitor.visit_some(self)),
+            other => Err(BinaryCursorError::InvalidData {
+                operation: "deserialize_option",
+                position,
+                message: format!("{} is not a valid Option flag", other),
+            }),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        locate(position, visitor.visit_unit())
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        locate(position, visitor.visit_newtype_struct(self))
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        let len = self.parse_length("deserialize_seq")?;
+        locate(position, visitor.visit_seq(Elements { de: self, len }))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        locate(position, visitor.visit_seq(Elements { de: self, len }))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        let len = self.parse_length("deserialize_map")?;
+        locate(position, visitor.visit_map(Elements { de: self, len }))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let position = self.cursor.position();
+        locate(position, visitor.visit_enum(self))
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_u32(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// The elements of a sequence, tuple, struct or map, of which `len` remain
+struct Elements<'b, 'a, T: AsRef<[u8]>> {
+    de: &'b mut CursorDeserializer<'a, T>,
+    len: usize,
+}
+
+impl<'de, T> SeqAccess<'de> for Elements<'_, '_, T>
+where
+    T: AsRef<[u8]>,
+{
+    type Error = BinaryCursorError;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de, T> MapAccess<'de> for Elements<'_, '_, T>
+where
+    T: AsRef<[u8]>,
+{
+    type Error = BinaryCursorError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, Self::Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de, T> EnumAccess<'de> for &mut CursorDeserializer<'_, T>
+where
+    T: AsRef<[u8]>,
+{
+    type Error = BinaryCursorError;
+    type Variant = Self;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self::Variant), Self::Error> {
+        let position = self.cursor.position();
+        let index = u32_from(self.read("deserialize_enum")?, self.config);
+        let value = locate(position, seed.deserialize(index.into_deserializer()))?;
+        Ok((value, self))
+    }
+}
+
+impl<'de, T> VariantAccess<'de> for &mut CursorDeserializer<'_, T>
+where
+    T: AsRef<[u8]>,
+{
+    type Error = BinaryCursorError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<S::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+    }
+}
+
+impl de::Error for BinaryCursorError {
+    fn custom<M: Display>(message: M) -> Self {
+        BinaryCursorError::ParseError(io::Error::new(
+            io::ErrorKind::InvalidData,
+            message.to_string(),
+        ))
+    }
+}
+// endregion: CursorDeserializer implementation
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Point {
+        x: i16,
+        y: i16,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Layer {
+        name: String,
+        points: Vec<Point>,
+        colour: Option<u32>,
+        visible: bool,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(u8),
+        Rect { w: u8, h: u8 },
+    }
+
+    fn layer_bytes() -> Vec<u8> {
+        let mut data = vec![];
+        data.extend_from_slice(&[0x04, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(b"base");
+        data.extend_from_slice(&[0x02, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(&[0x01, 0x00, 0xFF, 0xFF]);
+        data.extend_from_slice(&[0x10, 0x00, 0x20, 0x00]);
+        data.extend_from_slice(&[0x01, 0x78, 0x56, 0x34, 0x12]);
+        data.push(0x01);
+        data
+    }
+
+    #[test]
+    fn test_nested_struct() {
+        let mut data = layer_bytes();
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        let mut cursor = BinaryCursor::new(data);
+
+        let layer: Layer = from_cursor(&mut cursor).unwrap();
+        assert_eq!(
+            layer,
+            Layer {
+                name: "base".to_string(),
+                points: vec![Point { x: 1, y: -1 }, Point { x: 16, y: 32 }],
+                colour: Some(0x12345678),
+                visible: true,
+            }
+        );
+        assert_eq!(cursor.position(), 26);
+
+        let layer: Layer = from_cursor(&mut cursor).unwrap();
+        assert_eq!(
+            layer,
+            Layer {
+                name: String::new(),
+                points: vec![],
+                colour: None,
+                visible: false,
+            }
+        );
+        assert_eq!(cursor.remaining(), 0);
+    }
+
+    #[test]
+    fn test_config() {
+        let data = vec![0x03, b'a', b'b', b'c', 0x01, 0x01, 0x02, 0x01, 0x03, 0x04];
+        let mut cursor = BinaryCursor::new(data);
+        let config = DeserializerConfig::new()
+            .endianness(Endianness::Big)
+            .length_prefix(LengthPrefix::U8);
+
+        let value: (String, Vec<u16>, Option<()>) =
+            from_cursor_with_config(&mut cursor, config).unwrap();
+        assert_eq!(value, ("abc".to_string(), vec![0x0102], Some(())));
+        assert_eq!(cursor.position(), 8);
+
+        let value: u16 = from_cursor_with_config(&mut cursor, config).unwrap();
+        assert_eq!(value, 0x0304);
+    }
+
+    #[test]
+    fn test_enums_and_maps() {
+        let data = vec![
+            0x00, 0x00, 0x00, 0x00, //
+            0x01, 0x00, 0x00, 0x00, 0x05, //
+            0x02, 0x00, 0x00, 0x00, 0x03, 0x04, //
+            0x02, 0x01, 0x61, 0x00, 0x00, 0x00, 0x02, 0x62, 0x00, 0x00, 0x00,
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        let shapes: [Shape; 3] = from_cursor(&mut cursor).unwrap();
+        assert_eq!(
+            shapes,
+            [Shape::Empty, Shape::Circle(5), Shape::Rect { w: 3, h: 4 }]
+        );
+
+        let config = DeserializerConfig::new().length_prefix(LengthPrefix::U8);
+        let map: BTreeMap<u8, char> = from_cursor_with_config(&mut cursor, config).unwrap();
+        assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b')]));
+        assert_eq!(cursor.remaining(), 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        // Truncated inside the second point
+        let data = layer_bytes()[..18].to_vec();
+        let mut cursor = BinaryCursor::new(data);
+        let error = from_cursor::<Layer, _>(&mut cursor).unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+        assert_eq!(error.position(), Some(18));
+        assert_eq!(cursor.position(), 0);
+
+        // Invalid presence flag
+        let mut data = layer_bytes();
+        data[20] = 0x02;
+        let mut cursor = BinaryCursor::new(data);
+        let error = from_cursor::<Layer, _>(&mut cursor).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid data at offset 20 in deserialize_option: 2 is not a valid Option flag"
+        );
+
+        // Unknown enum variants are reported where the index was read
+        let data = vec![0x00, 0x07, 0x00, 0x00, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+        let error = from_cursor::<(u8, Shape), _>(&mut cursor).unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.position(), Some(1));
+        assert_eq!(cursor.position(), 0);
+
+        // Invalid UTF-8
+        let data = vec![0x01, 0x00, 0x00, 0x00, 0xFF];
+        let mut cursor = BinaryCursor::new(data);
+        let error = from_cursor::<String, _>(&mut cursor).unwrap_err();
+        assert_eq!(error.position(), Some(0));
+
+        // A hostile sequence length fails on the data rather than allocating
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x01];
+        let mut cursor = BinaryCursor::new(data);
+        let error = from_cursor::<Vec<u64>, _>(&mut cursor).unwrap_err();
+        assert_eq!(error.fields().kind, "unexpected_eof");
+
+        // Self-describing types are rejected
+        let mut cursor = BinaryCursor::new(vec![0x00]);
+        assert!(from_cursor::<serde::de::IgnoredAny, _>(&mut cursor).is_err());
+    }
+}
+// endregion: Tests
@@ -0,0 +1,196 @@
+//! Decoding of string fields stored in legacy text encodings.
+//!
+//! This module is only available with the `encoding` cargo feature, which pulls in
+//! [`encoding_rs`](https://docs.rs/encoding_rs). Any encoding it supports can be used, such
+//! as `SHIFT_JIS`, `WINDOWS_1252` (which is also how the WHATWG standard interprets
+//! ISO-8859-1 labels) or `EUC_KR`.
+
+use crate::binary_cursor::{BinaryCursor, BinaryCursorError};
+use encoding_rs::Encoding;
+
+impl<T> BinaryCursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Parses `len` bytes as a string in the given encoding
+    ///
+    /// Byte order marks are not interpreted. When `lossy` is `true`, malformed sequences are
+    /// replaced with U+FFFD; otherwise they are reported as an error. On error the position
+    /// is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x93, 0xFA, 0x96, 0x7B];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let value = cursor
+    ///     .parse_str_encoded(4, encoding_rs::SHIFT_JIS, false)
+    ///     .unwrap();
+    /// assert_eq!(value, "日本");
+    /// ```
+    pub fn parse_str_encoded(
+        &mut self,
+        len: usize,
+        encoding: &'static Encoding,
+        lossy: bool,
+    ) -> Result<String, BinaryCursorError> {
+        self.traced("parse_str_encoded", |cursor| {
+            cursor.atomic(|cursor| {
+                let start = cursor.data.position();
+                let bytes = cursor.parse_bytes(len)?;
+                decode(&bytes, encoding, lossy, "parse_str_encoded", start)
+            })
+        })
+    }
+
+    /// Parses a NUL-terminated string in the given encoding
+    ///
+    /// The terminator is found by scanning for a 0x00 byte, so this is only suitable for
+    /// ASCII-compatible encodings. It is consumed but not included in the result. See
+    /// [`parse_str_encoded`](Self::parse_str_encoded) for the meaning of `lossy`.
+    pub fn parse_cstr_encoded(
+        &mut self,
+        encoding: &'static Encoding,
+        lossy: bool,
+    ) -> Result<String, BinaryCursorError> {
+        self.traced("parse_cstr_encoded", |cursor| {
+            cursor.atomic(|cursor| {
+                let start = cursor.data.position();
+                let bytes = cursor.read_cstr_raw(usize::MAX, "parse_cstr_encoded")?;
+                decode(&bytes, encoding, lossy, "parse_cstr_encoded", start)
+            })
+        })
+    }
+}
+
+/// Decodes `bytes`, which started at `position`, in the given encoding
+fn decode(
+    bytes: &[u8],
+    encoding: &'static Encoding,
+    lossy: bool,
+    operation: &'static str,
+    position: u64,
+) -> Result<String, BinaryCursorError> {
+    if lossy {
+        let (value, _) = encoding.decode_without_bom_handling(bytes);
+        return Ok(value.into_owned());
+    }
+
+    encoding
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(|value| value.into_owned())
+        .ok_or_else(|| BinaryCursorError::InvalidData {
+            operation,
+            position,
+            message: format!("malformed {} sequence", encoding.name()),
+        })
+}
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_str_encoded_shift_jis() {
+        // "日本語" followed by "ｱ" (half-width katakana)
+        let data = vec![0x93, 0xFA, 0x96, 0x7B, 0x8C, 0xEA, 0xB1];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(
+            cursor
+                .parse_str_encoded(7, encoding_rs::SHIFT_JIS, false)
+                .unwrap(),
+            "日本語ｱ"
+        );
+        assert_eq!(cursor.position(), 7);
+    }
+
+    #[test]
+    fn test_parse_str_encoded_latin1() {
+        let data = vec![b'c', b'a', b'f', 0xE9, 0x80];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(
+            cursor
+                .parse_str_encoded(4, encoding_rs::WINDOWS_1252, false)
+                .unwrap(),
+            "café"
+        );
+        assert_eq!(
+            cursor
+                .parse_str_encoded(1, encoding_rs::WINDOWS_1252, false)
+                .unwrap(),
+            "€"
+        );
+    }
+
+    #[test]
+    fn test_parse_str_encoded_malformed() {
+        // 0x82 is a Shift-JIS lead byte, but 0x20 is not a valid trail byte
+        let data = vec![b'a', 0x82, 0x20];
+        let mut cursor = BinaryCursor::new(data);
+
+        let error = cursor
+            .parse_str_encoded(3, encoding_rs::SHIFT_JIS, false)
+            .unwrap_err();
+        assert_eq!(error.fields().kind, "invalid_data");
+        assert_eq!(error.fields().offset, Some(0));
+        assert_eq!(cursor.position(), 0);
+
+        assert_eq!(
+            cursor
+                .parse_str_encoded(3, encoding_rs::SHIFT_JIS, true)
+                .unwrap(),
+            "a\u{FFFD} "
+        );
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn test_parse_str_encoded_truncated() {
+        let data = vec![0x93, 0xFA];
+        let mut cursor = BinaryCursor::new(data);
+        assert!(
+            cursor
+                .parse_str_encoded(4, encoding_rs::SHIFT_JIS, true)
+                .is_err()
+        );
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_parse_cstr_encoded() {
+        let data = vec![0x93, 0xFA, 0x96, 0x7B, 0x00, 0xE9, 0x00, 0x82, 0x20, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(
+            cursor
+                .parse_cstr_encoded(encoding_rs::SHIFT_JIS, false)
+                .unwrap(),
+            "日本"
+        );
+        assert_eq!(cursor.position(), 5);
+        assert_eq!(
+            cursor
+                .parse_cstr_encoded(encoding_rs::WINDOWS_1252, false)
+                .unwrap(),
+            "é"
+        );
+        assert_eq!(cursor.position(), 7);
+
+        assert!(
+            cursor
+                .parse_cstr_encoded(encoding_rs::SHIFT_JIS, false)
+                .is_err()
+        );
+        assert_eq!(cursor.position(), 7);
+
+        let mut cursor = BinaryCursor::new(b"ab".to_vec());
+        let error = cursor
+            .parse_cstr_encoded(encoding_rs::WINDOWS_1252, false)
+            .unwrap_err();
+        assert_eq!(error.fields().operation, Some("parse_cstr_encoded"));
+    }
+}
+// endregion: Tests
@@ -0,0 +1,427 @@
+//! A trait for types that can be parsed from a `BinaryCursor`, and the generic
+//! [`BinaryCursor::parse`] entry point built on it.
+//!
+//! Implementations are provided for the primitive integer and float types, fixed-size arrays
+//! and tuples of up to eight elements. Multi-byte primitives are read in the cursor's
+//! configured byte order (little-endian unless set otherwise with
+//! [`BinaryCursorBuilder::endianness`](crate::binary_cursor::BinaryCursorBuilder::endianness)).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursor_binary_parser::prelude::*;
+//!
+//! struct Point {
+//!     x: i16,
+//!     y: i16,
+//! }
+//!
+//! impl FromCursor for Point {
+//!     fn from_cursor<T: AsRef<[u8]>>(
+//!         cursor: &mut BinaryCursor<T>,
+//!     ) -> Result<Self, BinaryCursorError> {
+//!         Ok(Point {
+//!             x: cursor.parse()?,
+//!             y: cursor.parse()?,
+//!         })
+//!     }
+//! }
+//!
+//! let data = vec![0x01, 0x00, 0xFF, 0xFF];
+//! let mut cursor = BinaryCursor::new(data);
+//!
+//! let point: Point = cursor.parse().unwrap();
+//! assert_eq!((point.x, point.y), (1, -1));
+//! ```
+
+use crate::binary_cursor::{BinaryCursor, BinaryCursorError};
+
+#[cfg(feature = "derive")]
+pub use cursor_binary_parser_derive::FromCursor;
+
+/// A type that can be parsed from the current position of a [`BinaryCursor`]
+///
+/// Implementations for composite types should parse their fields in order with
+/// [`BinaryCursor::parse`] or the other parse methods. They do not need to restore the
+/// position on error themselves, since [`BinaryCursor::parse`] does so.
+///
+/// With the `derive` cargo feature, this can also be derived for structs whose fields all
+/// implement it, using `#[derive(FromCursor)]`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be parsed from a `BinaryCursor`",
+    label = "`{Self}` does not implement `FromCursor`",
+    note = "implement `FromCursor` for `{Self}`, or derive it if it is a struct of parseable fields"
+)]
+pub trait FromCursor: Sized {
+    /// Parses a value from the current position, advancing past it
+    fn from_cursor<T: AsRef<[u8]>>(cursor: &mut BinaryCursor<T>)
+    -> Result<Self, BinaryCursorError>;
+}
+
+impl<T> BinaryCursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    /// Parses a value of any type implementing [`FromCursor`]
+    ///
+    /// On error the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let data = vec![0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// let (a, b, c) = cursor.parse::<(u16, u16, u32)>().unwrap();
+    /// assert_eq!((a, b, c), (1, 2, 3));
+    /// ```
+    pub fn parse<U: FromCursor>(&mut self) -> Result<U, BinaryCursorError> {
+        self.traced("parse", |cursor| cursor.atomic(U::from_cursor))
+    }
+
+    /// Parses a discriminant of type `V` and converts it to the enum `E` with [`TryFrom`]
+    ///
+    /// The discriminant is read like [`BinaryCursor::parse`], so multi-byte values use the
+    /// cursor's byte order. A value that `E` rejects is reported as
+    /// [`BinaryCursorError::InvalidData`] naming the value and the offset it was read from,
+    /// and the position is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Compression {
+    ///     None,
+    ///     Deflate,
+    /// }
+    ///
+    /// impl TryFrom<u8> for Compression {
+    ///     type Error = ();
+    ///
+    ///     fn try_from(value: u8) -> Result<Self, ()> {
+    ///         match value {
+    ///             0 => Ok(Compression::None),
+    ///             8 => Ok(Compression::Deflate),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let data = vec![0x08, 0x05];
+    /// let mut cursor = BinaryCursor::new(data);
+    ///
+    /// assert_eq!(cursor.parse_enum::<Compression, u8>().unwrap(), Compression::Deflate);
+    /// assert!(cursor.parse_enum::<Compression, u8>().is_err());
+    /// assert_eq!(cursor.position(), 1);
+    /// ```
+    pub fn parse_enum<E, V>(&mut self) -> Result<E, BinaryCursorError>
+    where
+        E: TryFrom<V>,
+        V: FromCursor + Copy + std::fmt::Display,
+    {
+        self.traced("parse_enum", |cursor| {
+            cursor.atomic(|cursor| {
+                let position = cursor.position();
+                let value = V::from_cursor(cursor)?;
+                E::try_from(value).map_err(|_| BinaryCursorError::InvalidData {
+                    operation: "parse_enum",
+                    position,
+                    message: format!(
+                        "{} is not a valid discriminant for {}",
+                        value,
+                        std::any::type_name::<E>()
+                    ),
+                })
+            })
+        })
+    }
+}
+
+impl FromCursor for u8 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_u8()
+    }
+}
+
+impl FromCursor for i8 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_i8()
+    }
+}
+
+impl FromCursor for u16 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_u16()
+    }
+}
+
+impl FromCursor for i16 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_i16()
+    }
+}
+
+impl FromCursor for u32 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_u32()
+    }
+}
+
+impl FromCursor for i32 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_i32()
+    }
+}
+
+impl FromCursor for u64 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_u64()
+    }
+}
+
+impl FromCursor for i64 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_i64()
+    }
+}
+
+impl FromCursor for f32 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_f32()
+    }
+}
+
+impl FromCursor for f64 {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.parse_f64()
+    }
+}
+
+impl<U: FromCursor, const N: usize> FromCursor for [U; N] {
+    fn from_cursor<T: AsRef<[u8]>>(
+        cursor: &mut BinaryCursor<T>,
+    ) -> Result<Self, BinaryCursorError> {
+        cursor.count_array(U::from_cursor)
+    }
+}
+
+/// Implements `FromCursor` for a tuple whose elements all implement it, parsing them in order
+///
+/// As for arrays, the position is left unchanged if any element fails.
+macro_rules! impl_from_cursor_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: FromCursor),+> FromCursor for ($($name,)+) {
+            fn from_cursor<T: AsRef<[u8]>>(
+                cursor: &mut BinaryCursor<T>,
+            ) -> Result<Self, BinaryCursorError> {
+                cursor.atomic(|cursor| Ok(($($name::from_cursor(cursor)?,)+)))
+            }
+        }
+    };
+}
+
+impl_from_cursor_for_tuple!(A);
+impl_from_cursor_for_tuple!(A, B);
+impl_from_cursor_for_tuple!(A, B, C);
+impl_from_cursor_for_tuple!(A, B, C, D);
+impl_from_cursor_for_tuple!(A, B, C, D, E);
+impl_from_cursor_for_tuple!(A, B, C, D, E, F);
+impl_from_cursor_for_tuple!(A, B, C, D, E, F, G);
+impl_from_cursor_for_tuple!(A, B, C, D, E, F, G, H);
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_cursor::{BinaryCursorBuilder, Endianness};
+
+    #[derive(Debug, PartialEq)]
+    struct ChunkHeader {
+        tag: [u8; 4],
+        length: u32,
+        flags: (u8, u8),
+    }
+
+    impl FromCursor for ChunkHeader {
+        fn from_cursor<T: AsRef<[u8]>>(
+            cursor: &mut BinaryCursor<T>,
+        ) -> Result<Self, BinaryCursorError> {
+            Ok(ChunkHeader {
+                tag: cursor.parse()?,
+                length: cursor.parse()?,
+                flags: cursor.parse()?,
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Container {
+        version: u16,
+        chunks: [ChunkHeader; 2],
+    }
+
+    impl FromCursor for Container {
+        fn from_cursor<T: AsRef<[u8]>>(
+            cursor: &mut BinaryCursor<T>,
+        ) -> Result<Self, BinaryCursorError> {
+            Ok(Container {
+                version: cursor.parse()?,
+                chunks: cursor.parse()?,
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    #[repr(u8)]
+    enum Opcode {
+        Query = 0,
+        Notify = 4,
+        Update = 5,
+    }
+
+    impl TryFrom<u8> for Opcode {
+        type Error = u8;
+
+        fn try_from(value: u8) -> Result<Self, u8> {
+            match value {
+                0 => Ok(Opcode::Query),
+                4 => Ok(Opcode::Notify),
+                5 => Ok(Opcode::Update),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl TryFrom<u16> for Opcode {
+        type Error = u16;
+
+        fn try_from(value: u16) -> Result<Self, u16> {
+            u8::try_from(value)
+                .ok()
+                .and_then(|value| Opcode::try_from(value).ok())
+                .ok_or(value)
+        }
+    }
+
+    #[test]
+    fn test_parse_enum() {
+        let data = vec![0x04, 0x00, 0x07];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse_enum::<Opcode, u8>().unwrap(), Opcode::Notify);
+        assert_eq!(cursor.parse_enum::<Opcode, u8>().unwrap(), Opcode::Query);
+
+        let error = cursor.parse_enum::<Opcode, u8>().unwrap_err();
+        assert_eq!(error.position(), Some(2));
+        assert_eq!(error.fields().operation, Some("parse_enum"));
+        assert!(error.to_string().contains("7 is not a valid discriminant"));
+        assert!(error.to_string().contains("Opcode"));
+        assert_eq!(cursor.position(), 2);
+
+        // Wider discriminants are read in the cursor's byte order
+        let data = vec![0x00, 0x05, 0x01, 0x05];
+        let mut cursor = BinaryCursorBuilder::new()
+            .endianness(Endianness::Big)
+            .build(&data)
+            .unwrap();
+        assert_eq!(cursor.parse_enum::<Opcode, u16>().unwrap(), Opcode::Update);
+        let error = cursor.parse_enum::<Opcode, u16>().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("261 is not a valid discriminant")
+        );
+    }
+
+    #[test]
+    fn test_parse_primitives() {
+        let data = vec![
+            0xFF, 0xFE, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F,
+        ];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse::<u8>().unwrap(), 0xFF);
+        assert_eq!(cursor.parse::<i16>().unwrap(), -2);
+        assert_eq!(cursor.parse::<u32>().unwrap(), 1);
+        assert_eq!(cursor.parse::<f32>().unwrap(), 1.0);
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_parse_uses_cursor_endianness() {
+        let data = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02];
+        let mut cursor = BinaryCursorBuilder::new()
+            .endianness(Endianness::Big)
+            .build(&data)
+            .unwrap();
+        assert_eq!(cursor.parse::<u16>().unwrap(), 1);
+        assert_eq!(cursor.parse::<i64>().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_parse_arrays_and_tuples() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x00, 0x06];
+        let mut cursor = BinaryCursor::new(data);
+        assert_eq!(cursor.parse::<[u8; 3]>().unwrap(), [1, 2, 3]);
+        assert_eq!(cursor.parse::<(u8, u16, i8)>().unwrap(), (4, 5, 6));
+        assert_eq!(cursor.parse::<[u16; 0]>().unwrap(), []);
+    }
+
+    #[test]
+    fn test_parse_user_struct() {
+        let data = vec![
+            0x02, 0x00, // version
+            b'I', b'H', b'D', b'R', 0x0D, 0x00, 0x00, 0x00, 0x01, 0x02, // first chunk
+            b'I', b'E', b'N', b'D', 0x00, 0x00, 0x00, 0x00, 0x03, 0x04, // second chunk
+        ];
+        let mut cursor = BinaryCursor::new(data);
+
+        let container: Container = cursor.parse().unwrap();
+        assert_eq!(container.version, 2);
+        assert_eq!(
+            container.chunks[0],
+            ChunkHeader {
+                tag: *b"IHDR",
+                length: 13,
+                flags: (1, 2),
+            }
+        );
+        assert_eq!(&container.chunks[1].tag, b"IEND");
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn test_parse_failure_restores_position() {
+        let data = vec![0x01, 0x00, b'I', b'H', b'D', b'R', 0x0D, 0x00];
+        let mut cursor = BinaryCursor::new(data);
+
+        let error = cursor.parse::<Container>().unwrap_err();
+        assert_eq!(error.position(), Some(6));
+        assert_eq!(cursor.position(), 0);
+        assert!(cursor.parse::<(u16, u16, u16, u16, u8)>().is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+}
+// endregion: Tests
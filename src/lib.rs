@@ -34,3 +34,15 @@
 //! ```
 
 pub mod binary_cursor;
+pub mod binary_writer;
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+mod compression;
+#[cfg(feature = "encoding")]
+mod encoding;
+#[cfg(feature = "mmap")]
+mod mmap;
+pub mod from_cursor;
+pub mod prelude;
+pub mod streaming_cursor;
@@ -0,0 +1,117 @@
+//! Construction of cursors over memory-mapped files.
+//!
+//! This is gated behind the `mmap` cargo feature. Mapping a file lets the operating system
+//! page in only the parts that are actually parsed, avoiding both reading the whole file
+//! into memory and a system call per field.
+//!
+//! # Safety
+//!
+//! The mapping reflects the file on disk, so the file must not be truncated or modified by
+//! other processes while the cursor is in use. Reading a mapped page that the file no longer
+//! covers is undefined behaviour, so [`BinaryCursor::from_file`] is an `unsafe fn` and
+//! callers must uphold this themselves.
+
+use crate::binary_cursor::{BinaryCursor, BinaryCursorError};
+use memmap2::Mmap;
+use std::fs::File;
+use std::path::Path;
+
+impl BinaryCursor<Mmap> {
+    /// Opens the file at `path` read-only and creates a cursor over a memory map of it
+    ///
+    /// Failures to open or map the file are reported as `BinaryCursorError::Io` with the
+    /// path. Empty files produce an empty cursor.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified, by this or any other process, for as long
+    /// as the cursor or any cursor borrowing its data is alive. Otherwise reads may see
+    /// changing data or fault on pages the file no longer covers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursor_binary_parser::binary_cursor::BinaryCursor;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let path = dir.path().join("header.bin");
+    /// std::fs::write(&path, [b'B', b'M', 0x3E, 0x00, 0x00, 0x00]).unwrap();
+    ///
+    /// // SAFETY: the file is private to this example and is not modified while mapped
+    /// let mut cursor = unsafe { BinaryCursor::from_file(&path) }.unwrap();
+    /// cursor.expect_tag(b"BM").unwrap();
+    /// assert_eq!(cursor.parse_u32_le().unwrap(), 62);
+    /// ```
+    pub unsafe fn from_file(path: impl AsRef<Path>) -> Result<Self, BinaryCursorError> {
+        let path = path.as_ref();
+        let io_error = |source| BinaryCursorError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        let file = File::open(path).map_err(io_error)?;
+        // SAFETY: the map is read-only, and the caller guarantees that the file is not
+        // modified while it is mapped
+        let map = unsafe { Mmap::map(&file) }.map_err(io_error)?;
+        Ok(Self::new(map))
+    }
+}
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("image.bin");
+        std::fs::write(
+            &path,
+            [b'R', b'I', b'F', b'F', 0x24, 0x00, 0x00, 0x00, 0x01],
+        )
+        .unwrap();
+
+        // SAFETY: the file is in a private temporary directory and is not modified
+        let mut cursor = unsafe { BinaryCursor::from_file(&path) }.unwrap();
+        assert_eq!(cursor.len(), 9);
+        cursor.expect_tag(b"RIFF").unwrap();
+        assert_eq!(cursor.parse_u32_le().unwrap(), 36);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x01);
+        assert!(cursor.parse_u8().is_err());
+    }
+
+    #[test]
+    fn test_from_file_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("empty.bin");
+        std::fs::write(&path, []).unwrap();
+
+        // SAFETY: the file is in a private temporary directory and is not modified
+        let mut cursor = unsafe { BinaryCursor::from_file(&path) }.unwrap();
+        assert!(cursor.is_empty());
+        assert!(cursor.parse_u8().is_err());
+    }
+
+    #[test]
+    fn test_from_file_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.bin");
+
+        // SAFETY: the file does not exist, so nothing is mapped
+        let error = unsafe { BinaryCursor::from_file(&path) }.unwrap_err();
+        match &error {
+            BinaryCursorError::Io {
+                path: error_path,
+                source,
+            } => {
+                assert_eq!(error_path, &path);
+                assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(error.fields().kind, "io_error");
+        assert!(error.to_string().contains("missing.bin"));
+    }
+}
+// endregion: Tests
@@ -0,0 +1,32 @@
+//! Convenience re-exports of the types needed for most parsing code.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursor_binary_parser::prelude::*;
+//!
+//! fn parse_header<T: AsRef<[u8]>>(
+//!     cursor: &mut BinaryCursor<T>,
+//! ) -> Result<(u16, String), BinaryCursorError> {
+//!     let version = cursor.parse_u16()?;
+//!     let name = cursor.parse_fixed_str(4, Padding::Null)?;
+//!     Ok((version, name))
+//! }
+//!
+//! let data = vec![0x00, 0x02, b'a', b'b', 0x00, 0x00];
+//! let mut cursor = BinaryCursorBuilder::new()
+//!     .endianness(Endianness::Big)
+//!     .build(data)
+//!     .unwrap();
+//!
+//! assert_eq!(parse_header(&mut cursor).unwrap(), (2, "ab".to_string()));
+//! ```
+
+pub use crate::binary_cursor::{
+    Alternative, BinaryCursor, BinaryCursorBits, BinaryCursorBuilder, BinaryCursorError,
+    BinaryCursorJump, BinaryCursorRev, BitField, BitOrder, CursorOptions, DosDateTime, Endianness,
+    ErrorFields, OffsetBase, Padding, RecordIter, Uuid,
+};
+pub use crate::binary_writer::{BinaryWriter, BinaryWriterJump};
+pub use crate::from_cursor::FromCursor;
+pub use crate::streaming_cursor::{StreamingBinaryCursor, StreamingBinaryCursorJump};
@@ -0,0 +1,679 @@
+//! A binary cursor over `Read + Seek` sources such as files.
+//!
+//! `StreamingBinaryCursor` provides the core `BinaryCursor` parsing interface without
+//! requiring the whole input to be held in memory, which makes it suitable for large disk
+//! images. Positions map directly onto `seek`, so the location stack and the
+//! `StreamingBinaryCursorJump` guard work as they do for in-memory cursors.
+//!
+//! Because seeking and reading can fail for reasons other than running out of data,
+//! methods that move the position return a `Result`. Running out of data is reported as
+//! `UnexpectedEof` and leaves the position unchanged, as with `BinaryCursor`. Wrapping a
+//! `File` in a `BufReader` is recommended when parsing many small values.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursor_binary_parser::streaming_cursor::{StreamingBinaryCursor, StreamingBinaryCursorJump};
+//! use std::io::Cursor;
+//!
+//! // Any Read + Seek source can be used, such as a BufReader<File>
+//! let source = Cursor::new(vec![0x08, 0x00, 0x00, 0x00, 0xAA, 0xBB, 0xCC, 0xDD, 0x2A]);
+//! let mut cursor = StreamingBinaryCursor::new(source).unwrap();
+//!
+//! let offset = cursor.parse_u32_le().unwrap();
+//! {
+//!     let mut jump = StreamingBinaryCursorJump::new(&mut cursor);
+//!     jump.jump(offset as u64).unwrap();
+//!     assert_eq!(jump.cursor.parse_u8().unwrap(), 0x2A);
+//! }
+//! assert_eq!(cursor.position(), 4);
+//! ```
+
+use crate::binary_cursor::{BinaryCursorError, CursorOptions, Endianness};
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+// region: StreamingCursor implementation
+/// A cursor-like interface for parsing binary data from a `Read + Seek` source
+///
+/// Use [`BinaryCursorBuilder::build_streaming`](crate::binary_cursor::BinaryCursorBuilder::build_streaming)
+/// to create one with options other than the defaults.
+#[derive(Debug)]
+pub struct StreamingBinaryCursor<R: Read + Seek> {
+    /// The source being parsed
+    reader: R,
+    /// The current position, kept in step with the source's
+    position: u64,
+    /// Stack of saved positions for temporary jumps
+    location_stack: Vec<u64>,
+    /// Options controlling how the data is parsed
+    options: CursorOptions,
+}
+
+impl<R> StreamingBinaryCursor<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a new `StreamingBinaryCursor` starting at the reader's current position
+    pub fn new(reader: R) -> Result<Self, BinaryCursorError> {
+        Self::with_options(reader, CursorOptions::default())
+    }
+
+    /// Creates a new `StreamingBinaryCursor` with the given options, which are assumed to be
+    /// valid
+    pub(crate) fn with_options(
+        mut reader: R,
+        options: CursorOptions,
+    ) -> Result<Self, BinaryCursorError> {
+        let position = reader.stream_position()?;
+        Ok(Self {
+            reader,
+            position,
+            location_stack: vec![],
+            options,
+        })
+    }
+
+    /// Returns the options this cursor was created with
+    pub fn options(&self) -> &CursorOptions {
+        &self.options
+    }
+
+    /// Returns a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Consumes the cursor, returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Saves the current position to the location stack
+    pub fn push_location(&mut self) {
+        self.location_stack.push(self.position);
+    }
+
+    /// Removes and returns the most recently saved position from the location stack
+    pub fn pop_location(&mut self) -> Option<u64> {
+        self.location_stack.pop()
+    }
+
+    /// Restores the most recently saved position from the location stack
+    ///
+    /// Returns `true` if a position was restored, `false` if the stack was empty. If the
+    /// seek fails the saved position is still removed from the stack.
+    pub fn restore_location(&mut self) -> Result<bool, BinaryCursorError> {
+        match self.location_stack.pop() {
+            Some(pos) => {
+                self.set_position(pos)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the current position in the data stream
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Seeks to `pos`, measured from the start of the source
+    pub fn set_position(&mut self, pos: u64) -> Result<(), BinaryCursorError> {
+        self.position = self.reader.seek(SeekFrom::Start(pos))?;
+        Ok(())
+    }
+
+    /// Returns the total length of the source in bytes
+    ///
+    /// This seeks to the end of the source and back.
+    pub fn len(&mut self) -> Result<u64, BinaryCursorError> {
+        let len = self.reader.seek(SeekFrom::End(0))?;
+        self.set_position(self.position)?;
+        Ok(len)
+    }
+
+    /// Returns `true` if the source contains no bytes
+    pub fn is_empty(&mut self) -> Result<bool, BinaryCursorError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Returns the number of bytes between the current position and the end of the source
+    pub fn remaining(&mut self) -> Result<u64, BinaryCursorError> {
+        Ok(self.len()?.saturating_sub(self.position))
+    }
+
+    /// Reads `N` bytes from the current position, checking alignment if it is enforced
+    fn read_array<const N: usize>(
+        &mut self,
+        operation: &'static str,
+    ) -> Result<[u8; N], BinaryCursorError> {
+        if self.options.enforce_alignment && !self.position.is_multiple_of(N as u64) {
+            return Err(BinaryCursorError::InvalidData {
+                operation,
+                position: self.position,
+                message: format!("position is not aligned to {} bytes", N),
+            });
+        }
+
+        let mut buf = [0u8; N];
+        self.read_into(&mut buf, operation)?;
+        Ok(buf)
+    }
+
+    /// Fills `buf` from the current position and advances past the bytes read
+    ///
+    /// On error the reader is seeked back, so the position is left unchanged.
+    fn read_into(
+        &mut self,
+        buf: &mut [u8],
+        operation: &'static str,
+    ) -> Result<(), BinaryCursorError> {
+        let start = self.position;
+        let mut filled = 0;
+        while filled < buf.len() {
+            match self.reader.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.set_position(start)?;
+                    return Err(e.into());
+                }
+            }
+        }
+
+        if filled < buf.len() {
+            self.set_position(start)?;
+            return Err(BinaryCursorError::UnexpectedEof {
+                operation,
+                position: start,
+                requested: buf.len(),
+                available: filled,
+            });
+        }
+        self.position = start + buf.len() as u64;
+        Ok(())
+    }
+
+    /// Parses a single u8 from the current position
+    pub fn parse_u8(&mut self) -> Result<u8, BinaryCursorError> {
+        Ok(self.read_array::<1>("parse_u8")?[0])
+    }
+
+    /// Parses a u16 in little-endian format from the current position
+    pub fn parse_u16_le(&mut self) -> Result<u16, BinaryCursorError> {
+        Ok(u16::from_le_bytes(self.read_array("parse_u16_le")?))
+    }
+
+    /// Parses a u32 in little-endian format from the current position
+    pub fn parse_u32_le(&mut self) -> Result<u32, BinaryCursorError> {
+        Ok(u32::from_le_bytes(self.read_array("parse_u32_le")?))
+    }
+
+    /// Parses a u64 in little-endian format from the current position
+    pub fn parse_u64_le(&mut self) -> Result<u64, BinaryCursorError> {
+        Ok(u64::from_le_bytes(self.read_array("parse_u64_le")?))
+    }
+
+    /// Parses an f32 in little-endian format from the current position
+    pub fn parse_f32_le(&mut self) -> Result<f32, BinaryCursorError> {
+        Ok(f32::from_le_bytes(self.read_array("parse_f32_le")?))
+    }
+
+    /// Parses an f64 (double precision) in little-endian format from the current position
+    pub fn parse_f64_le(&mut self) -> Result<f64, BinaryCursorError> {
+        Ok(f64::from_le_bytes(self.read_array("parse_f64_le")?))
+    }
+
+    /// Parses a specified number of bytes from the current position
+    ///
+    /// The buffer grows as data is read rather than being allocated up front, and if the
+    /// cursor has an allocation limit, a `count` above it is rejected.
+    pub fn parse_bytes(&mut self, count: usize) -> Result<Vec<u8>, BinaryCursorError> {
+        let start = self.position;
+        if let Some(max) = self.options.max_alloc
+            && count > max
+        {
+            return Err(BinaryCursorError::InvalidData {
+                operation: "parse_bytes",
+                position: start,
+                message: format!("{} bytes exceeds allocation limit of {} bytes", count, max),
+            });
+        }
+
+        let mut buf = vec![];
+        let read = (&mut self.reader).take(count as u64).read_to_end(&mut buf);
+        match read {
+            Ok(n) if n == count => {
+                self.position = start + count as u64;
+                Ok(buf)
+            }
+            Ok(n) => {
+                self.set_position(start)?;
+                Err(BinaryCursorError::UnexpectedEof {
+                    operation: "parse_bytes",
+                    position: start,
+                    requested: count,
+                    available: n,
+                })
+            }
+            Err(e) => {
+                self.set_position(start)?;
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Parses an i8 from the current position
+    pub fn parse_i8(&mut self) -> Result<i8, BinaryCursorError> {
+        Ok(i8::from_le_bytes(self.read_array("parse_i8")?))
+    }
+
+    /// Parses an i16 in little-endian format from the current position
+    pub fn parse_i16_le(&mut self) -> Result<i16, BinaryCursorError> {
+        Ok(i16::from_le_bytes(self.read_array("parse_i16_le")?))
+    }
+
+    /// Parses an i32 in little-endian format from the current position
+    pub fn parse_i32_le(&mut self) -> Result<i32, BinaryCursorError> {
+        Ok(i32::from_le_bytes(self.read_array("parse_i32_le")?))
+    }
+
+    /// Parses an i64 in little-endian format from the current position
+    pub fn parse_i64_le(&mut self) -> Result<i64, BinaryCursorError> {
+        Ok(i64::from_le_bytes(self.read_array("parse_i64_le")?))
+    }
+
+    /// Parses a u16 in the cursor's configured byte order from the current position
+    pub fn parse_u16(&mut self) -> Result<u16, BinaryCursorError> {
+        let buf = self.read_array("parse_u16")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => u16::from_le_bytes(buf),
+            Endianness::Big => u16::from_be_bytes(buf),
+        })
+    }
+
+    /// Parses a u32 in the cursor's configured byte order from the current position
+    pub fn parse_u32(&mut self) -> Result<u32, BinaryCursorError> {
+        let buf = self.read_array("parse_u32")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => u32::from_le_bytes(buf),
+            Endianness::Big => u32::from_be_bytes(buf),
+        })
+    }
+
+    /// Parses a u64 in the cursor's configured byte order from the current position
+    pub fn parse_u64(&mut self) -> Result<u64, BinaryCursorError> {
+        let buf = self.read_array("parse_u64")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => u64::from_le_bytes(buf),
+            Endianness::Big => u64::from_be_bytes(buf),
+        })
+    }
+
+    /// Parses an i16 in the cursor's configured byte order from the current position
+    pub fn parse_i16(&mut self) -> Result<i16, BinaryCursorError> {
+        let buf = self.read_array("parse_i16")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => i16::from_le_bytes(buf),
+            Endianness::Big => i16::from_be_bytes(buf),
+        })
+    }
+
+    /// Parses an i32 in the cursor's configured byte order from the current position
+    pub fn parse_i32(&mut self) -> Result<i32, BinaryCursorError> {
+        let buf = self.read_array("parse_i32")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => i32::from_le_bytes(buf),
+            Endianness::Big => i32::from_be_bytes(buf),
+        })
+    }
+
+    /// Parses an i64 in the cursor's configured byte order from the current position
+    pub fn parse_i64(&mut self) -> Result<i64, BinaryCursorError> {
+        let buf = self.read_array("parse_i64")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => i64::from_le_bytes(buf),
+            Endianness::Big => i64::from_be_bytes(buf),
+        })
+    }
+
+    /// Parses an f32 in the cursor's configured byte order from the current position
+    pub fn parse_f32(&mut self) -> Result<f32, BinaryCursorError> {
+        let buf = self.read_array("parse_f32")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => f32::from_le_bytes(buf),
+            Endianness::Big => f32::from_be_bytes(buf),
+        })
+    }
+
+    /// Parses an f64 in the cursor's configured byte order from the current position
+    pub fn parse_f64(&mut self) -> Result<f64, BinaryCursorError> {
+        let buf = self.read_array("parse_f64")?;
+        Ok(match self.options.endianness {
+            Endianness::Little => f64::from_le_bytes(buf),
+            Endianness::Big => f64::from_be_bytes(buf),
+        })
+    }
+
+    /// Runs a parser, restoring the starting position if it fails
+    ///
+    /// If the seek back to the starting position itself fails, that error is returned
+    /// instead of the parser's.
+    pub fn atomic<U, F>(&mut self, parser: F) -> Result<U, BinaryCursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        let start = self.position;
+        let result = parser(self);
+        if result.is_err() {
+            self.set_position(start)?;
+        }
+        result
+    }
+
+    /// Parses `count` items using the provided parser function
+    ///
+    /// As with [`BinaryCursor::count`](crate::binary_cursor::BinaryCursor::count), space is
+    /// reserved according to the data remaining in the source rather than `count`, and the
+    /// first failing item stops the run. On error the position is left unchanged.
+    pub fn count<U, F>(&mut self, mut parser: F, count: usize) -> Result<Vec<U>, BinaryCursorError>
+    where
+        F: FnMut(&mut Self) -> Result<U, BinaryCursorError>,
+    {
+        self.atomic(|cursor| {
+            let remaining = usize::try_from(cursor.remaining()?).unwrap_or(usize::MAX);
+            let mut items = Vec::with_capacity(count.min(remaining));
+            for _ in 0..count {
+                items.push(parser(cursor)?);
+            }
+            Ok(items)
+        })
+    }
+}
+// endregion: StreamingCursor implementation
+
+// region: StreamingCursorJump implementation
+/// A helper type for temporary position changes in a `StreamingBinaryCursor`
+///
+/// This type provides a way to temporarily change the position of a
+/// `StreamingBinaryCursor` and automatically restore it when the
+/// `StreamingBinaryCursorJump` is dropped. Errors from the seek performed on drop cannot
+/// be reported; call [`StreamingBinaryCursor::restore_location`] directly if they matter.
+///
+/// As with [`BinaryCursorJump`](crate::binary_cursor::BinaryCursorJump), a guard can jump
+/// any number of times, and on drop restores the position from before its first jump.
+pub struct StreamingBinaryCursorJump<'a, R: Read + Seek> {
+    /// Reference to the cursor being manipulated
+    pub cursor: &'a mut StreamingBinaryCursor<R>,
+    /// The depth of the location stack when the guard was created
+    depth: usize,
+}
+
+impl<'a, R> StreamingBinaryCursorJump<'a, R>
+where
+    R: Read + Seek,
+{
+    /// Creates a new `StreamingBinaryCursorJump` for the given cursor
+    pub fn new(cursor: &'a mut StreamingBinaryCursor<R>) -> Self {
+        let depth = cursor.location_stack.len();
+        Self { cursor, depth }
+    }
+
+    /// Checks that another position can be saved without exceeding the cursor's depth limit
+    fn check_depth(&self, operation: &'static str) -> Result<(), BinaryCursorError> {
+        match self.cursor.options.depth_limit {
+            Some(limit) if self.cursor.location_stack.len() >= limit => {
+                Err(BinaryCursorError::InvalidData {
+                    operation,
+                    position: self.cursor.position(),
+                    message: format!("location stack depth limit of {} reached", limit),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Temporarily jumps to the specified position
+    ///
+    /// The position will be automatically restored when the `StreamingBinaryCursorJump` is
+    /// dropped. If the depth limit is reached or the seek fails, an error is returned and
+    /// nothing is saved to the location stack.
+    pub fn jump(&mut self, location: u64) -> Result<(), BinaryCursorError> {
+        self.jump_to(location, "jump")
+    }
+
+    /// Saves the current position and seeks to `location`, undoing the save on failure
+    fn jump_to(&mut self, location: u64, operation: &'static str) -> Result<(), BinaryCursorError> {
+        self.check_depth(operation)?;
+        self.cursor.push_location();
+        if let Err(e) = self.cursor.set_position(location) {
+            self.cursor.pop_location();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Temporarily jumps to a position relative to the current cursor location
+    ///
+    /// The position will be automatically restored when the `StreamingBinaryCursorJump` is
+    /// dropped. A positive offset moves forward, while a negative offset moves backward.
+    pub fn jump_relative(&mut self, offset: i64) -> Result<(), BinaryCursorError> {
+        let current_pos = self.cursor.position();
+        let new_pos = if offset >= 0 {
+            current_pos.checked_add(offset as u64)
+        } else {
+            current_pos.checked_sub(offset.unsigned_abs())
+        }
+        .ok_or_else(|| {
+            BinaryCursorError::ParseError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Position would overflow/underflow",
+            ))
+        })?;
+        self.jump_to(new_pos, "jump_relative")
+    }
+}
+
+impl<'a, R> Drop for StreamingBinaryCursorJump<'a, R>
+where
+    R: Read + Seek,
+{
+    fn drop(&mut self) {
+        // Only restore if this guard saved a position, and then to the position from before
+        // its first jump, as for `BinaryCursorJump`
+        if self.cursor.location_stack.len() > self.depth {
+            self.cursor.location_stack.truncate(self.depth + 1);
+            let _ = self.cursor.restore_location();
+        }
+    }
+}
+// endregion: StreamingCursorJump implementation
+
+// region: Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_cursor::BinaryCursorBuilder;
+    use std::io::Cursor;
+
+    fn source(data: &[u8]) -> Cursor<Vec<u8>> {
+        Cursor::new(data.to_vec())
+    }
+
+    #[test]
+    fn test_streaming_parse_primitives() {
+        let data = [
+            0x42, 0xFE, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12, 0x00, 0x00, 0x80, 0x3F,
+        ];
+        let mut cursor = StreamingBinaryCursor::new(source(&data)).unwrap();
+
+        assert_eq!(cursor.parse_u8().unwrap(), 0x42);
+        assert_eq!(cursor.parse_i8().unwrap(), -2);
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x1234);
+        assert_eq!(cursor.parse_u32_le().unwrap(), 0x12345678);
+        assert_eq!(cursor.parse_f32_le().unwrap(), 1.0);
+        assert_eq!(cursor.position(), 12);
+        assert_eq!(cursor.remaining().unwrap(), 0);
+        assert_eq!(cursor.len().unwrap(), 12);
+    }
+
+    #[test]
+    fn test_streaming_matches_in_memory_cursor() {
+        let data: Vec<u8> = (0..64).collect();
+        let mut streaming = StreamingBinaryCursor::new(source(&data)).unwrap();
+        let mut in_memory = crate::binary_cursor::BinaryCursor::new(&data);
+
+        assert_eq!(
+            streaming.parse_u64_le().unwrap(),
+            in_memory.parse_u64_le().unwrap()
+        );
+        assert_eq!(
+            streaming.parse_i32_le().unwrap(),
+            in_memory.parse_i32_le().unwrap()
+        );
+        assert_eq!(
+            streaming.parse_i16_le().unwrap(),
+            in_memory.parse_i16_le().unwrap()
+        );
+        assert_eq!(
+            streaming.parse_f64_le().unwrap(),
+            in_memory.parse_f64_le().unwrap()
+        );
+        assert_eq!(
+            streaming.parse_bytes(10).unwrap(),
+            in_memory.parse_bytes(10).unwrap()
+        );
+        assert_eq!(streaming.position(), in_memory.position());
+    }
+
+    #[test]
+    fn test_streaming_eof_restores_position() {
+        let data = [0x01, 0x02, 0x03];
+        let mut cursor = StreamingBinaryCursor::new(source(&data)).unwrap();
+        cursor.parse_u8().unwrap();
+
+        let error = cursor.parse_u32_le().unwrap_err();
+        assert!(matches!(
+            error,
+            BinaryCursorError::UnexpectedEof {
+                operation: "parse_u32_le",
+                position: 1,
+                requested: 4,
+                available: 2,
+            }
+        ));
+        assert_eq!(cursor.position(), 1);
+        assert!(cursor.parse_bytes(3).is_err());
+        assert_eq!(cursor.parse_u16_le().unwrap(), 0x0302);
+    }
+
+    #[test]
+    fn test_streaming_count() {
+        let data = [0x01, 0x00, 0x02, 0x00, 0x03];
+        let mut cursor = StreamingBinaryCursor::new(source(&data)).unwrap();
+
+        assert_eq!(cursor.count(|c| c.parse_u16_le(), 2).unwrap(), vec![1, 2]);
+        assert_eq!(cursor.position(), 4);
+
+        // A failing item, or a corrupt count, leaves the position unchanged
+        cursor.set_position(0).unwrap();
+        let error = cursor.count(|c| c.parse_u16_le(), 3).unwrap_err();
+        assert_eq!(error.position(), Some(4));
+        assert_eq!(cursor.position(), 0);
+        assert!(cursor.count(|c| c.parse_u8(), usize::MAX).is_err());
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn test_streaming_jump() {
+        let data = [0x04, 0x00, 0x00, 0x00, 0xAA, 0xBB];
+        let mut cursor = StreamingBinaryCursor::new(source(&data)).unwrap();
+        let offset = cursor.parse_u32_le().unwrap();
+
+        {
+            let mut jump = StreamingBinaryCursorJump::new(&mut cursor);
+            jump.jump(offset as u64).unwrap();
+            assert_eq!(jump.cursor.parse_u8().unwrap(), 0xAA);
+            {
+                let mut inner = StreamingBinaryCursorJump::new(jump.cursor);
+                inner.jump_relative(-5).unwrap();
+                assert_eq!(inner.cursor.parse_u8().unwrap(), 0x04);
+                assert!(inner.jump_relative(-10).is_err());
+            }
+            assert_eq!(jump.cursor.parse_u8().unwrap(), 0xBB);
+        }
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.pop_location(), None);
+    }
+
+    #[test]
+    fn test_streaming_jump_multi_hop() {
+        let data = [0x03, 0xFF, 0xFF, 0x05, 0xFF, 0x2A];
+        let mut cursor = StreamingBinaryCursor::new(source(&data)).unwrap();
+        cursor.set_position(1).unwrap();
+
+        {
+            let mut jump = StreamingBinaryCursorJump::new(&mut cursor);
+            jump.jump(0).unwrap();
+            let next = jump.cursor.parse_u8().unwrap() as u64;
+            jump.jump(next).unwrap();
+            let next = jump.cursor.parse_u8().unwrap() as u64;
+            jump.jump(next).unwrap();
+            assert_eq!(jump.cursor.parse_u8().unwrap(), 0x2A);
+        }
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.pop_location(), None);
+    }
+
+    #[test]
+    fn test_streaming_location_stack() {
+        let data = [0x01, 0x02, 0x03, 0x04];
+        let mut cursor = StreamingBinaryCursor::new(source(&data)).unwrap();
+        cursor.set_position(1).unwrap();
+        cursor.push_location();
+        cursor.set_position(3).unwrap();
+        assert_eq!(cursor.parse_u8().unwrap(), 0x04);
+        assert!(cursor.restore_location().unwrap());
+        assert_eq!(cursor.parse_u8().unwrap(), 0x02);
+        assert!(!cursor.restore_location().unwrap());
+    }
+
+    #[test]
+    fn test_streaming_starts_at_reader_position() {
+        let mut reader = source(&[0x01, 0x02, 0x03]);
+        reader.set_position(2);
+        let mut cursor = StreamingBinaryCursor::new(reader).unwrap();
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.parse_u8().unwrap(), 0x03);
+    }
+
+    #[test]
+    fn test_streaming_builder_options() {
+        let data = [0x00, 0x01, 0x00, 0x02];
+        let mut cursor = BinaryCursorBuilder::new()
+            .endianness(Endianness::Big)
+            .max_alloc(2)
+            .depth_limit(1)
+            .build_streaming(source(&data))
+            .unwrap();
+
+        assert_eq!(cursor.parse_u16().unwrap(), 1);
+        assert!(cursor.parse_bytes(3).is_err());
+        assert_eq!(cursor.position(), 2);
+
+        let mut jump = StreamingBinaryCursorJump::new(&mut cursor);
+        jump.jump(0).unwrap();
+        assert!(jump.jump(2).is_err());
+        drop(jump);
+        assert_eq!(cursor.parse_u16().unwrap(), 2);
+
+        assert!(
+            BinaryCursorBuilder::new()
+                .max_alloc(0)
+                .build_streaming(source(&data))
+                .is_err()
+        );
+    }
+}
+// endregion: Tests
@@ -0,0 +1,206 @@
+//! Parsing of realistic headers with `#[derive(FromCursor)]`.
+
+#![cfg(feature = "derive")]
+
+use cursor_binary_parser::prelude::*;
+
+// region: BMP
+/// The 14-byte `BITMAPFILEHEADER` that starts every BMP file
+#[derive(Debug, PartialEq, FromCursor)]
+struct BitmapFileHeader {
+    signature: [u8; 2],
+    file_size: u32,
+    #[cursor(skip = 4)]
+    pixel_offset: u32,
+}
+
+/// The 40-byte `BITMAPINFOHEADER` that follows the file header
+#[derive(Debug, PartialEq, FromCursor)]
+struct BitmapInfoHeader {
+    header_size: u32,
+    width: i32,
+    height: i32,
+    planes: u16,
+    bits_per_pixel: u16,
+    compression: u32,
+    image_size: u32,
+    resolution: (i32, i32),
+    colours_used: u32,
+    colours_important: u32,
+}
+
+#[derive(Debug, PartialEq, FromCursor)]
+struct Bitmap {
+    file: BitmapFileHeader,
+    info: BitmapInfoHeader,
+}
+
+/// A 2x1 24-bit BMP: file header, info header and one padded row of pixels
+const BMP_2X1: [u8; 62] = [
+    b'B', b'M', // signature
+    0x3E, 0x00, 0x00, 0x00, // file size = 62
+    0x00, 0x00, 0x00, 0x00, // reserved
+    0x36, 0x00, 0x00, 0x00, // pixel offset = 54
+    0x28, 0x00, 0x00, 0x00, // header size = 40
+    0x02, 0x00, 0x00, 0x00, // width = 2
+    0xFF, 0xFF, 0xFF, 0xFF, // height = -1 (top-down)
+    0x01, 0x00, // planes = 1
+    0x18, 0x00, // bits per pixel = 24
+    0x00, 0x00, 0x00, 0x00, // compression = BI_RGB
+    0x08, 0x00, 0x00, 0x00, // image size = 8
+    0x13, 0x0B, 0x00, 0x00, // horizontal resolution = 2835
+    0x13, 0x0B, 0x00, 0x00, // vertical resolution = 2835
+    0x00, 0x00, 0x00, 0x00, // colours used
+    0x00, 0x00, 0x00, 0x00, // colours important
+    0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, // red, white, row padding
+];
+
+#[test]
+fn test_derive_bmp_header() {
+    let mut cursor = BinaryCursor::new(&BMP_2X1[..]);
+    let bitmap: Bitmap = cursor.parse().unwrap();
+
+    assert_eq!(
+        bitmap.file,
+        BitmapFileHeader {
+            signature: *b"BM",
+            file_size: 62,
+            pixel_offset: 54,
+        }
+    );
+    assert_eq!(bitmap.info.width, 2);
+    assert_eq!(bitmap.info.height, -1);
+    assert_eq!(bitmap.info.bits_per_pixel, 24);
+    assert_eq!(bitmap.info.resolution, (2835, 2835));
+    assert_eq!(cursor.position(), bitmap.file.pixel_offset as u64);
+}
+
+#[test]
+fn test_derive_bmp_truncated() {
+    let mut cursor = BinaryCursor::new(&BMP_2X1[..30]);
+    let error = cursor.parse::<Bitmap>().unwrap_err();
+    assert_eq!(error.position(), Some(30));
+    assert_eq!(cursor.position(), 0);
+}
+
+#[test]
+fn test_derive_from_cursor_is_atomic() {
+    // Calling the trait method directly, rather than through `parse`, is atomic too
+    let mut cursor = BinaryCursor::new(&BMP_2X1[..8]);
+    let error = BitmapFileHeader::from_cursor(&mut cursor).unwrap_err();
+    assert_eq!(error.position(), Some(6));
+    assert_eq!(cursor.position(), 0);
+
+    // Including when a nested struct fails partway through
+    let mut cursor = BinaryCursor::new(&BMP_2X1[..20]);
+    cursor.skip(1).unwrap();
+    assert!(Bitmap::from_cursor(&mut cursor).is_err());
+    assert_eq!(cursor.position(), 1);
+}
+// endregion: BMP
+
+// region: Big-endian chunks
+/// A big-endian chunk with a length-prefixed list of palette entries
+#[derive(Debug, PartialEq, FromCursor)]
+#[cursor(endian = "big")]
+struct PaletteChunk {
+    tag: [u8; 4],
+    version: u16,
+    #[cursor(endian = "little")]
+    flags: u16,
+    entry_count: u8,
+    #[cursor(count = "entry_count")]
+    entries: Vec<[u8; 3]>,
+    checksum: u32,
+}
+
+#[derive(Debug, PartialEq, FromCursor)]
+struct Dimensions(#[cursor(endian = "big")] u16, u16);
+
+#[derive(Debug, PartialEq, FromCursor)]
+struct Marker;
+
+#[test]
+fn test_derive_big_endian_with_count() {
+    let data = [
+        b'P', b'L', b'T', b'E', // tag
+        0x00, 0x02, // version = 2, big-endian
+        0x01, 0x00, // flags = 1, little-endian
+        0x02, // two entries
+        0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, // red, green
+        0x12, 0x34, 0x56, 0x78, // checksum
+    ];
+    let mut cursor = BinaryCursor::new(&data[..]);
+
+    let chunk: PaletteChunk = cursor.parse().unwrap();
+    assert_eq!(
+        chunk,
+        PaletteChunk {
+            tag: *b"PLTE",
+            version: 2,
+            flags: 1,
+            entry_count: 2,
+            entries: vec![[0xFF, 0x00, 0x00], [0x00, 0xFF, 0x00]],
+            checksum: 0x12345678,
+        }
+    );
+    assert!(cursor.at_end());
+    // The cursor's own byte order is untouched
+    assert_eq!(cursor.options().endianness, Endianness::Little);
+}
+
+/// A list whose length field is signed, so a corrupt file can make it negative
+#[derive(Debug, PartialEq, FromCursor)]
+struct SignedList {
+    len: i8,
+    #[cursor(count = "len")]
+    items: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, FromCursor)]
+struct Tagged<T> {
+    tag: u8,
+    value: T,
+}
+
+#[test]
+fn test_derive_signed_count() {
+    let data = [0x02, 0xAA, 0xBB];
+    let mut cursor = BinaryCursor::new(&data[..]);
+    assert_eq!(
+        cursor.parse::<SignedList>().unwrap(),
+        SignedList {
+            len: 2,
+            items: vec![0xAA, 0xBB],
+        }
+    );
+
+    let data = [0xFF, 0xAA, 0xBB];
+    let mut cursor = BinaryCursor::new(&data[..]);
+    let error = cursor.parse::<SignedList>().unwrap_err();
+    assert_eq!(error.fields().kind, "invalid_data");
+    assert!(error.to_string().contains("count field `len` holds -1"));
+    assert_eq!(cursor.position(), 0);
+}
+
+#[test]
+fn test_derive_generic_struct() {
+    let data = [0x01, 0x34, 0x12];
+    let mut cursor = BinaryCursor::new(&data[..]);
+    assert_eq!(
+        cursor.parse::<Tagged<u16>>().unwrap(),
+        Tagged {
+            tag: 1,
+            value: 0x1234,
+        }
+    );
+}
+
+#[test]
+fn test_derive_tuple_and_unit_structs() {
+    let data = [0x00, 0x01, 0x02, 0x00];
+    let mut cursor = BinaryCursor::new(&data[..]);
+    assert_eq!(cursor.parse::<Marker>().unwrap(), Marker);
+    assert_eq!(cursor.parse::<Dimensions>().unwrap(), Dimensions(1, 2));
+}
+// endregion: Big-endian chunks
@@ -0,0 +1,217 @@
+//! End-to-end parsing of the embedded format fixtures using only the public API.
+
+mod test_vectors;
+
+use cursor_binary_parser::binary_cursor::{
+    BinaryCursor, BinaryCursorError, BinaryCursorJump, Endianness,
+};
+use test_vectors::*;
+
+// region: WAV
+#[derive(Debug, PartialEq)]
+struct WavHeader {
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    data_len: u32,
+}
+
+fn parse_wav<T: AsRef<[u8]>>(cursor: &mut BinaryCursor<T>) -> Result<WavHeader, BinaryCursorError> {
+    cursor.expect_tag(b"RIFF")?;
+    let _chunk_size = cursor.parse_u32_le()?;
+    cursor.expect_tag(b"WAVE")?;
+    cursor.expect_tag(b"fmt ")?;
+    let _fmt_size = cursor.parse_u32_le()?;
+    let _audio_format = cursor.parse_u16_le()?;
+    let channels = cursor.parse_u16_le()?;
+    let sample_rate = cursor.parse_u32_le()?;
+    let _byte_rate = cursor.parse_u32_le()?;
+    let _block_align = cursor.parse_u16_le()?;
+    let bits_per_sample = cursor.parse_u16_le()?;
+    cursor.expect_tag(b"data")?;
+    let data_len = cursor.parse_u32_le()?;
+
+    Ok(WavHeader {
+        channels,
+        sample_rate,
+        bits_per_sample,
+        data_len,
+    })
+}
+
+#[test]
+fn test_wav_header() {
+    let mut cursor = BinaryCursor::new(&WAV_MINIMAL[..]);
+    let header = parse_wav(&mut cursor).unwrap();
+    assert_eq!(
+        header,
+        WavHeader {
+            channels: 1,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            data_len: 4,
+        }
+    );
+    assert_eq!(cursor.position(), 44);
+
+    let samples = cursor
+        .count(|c| c.parse_i16_le(), header.data_len as usize / 2)
+        .unwrap();
+    assert_eq!(samples, vec![0, 32767]);
+}
+
+#[test]
+fn test_wav_truncated() {
+    let mut cursor = BinaryCursor::new(&WAV_TRUNCATED[..]);
+    let error = parse_wav(&mut cursor).unwrap_err();
+    // The file ends two bytes into the sample rate field
+    assert_eq!(error.position(), Some(24));
+    assert_eq!(error.fields().available, Some(2));
+}
+// endregion: WAV
+
+// region: PNG
+fn parse_png_chunks<T: AsRef<[u8]>>(
+    cursor: &mut BinaryCursor<T>,
+) -> Result<Vec<(Vec<u8>, u64, u32)>, BinaryCursorError> {
+    cursor.expect_tag(b"\x89PNG\r\n\x1a\n")?;
+
+    let mut chunks = vec![];
+    loop {
+        let length = cursor.parse_uint(4, Endianness::Big)?;
+        let chunk_type = cursor.parse_bytes(4)?;
+        let data_start = cursor.position();
+        cursor.parse_bytes(length as usize)?;
+        let crc = cursor.parse_uint(4, Endianness::Big)? as u32;
+        // The CRC covers the chunk type and data
+        cursor.verify_crc32(data_start - 4, length + 4, crc)?;
+
+        let is_end = chunk_type == b"IEND";
+        chunks.push((chunk_type, data_start, crc));
+        if is_end {
+            return Ok(chunks);
+        }
+    }
+}
+
+#[test]
+fn test_png_chunks() {
+    let mut cursor = BinaryCursor::new(&PNG_1X1[..]);
+    let chunks = parse_png_chunks(&mut cursor).unwrap();
+
+    let types: Vec<&[u8]> = chunks.iter().map(|(t, _, _)| t.as_slice()).collect();
+    assert_eq!(types, vec![&b"IHDR"[..], b"IDAT", b"IEND"]);
+    assert_eq!(chunks[0].2, 0x3A7E9B55);
+    assert_eq!(cursor.position(), PNG_1X1.len() as u64);
+
+    // Go back and read the image dimensions out of IHDR
+    let ihdr_start = chunks[0].1;
+    let mut jump = BinaryCursorJump::new(&mut cursor);
+    jump.jump(ihdr_start).unwrap();
+    assert_eq!(jump.cursor.parse_uint(4, Endianness::Big).unwrap(), 1);
+    assert_eq!(jump.cursor.parse_uint(4, Endianness::Big).unwrap(), 1);
+    assert_eq!(jump.cursor.parse_u8().unwrap(), 8);
+}
+
+#[test]
+fn test_png_bad_chunk_length() {
+    let mut cursor = BinaryCursor::new(&PNG_BAD_CHUNK_LENGTH[..]);
+    assert!(parse_png_chunks(&mut cursor).is_err());
+}
+// endregion: PNG
+
+// region: Protobuf
+#[derive(Debug, PartialEq)]
+enum ProtobufValue {
+    Varint(u64),
+    Bytes(Vec<u8>),
+}
+
+fn parse_protobuf<T: AsRef<[u8]>>(
+    cursor: &mut BinaryCursor<T>,
+) -> Result<Vec<(u64, ProtobufValue)>, BinaryCursorError> {
+    let mut fields = vec![];
+    while !cursor.at_end() {
+        let key = cursor.parse_uleb128()?;
+        let value = match key & 0x07 {
+            0 => ProtobufValue::Varint(cursor.parse_uleb128()?),
+            2 => {
+                let len = cursor.parse_uleb128()?;
+                ProtobufValue::Bytes(cursor.parse_bytes(len as usize)?)
+            }
+            wire_type => panic!("unexpected wire type {}", wire_type),
+        };
+        fields.push((key >> 3, value));
+    }
+    Ok(fields)
+}
+
+#[test]
+fn test_protobuf_message() {
+    let mut cursor = BinaryCursor::new(&PROTOBUF_MESSAGE[..]);
+    let fields = parse_protobuf(&mut cursor).unwrap();
+    assert_eq!(
+        fields,
+        vec![
+            (1, ProtobufValue::Varint(150)),
+            (2, ProtobufValue::Bytes(b"testing".to_vec())),
+        ]
+    );
+}
+
+#[test]
+fn test_protobuf_truncated() {
+    let mut cursor = BinaryCursor::new(&PROTOBUF_TRUNCATED[..]);
+    assert!(parse_protobuf(&mut cursor).is_err());
+}
+// endregion: Protobuf
+
+// region: LEB128
+#[test]
+fn test_uleb128_cases() {
+    for (bytes, expected) in ULEB128_CASES {
+        let mut cursor = BinaryCursor::new(*bytes);
+        assert_eq!(cursor.parse_uleb128().unwrap(), *expected);
+        assert_eq!(cursor.position(), bytes.len() as u64);
+    }
+}
+
+#[test]
+fn test_sleb128_cases() {
+    for (bytes, expected) in SLEB128_CASES {
+        let mut cursor = BinaryCursor::new(*bytes);
+        assert_eq!(cursor.parse_sleb128().unwrap(), *expected);
+        assert_eq!(cursor.position(), bytes.len() as u64);
+    }
+}
+
+#[test]
+fn test_uleb128_corrupt() {
+    let mut cursor = BinaryCursor::new(&ULEB128_OVERLONG[..]);
+    assert!(cursor.parse_uleb128().is_err());
+
+    let mut cursor = BinaryCursor::new(&ULEB128_TRUNCATED[..]);
+    assert!(cursor.parse_uleb128().is_err());
+}
+// endregion: LEB128
+
+// region: UTF-16
+#[test]
+fn test_utf16_samples() {
+    let mut cursor = BinaryCursor::new(&UTF16_LE_HELLO[..]);
+    assert_eq!(cursor.parse_utf16_le(5).unwrap(), "Hello");
+
+    let mut cursor = BinaryCursor::new(&UTF16_BE_HELLO[..]);
+    assert_eq!(cursor.parse_utf16_be(5).unwrap(), "Hello");
+
+    let mut cursor = BinaryCursor::new(&UTF16_LE_SURROGATE_PAIR[..]);
+    assert_eq!(cursor.parse_utf16_le(3).unwrap(), "a😀");
+}
+
+#[test]
+fn test_utf16_unpaired_surrogate() {
+    let mut cursor = BinaryCursor::new(&UTF16_LE_UNPAIRED_SURROGATE[..]);
+    assert!(cursor.parse_utf16_le(2).is_err());
+    assert_eq!(cursor.position(), 0);
+}
+// endregion: UTF-16
@@ -0,0 +1,70 @@
+//! Parsing of a disk-backed file with `StreamingBinaryCursor`.
+
+use cursor_binary_parser::binary_cursor::BinaryCursorError;
+use cursor_binary_parser::streaming_cursor::{StreamingBinaryCursor, StreamingBinaryCursorJump};
+use std::io::{BufReader, Read, Seek, Write};
+
+/// Writes an image with a header pointing at a table of `(offset, length)` entries, each
+/// describing a little-endian u32 record stored earlier in the file
+fn write_image(file: &mut impl Write, records: &[u32]) {
+    let table_offset = 16 + records.len() as u64 * 4;
+    let mut bytes = vec![];
+    bytes.extend_from_slice(b"IMG0");
+    bytes.extend_from_slice(&(records.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&table_offset.to_le_bytes());
+    for record in records {
+        bytes.extend_from_slice(&record.to_le_bytes());
+    }
+    for i in 0..records.len() as u32 {
+        bytes.extend_from_slice(&(16 + i * 4).to_le_bytes());
+    }
+    file.write_all(&bytes).unwrap();
+}
+
+fn parse_image<R: Read + Seek>(
+    cursor: &mut StreamingBinaryCursor<R>,
+) -> Result<Vec<u32>, BinaryCursorError> {
+    let magic = cursor.parse_bytes(4)?;
+    assert_eq!(magic, b"IMG0");
+    let count = cursor.parse_u32_le()?;
+    let table_offset = cursor.parse_u64_le()?;
+
+    let mut records = vec![];
+    for i in 0..count as u64 {
+        let mut jump = StreamingBinaryCursorJump::new(cursor);
+        jump.jump(table_offset + i * 4)?;
+        let record_offset = jump.cursor.parse_u32_le()?;
+        let mut inner = StreamingBinaryCursorJump::new(jump.cursor);
+        inner.jump(record_offset as u64)?;
+        records.push(inner.cursor.parse_u32_le()?);
+    }
+    Ok(records)
+}
+
+#[test]
+fn test_streaming_tempfile() {
+    let records = [0xDEADBEEF, 1, 2, 3, u32::MAX];
+    let mut file = tempfile::tempfile().unwrap();
+    write_image(&mut file, &records);
+    file.rewind().unwrap();
+
+    let mut cursor = StreamingBinaryCursor::new(BufReader::new(file)).unwrap();
+    assert_eq!(parse_image(&mut cursor).unwrap(), records);
+    // Every jump was restored, leaving the cursor just after the header
+    assert_eq!(cursor.position(), 16);
+    assert_eq!(cursor.remaining().unwrap(), 40);
+}
+
+#[test]
+fn test_streaming_tempfile_truncated() {
+    let mut file = tempfile::tempfile().unwrap();
+    write_image(&mut file, &[1, 2, 3]);
+    file.set_len(30).unwrap();
+    file.rewind().unwrap();
+
+    let mut cursor = StreamingBinaryCursor::new(BufReader::new(file)).unwrap();
+    let error = parse_image(&mut cursor).unwrap_err();
+    assert_eq!(error.position(), Some(28));
+    assert_eq!(error.fields().available, Some(2));
+    assert_eq!(cursor.position(), 16);
+}
@@ -0,0 +1,125 @@
+//! Small, well-known binary fixtures used to exercise the parser end-to-end.
+//!
+//! Every fixture is embedded as a byte array so the tests need no external files. Each valid
+//! fixture has at least one deliberately corrupted sibling for exercising error paths. The
+//! fixtures double as worked examples of the formats they describe.
+
+// region: WAV
+/// A minimal valid WAV file: a canonical 44-byte RIFF header describing 16-bit mono PCM at
+/// 8 kHz, followed by two samples.
+pub const WAV_MINIMAL: [u8; 48] = [
+    b'R', b'I', b'F', b'F', // chunk id
+    0x28, 0x00, 0x00, 0x00, // chunk size = 40
+    b'W', b'A', b'V', b'E', // format
+    b'f', b'm', b't', b' ', // sub-chunk id
+    0x10, 0x00, 0x00, 0x00, // sub-chunk size = 16
+    0x01, 0x00, // audio format = PCM
+    0x01, 0x00, // channels = 1
+    0x40, 0x1F, 0x00, 0x00, // sample rate = 8000
+    0x80, 0x3E, 0x00, 0x00, // byte rate = 16000
+    0x02, 0x00, // block align = 2
+    0x10, 0x00, // bits per sample = 16
+    b'd', b'a', b't', b'a', // sub-chunk id
+    0x04, 0x00, 0x00, 0x00, // sub-chunk size = 4
+    0x00, 0x00, 0xFF, 0x7F, // samples = [0, 32767]
+];
+
+/// `WAV_MINIMAL` cut off in the middle of the `fmt ` sub-chunk.
+pub const WAV_TRUNCATED: [u8; 26] = [
+    b'R', b'I', b'F', b'F', 0x28, 0x00, 0x00, 0x00, b'W', b'A', b'V', b'E', b'f', b'm', b't', b' ',
+    0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x40, 0x1F,
+];
+// endregion: WAV
+
+// region: PNG
+/// A valid 1x1 8-bit greyscale PNG: signature, `IHDR`, a single `IDAT` and `IEND`.
+///
+/// All integers in PNG are big-endian, and every chunk carries a CRC-32 over its type and data.
+pub const PNG_1X1: [u8; 67] = [
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, // signature
+    0x00, 0x00, 0x00, 0x0D, b'I', b'H', b'D', b'R', // IHDR, 13 bytes
+    0x00, 0x00, 0x00, 0x01, // width = 1
+    0x00, 0x00, 0x00, 0x01, // height = 1
+    0x08, 0x00, 0x00, 0x00, 0x00, // depth, colour type, compression, filter, interlace
+    0x3A, 0x7E, 0x9B, 0x55, // CRC
+    0x00, 0x00, 0x00, 0x0A, b'I', b'D', b'A', b'T', // IDAT, 10 bytes
+    0x78, 0xDA, 0x63, 0xA8, 0x07, 0x00, 0x00, 0x81, 0x00, 0x80, // zlib stream
+    0x7E, 0x1C, 0x29, 0xC7, // CRC
+    0x00, 0x00, 0x00, 0x00, b'I', b'E', b'N', b'D', // IEND, 0 bytes
+    0xAE, 0x42, 0x60, 0x82, // CRC
+];
+
+/// `PNG_1X1` with the `IDAT` length field corrupted to claim far more data than exists.
+pub const PNG_BAD_CHUNK_LENGTH: [u8; 67] = [
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, b'I', b'H', b'D', b'R',
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0x3A, 0x7E, 0x9B,
+    0x55, 0x00, 0x00, 0xFF, 0x0A, b'I', b'D', b'A', b'T', 0x78, 0xDA, 0x63, 0xA8, 0x07, 0x00, 0x00,
+    0x81, 0x00, 0x80, 0x7E, 0x1C, 0x29, 0xC7, 0x00, 0x00, 0x00, 0x00, b'I', b'E', b'N', b'D', 0xAE,
+    0x42, 0x60, 0x82,
+];
+// endregion: PNG
+
+// region: Protobuf
+/// A protobuf message with field 1 (varint) = 150 and field 2 (length-delimited) = "testing",
+/// as used in the protobuf encoding guide.
+pub const PROTOBUF_MESSAGE: [u8; 12] = [
+    0x08, 0x96, 0x01, // field 1, wire type 0, value 150
+    0x12, 0x07, b't', b'e', b's', b't', b'i', b'n', b'g', // field 2, wire type 2, "testing"
+];
+
+/// `PROTOBUF_MESSAGE` cut off in the middle of the first varint.
+pub const PROTOBUF_TRUNCATED: [u8; 2] = [0x08, 0x96];
+// endregion: Protobuf
+
+// region: LEB128
+/// Unsigned LEB128 encodings paired with the values they decode to.
+pub const ULEB128_CASES: &[(&[u8], u64)] = &[
+    (&[0x00], 0),
+    (&[0x01], 1),
+    (&[0x7F], 127),
+    (&[0x80, 0x01], 128),
+    (&[0xFF, 0x7F], 16383),
+    (&[0x80, 0x80, 0x01], 16384),
+    (&[0xE5, 0x8E, 0x26], 624485),
+    (
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+        u64::MAX,
+    ),
+];
+
+/// Signed LEB128 encodings paired with the values they decode to.
+pub const SLEB128_CASES: &[(&[u8], i64)] = &[
+    (&[0x00], 0),
+    (&[0x02], 2),
+    (&[0x7E], -2),
+    (&[0x7F], -1),
+    (&[0xFF, 0x00], 127),
+    (&[0x81, 0x7F], -127),
+    (&[0x80, 0x7F], -128),
+    (&[0xC0, 0xBB, 0x78], -123456),
+    (
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F],
+        i64::MIN,
+    ),
+];
+
+/// An unsigned LEB128 value with eleven bytes, which cannot fit in a u64.
+pub const ULEB128_OVERLONG: [u8; 11] = [0x80; 11];
+
+/// An unsigned LEB128 value whose final byte still has the continuation bit set.
+pub const ULEB128_TRUNCATED: [u8; 2] = [0x80, 0x80];
+// endregion: LEB128
+
+// region: UTF-16
+/// "Hello" encoded as UTF-16LE.
+pub const UTF16_LE_HELLO: [u8; 10] = [b'H', 0x00, b'e', 0x00, b'l', 0x00, b'l', 0x00, b'o', 0x00];
+
+/// "Hello" encoded as UTF-16BE.
+pub const UTF16_BE_HELLO: [u8; 10] = [0x00, b'H', 0x00, b'e', 0x00, b'l', 0x00, b'l', 0x00, b'o'];
+
+/// "a😀" encoded as UTF-16LE; the emoji requires a surrogate pair.
+pub const UTF16_LE_SURROGATE_PAIR: [u8; 6] = [b'a', 0x00, 0x3D, 0xD8, 0x00, 0xDE];
+
+/// A lone high surrogate followed by "a" in UTF-16LE, which is not valid UTF-16.
+pub const UTF16_LE_UNPAIRED_SURROGATE: [u8; 4] = [0x3D, 0xD8, b'a', 0x00];
+// endregion: UTF-16